@@ -390,6 +390,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "none" => quote!(display: taffy::style::Display::None,),
             "block" => quote!(display: taffy::style::Display::Block,),
             "grid" => quote!(display: taffy::style::Display::Grid,),
+            "contents" => quote!(display: taffy::style::Display::Contents,),
             _ => quote!(display: taffy::style::Display::Flex,),
         },
         _ => quote!(),
@@ -406,6 +407,9 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let position = match style["position"] {
         Value::String(ref value) => match value.as_ref() {
             "absolute" => quote!(position: taffy::style::Position::Absolute,),
+            "static" => quote!(position: taffy::style::Position::Static,),
+            "fixed" => quote!(position: taffy::style::Position::Fixed,),
+            "sticky" => quote!(position: taffy::style::Position::Sticky,),
             _ => quote!(),
         },
         _ => quote!(),
@@ -480,7 +484,18 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "flex-end" => quote!(align_items: Some(taffy::style::AlignItems::FlexEnd),),
             "center" => quote!(align_items: Some(taffy::style::AlignItems::Center),),
             "baseline" => quote!(align_items: Some(taffy::style::AlignItems::Baseline),),
+            "last baseline" => quote!(align_items: Some(taffy::style::AlignItems::LastBaseline),),
             "stretch" => quote!(align_items: Some(taffy::style::AlignItems::Stretch),),
+            "safe start" => quote!(align_items: Some(taffy::style::AlignItems::SafeStart),),
+            "safe end" => quote!(align_items: Some(taffy::style::AlignItems::SafeEnd),),
+            "safe flex-start" => quote!(align_items: Some(taffy::style::AlignItems::SafeFlexStart),),
+            "safe flex-end" => quote!(align_items: Some(taffy::style::AlignItems::SafeFlexEnd),),
+            "safe center" => quote!(align_items: Some(taffy::style::AlignItems::SafeCenter),),
+            "unsafe start" => quote!(align_items: Some(taffy::style::AlignItems::Start),),
+            "unsafe end" => quote!(align_items: Some(taffy::style::AlignItems::End),),
+            "unsafe flex-start" => quote!(align_items: Some(taffy::style::AlignItems::FlexStart),),
+            "unsafe flex-end" => quote!(align_items: Some(taffy::style::AlignItems::FlexEnd),),
+            "unsafe center" => quote!(align_items: Some(taffy::style::AlignItems::Center),),
             _ => quote!(),
         },
         _ => quote!(),
@@ -494,7 +509,18 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "flex-end" => quote!(align_self: Some(taffy::style::AlignSelf::FlexEnd),),
             "center" => quote!(align_self: Some(taffy::style::AlignSelf::Center),),
             "baseline" => quote!(align_self: Some(taffy::style::AlignSelf::Baseline),),
+            "last baseline" => quote!(align_self: Some(taffy::style::AlignSelf::LastBaseline),),
             "stretch" => quote!(align_self: Some(taffy::style::AlignSelf::Stretch),),
+            "safe start" => quote!(align_self: Some(taffy::style::AlignSelf::SafeStart),),
+            "safe end" => quote!(align_self: Some(taffy::style::AlignSelf::SafeEnd),),
+            "safe flex-start" => quote!(align_self: Some(taffy::style::AlignSelf::SafeFlexStart),),
+            "safe flex-end" => quote!(align_self: Some(taffy::style::AlignSelf::SafeFlexEnd),),
+            "safe center" => quote!(align_self: Some(taffy::style::AlignSelf::SafeCenter),),
+            "unsafe start" => quote!(align_self: Some(taffy::style::AlignSelf::Start),),
+            "unsafe end" => quote!(align_self: Some(taffy::style::AlignSelf::End),),
+            "unsafe flex-start" => quote!(align_self: Some(taffy::style::AlignSelf::FlexStart),),
+            "unsafe flex-end" => quote!(align_self: Some(taffy::style::AlignSelf::FlexEnd),),
+            "unsafe center" => quote!(align_self: Some(taffy::style::AlignSelf::Center),),
             _ => quote!(),
         },
         _ => quote!(),
@@ -508,7 +534,18 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "flex-end" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexEnd),),
             "center" => quote!(justify_items: Some(taffy::style::JustifyItems::Center),),
             "baseline" => quote!(justify_items: Some(taffy::style::JustifyItems::Baseline),),
+            "last baseline" => quote!(justify_items: Some(taffy::style::JustifyItems::LastBaseline),),
             "stretch" => quote!(justify_items: Some(taffy::style::JustifyItems::Stretch),),
+            "safe start" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeStart),),
+            "safe end" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeEnd),),
+            "safe flex-start" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeFlexStart),),
+            "safe flex-end" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeFlexEnd),),
+            "safe center" => quote!(justify_items: Some(taffy::style::JustifyItems::SafeCenter),),
+            "unsafe start" => quote!(justify_items: Some(taffy::style::JustifyItems::Start),),
+            "unsafe end" => quote!(justify_items: Some(taffy::style::JustifyItems::End),),
+            "unsafe flex-start" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexStart),),
+            "unsafe flex-end" => quote!(justify_items: Some(taffy::style::JustifyItems::FlexEnd),),
+            "unsafe center" => quote!(justify_items: Some(taffy::style::JustifyItems::Center),),
             _ => quote!(),
         },
         _ => quote!(),
@@ -522,7 +559,18 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "flex-end" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexEnd),),
             "center" => quote!(justify_self: Some(taffy::style::JustifySelf::Center),),
             "baseline" => quote!(justify_self: Some(taffy::style::JustifySelf::Baseline),),
+            "last baseline" => quote!(justify_self: Some(taffy::style::JustifySelf::LastBaseline),),
             "stretch" => quote!(justify_self: Some(taffy::style::JustifySelf::Stretch),),
+            "safe start" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeStart),),
+            "safe end" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeEnd),),
+            "safe flex-start" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeFlexStart),),
+            "safe flex-end" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeFlexEnd),),
+            "safe center" => quote!(justify_self: Some(taffy::style::JustifySelf::SafeCenter),),
+            "unsafe start" => quote!(justify_self: Some(taffy::style::JustifySelf::Start),),
+            "unsafe end" => quote!(justify_self: Some(taffy::style::JustifySelf::End),),
+            "unsafe flex-start" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexStart),),
+            "unsafe flex-end" => quote!(justify_self: Some(taffy::style::JustifySelf::FlexEnd),),
+            "unsafe center" => quote!(justify_self: Some(taffy::style::JustifySelf::Center),),
             _ => quote!(),
         },
         _ => quote!(),
@@ -539,6 +587,16 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "space-between" => quote!(align_content: Some(taffy::style::AlignContent::SpaceBetween),),
             "space-around" => quote!(align_content: Some(taffy::style::AlignContent::SpaceAround),),
             "space-evenly" => quote!(align_content: Some(taffy::style::AlignContent::SpaceEvenly),),
+            "safe start" => quote!(align_content: Some(taffy::style::AlignContent::SafeStart),),
+            "safe end" => quote!(align_content: Some(taffy::style::AlignContent::SafeEnd),),
+            "safe flex-start" => quote!(align_content: Some(taffy::style::AlignContent::SafeFlexStart),),
+            "safe flex-end" => quote!(align_content: Some(taffy::style::AlignContent::SafeFlexEnd),),
+            "safe center" => quote!(align_content: Some(taffy::style::AlignContent::SafeCenter),),
+            "unsafe start" => quote!(align_content: Some(taffy::style::AlignContent::Start),),
+            "unsafe end" => quote!(align_content: Some(taffy::style::AlignContent::End),),
+            "unsafe flex-start" => quote!(align_content: Some(taffy::style::AlignContent::FlexStart),),
+            "unsafe flex-end" => quote!(align_content: Some(taffy::style::AlignContent::FlexEnd),),
+            "unsafe center" => quote!(align_content: Some(taffy::style::AlignContent::Center),),
             _ => quote!(),
         },
         _ => quote!(),
@@ -555,6 +613,16 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "space-between" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceBetween),),
             "space-around" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceAround),),
             "space-evenly" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceEvenly),),
+            "safe start" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeStart),),
+            "safe end" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeEnd),),
+            "safe flex-start" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeFlexStart),),
+            "safe flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeFlexEnd),),
+            "safe center" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeCenter),),
+            "unsafe start" => quote!(justify_content: Some(taffy::style::JustifyContent::Start),),
+            "unsafe end" => quote!(justify_content: Some(taffy::style::JustifyContent::End),),
+            "unsafe flex-start" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexStart),),
+            "unsafe flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexEnd),),
+            "unsafe center" => quote!(justify_content: Some(taffy::style::JustifyContent::Center),),
             _ => quote!(),
         },
         _ => quote!(),
@@ -1,12 +1,37 @@
-//! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
+//! Computes the CSS block layout algorithm.
+//!
+//! Block containers may contain block-level boxes, inline-level boxes, or a mixture of the two. When the `inline_layout`
+//! feature is enabled, each run of consecutive inline-level children is wrapped in an anonymous block box and laid out
+//! into line boxes (an "inline formatting context"). Inline-level content is limited to text runs (whose segments are
+//! supplied by [`LayoutBlockContainer::shape_text_run`]) and atomic inline boxes (such as `inline-block` or `inline-flex`
+//! nodes, or replaced elements like images). Non-atomic inline boxes (like `<span>`) are not supported and their content
+//! should be flattened into text runs. All inline-level content is aligned to the baseline of its line box.
+//!
+//! Children may be floated to the left or right edge of the container using the `float` style. Subsequent block-level
+//! boxes and line boxes are shortened to fit beside the floats, unless they use the `clear` style to be placed below them.
+//!
+//! Block containers with a non-auto `column_count` or `column_width` are multi-column containers, whose children are
+//! distributed between columns. Children are never fragmented across columns.
+use core::ops::Range;
+
+use crate::compute::common::scrollbars::{
+    child_scrollbar_size, compute_with_auto_scrollbars, resolve_scrollbar_gutter_inset, resolve_scrollbar_size,
+};
+use crate::compute::common::sizing_keywords::{resolve_child_intrinsic_sizing_keywords, resolve_stretch_sizes};
+use crate::compute::common::writing_mode::compute_in_writing_mode;
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AvailableSpace, CoreStyle, LengthPercentageAuto, Overflow, Position};
+#[cfg(feature = "inline_layout")]
+use crate::style::DisplayOutside;
+use crate::style::{
+    AvailableSpace, Clear, ColumnFill, ColumnSpan, CoreStyle, Direction, Float, LengthPercentageAuto, Overflow,
+    Position, ScrollbarGutter,
+};
 use crate::style_helpers::TaffyMaxContent;
 use crate::tree::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
-use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
+use crate::tree::{LayoutPartialTreeExt, NodeId};
 use crate::util::debug::debug_log;
-use crate::util::sys::f32_max;
 use crate::util::sys::Vec;
+use crate::util::sys::{f32_max, f32_min};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, LayoutBlockContainer, TextAlign};
@@ -14,6 +39,70 @@ use crate::{BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, L
 #[cfg(feature = "content_size")]
 use super::common::content_size::compute_content_size_contribution;
 
+/// A segment of a shaped text run which cannot be broken across lines
+#[cfg(feature = "inline_layout")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextSegment {
+    /// The advance width of the segment, excluding any trailing whitespace
+    pub width: f32,
+    /// The advance width of any whitespace following the segment. Trailing whitespace hangs at the end of a line,
+    /// and so does not cause the segment to be wrapped onto the next line.
+    pub trailing_whitespace: f32,
+    /// Whether the segment is followed by a forced line break (e.g. a newline in preformatted text)
+    pub forced_break: bool,
+}
+
+/// The text content of an inline-level leaf node, shaped into segments that can be laid out into line boxes.
+/// A soft wrap opportunity is assumed to exist after every segment.
+#[cfg(feature = "inline_layout")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapedTextRun {
+    /// The segments of the text run, in logical order
+    pub segments: Vec<TextSegment>,
+    /// The height that the text run contributes to each line box it is placed in (the `line-height`)
+    pub line_height: f32,
+    /// The distance from the top of the text run's line height to its alphabetic baseline
+    pub baseline: f32,
+}
+
+/// A line box generated by laying out the inline-level content of a block container
+#[cfg(feature = "inline_layout")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineBox {
+    /// The position of the top-left corner of the line box, relative to the block container's border box
+    pub location: Point<f32>,
+    /// The size of the line box. Line boxes span the full width of the block container's content box.
+    pub size: Size<f32>,
+    /// The distance from the top of the line box to its baseline
+    pub baseline: f32,
+    /// The fragments of inline-level content placed within the line box, in visual order
+    pub fragments: Vec<InlineFragment>,
+}
+
+/// The portion of an inline-level node that has been placed within a single line box
+#[cfg(feature = "inline_layout")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineFragment {
+    /// The inline-level node that the fragment belongs to
+    pub node_id: NodeId,
+    /// The range of the text run's [`segments`](ShapedTextRun::segments) contained in the fragment.
+    /// Always empty for atomic inline boxes. In right-to-left containers the segments are placed from right to left.
+    pub segments: Range<usize>,
+    /// The position of the top-left corner of the fragment, relative to the block container's border box.
+    /// For atomic inline boxes this is the position of the box's border box.
+    pub location: Point<f32>,
+    /// The size of the fragment. For atomic inline boxes this is the size of the box's border box.
+    pub size: Size<f32>,
+}
+
+/// Information from the computation of the inline formatting contexts of a block container
+#[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedInlineInfo {
+    /// The line boxes of the block container, in order from top to bottom
+    pub lines: Vec<LineBox>,
+}
+
 /// Per-child data that is accumulated and modified over the course of the layout algorithm
 struct BlockItem {
     /// The identifier for the associated node
@@ -25,6 +114,21 @@ struct BlockItem {
 
     /// Items that are tables don't have stretch sizing applied to them
     is_table: bool,
+    /// Whether the item's writing mode differs from the container's. Such items are in an orthogonal flow
+    /// and establish an independent formatting context.
+    is_orthogonal: bool,
+    /// Whether the item is an in-flow inline-level box (which is laid out into line boxes) rather than a block-level box
+    #[cfg(feature = "inline_layout")]
+    is_inline: bool,
+    /// Which side of the container the item is floated to. Floated items are taken out of flow.
+    float: Float,
+    /// Which floats the item must be placed below
+    clear: Clear,
+    /// Whether the item spans all of the columns of a multi-column container
+    is_column_spanner: bool,
+    /// Whether the item is an in-flow block container which doesn't establish a new block formatting context, and so
+    /// continues the float context of the container rather than being shortened to fit beside its floats
+    continues_float_context: bool,
 
     /// The base size of this item
     size: Size<Option<f32>>,
@@ -37,6 +141,8 @@ struct BlockItem {
     overflow: Point<Overflow>,
     /// The width of the item's scrollbars (if it has scrollbars)
     scrollbar_width: f32,
+    /// The space reserved for the item's scrollbars
+    scrollbar_gutter: ScrollbarGutter,
 
     /// The position style of the item
     position: Position,
@@ -60,12 +166,23 @@ struct BlockItem {
     can_be_collapsed_through: bool,
 }
 
-/// Computes the layout of [`LayoutPartialTree`] according to the block layout algorithm
+/// Computes the layout of [`LayoutBlockContainer`] according to the block layout algorithm
 pub fn compute_block_layout(
     tree: &mut impl LayoutBlockContainer,
     node_id: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
+    let writing_mode = tree.resolve_writing_mode(node_id);
+    if writing_mode.is_vertical() {
+        return compute_in_writing_mode(tree, writing_mode, inputs, |tree, inputs| {
+            compute_logical_layout(tree, node_id, inputs)
+        });
+    }
+    compute_logical_layout(tree, node_id, inputs)
+}
+
+/// Computes the layout of [`LayoutBlockContainer`] in the logical coordinate space of the container
+fn compute_logical_layout(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
     let style = tree.get_block_container_style(node_id);
 
@@ -118,11 +235,17 @@ pub fn compute_block_layout(
     }
 
     debug_log!("BLOCK");
-    compute_inner(tree, node_id, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+    let inputs = LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs };
+    compute_with_auto_scrollbars(tree, node_id, inputs, compute_inner)
 }
 
 /// Computes the layout of [`LayoutBlockContainer`] according to the block layout algorithm
-fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+fn compute_inner(
+    tree: &mut impl LayoutBlockContainer,
+    node_id: NodeId,
+    inputs: LayoutInput,
+    auto_scrollbars: Point<bool>,
+) -> LayoutOutput {
     let LayoutInput {
         known_dimensions, parent_size, available_space, run_mode, vertical_margins_are_collapsible, ..
     } = inputs;
@@ -135,16 +258,17 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let padding = raw_padding.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let border = raw_border.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`, or to
+    // `Overflow::Auto` and the content was found to overflow the node, unless overridden by `scrollbar_gutter`.
+    let direction = tree.resolve_direction(node_id);
     let scrollbar_gutter = {
-        let offsets = style.overflow().transpose().map(|overflow| match overflow {
-            Overflow::Scroll => style.scrollbar_width(),
-            _ => 0.0,
-        });
-        // TODO: make side configurable based on the `direction` property
-        Rect { top: 0.0, left: 0.0, right: offsets.x, bottom: offsets.y }
+        let scrollbar_size = resolve_scrollbar_size(
+            style.overflow(),
+            style.scrollbar_width(),
+            style.scrollbar_gutter(),
+            auto_scrollbars,
+        );
+        resolve_scrollbar_gutter_inset(scrollbar_size, style.scrollbar_gutter(), direction)
     };
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
@@ -169,18 +293,27 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
 
+    // The container is a multi-column container if either its column count or column width is not auto
+    let column_count = style.column_count();
+    let column_width = style.column_width();
+    let column_gap = style.column_gap();
+    let column_fill = style.column_fill();
+    let is_multicol_container = column_count.is_some() || !column_width.is_auto();
+
+    // Containers which are not in-flow block-level children of a block container (such as floats and flex items),
+    // scroll containers, absolutely positioned containers and multi-column containers establish a new block formatting
+    // context. Their margins don't collapse with those of their children, and they contain their floats.
+    let establishes_formatting_context = !vertical_margins_are_collapsible.start
+        || is_multicol_container
+        || style.overflow().x.is_scroll_container()
+        || style.overflow().y.is_scroll_container()
+        || style.position().is_absolutely_positioned();
+
     // Determine margin collapsing behaviour
     let own_margins_collapse_with_children = Line {
-        start: vertical_margins_are_collapsible.start
-            && !style.overflow().x.is_scroll_container()
-            && !style.overflow().y.is_scroll_container()
-            && style.position() == Position::Relative
-            && padding.top == 0.0
-            && border.top == 0.0,
+        start: !establishes_formatting_context && padding.top == 0.0 && border.top == 0.0,
         end: vertical_margins_are_collapsible.end
-            && !style.overflow().x.is_scroll_container()
-            && !style.overflow().y.is_scroll_container()
-            && style.position() == Position::Relative
+            && !establishes_formatting_context
             && padding.bottom == 0.0
             && border.bottom == 0.0
             && size.height.is_none(),
@@ -188,7 +321,7 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let has_styles_preventing_being_collapsed_through = !style.is_block()
         || style.overflow().x.is_scroll_container()
         || style.overflow().y.is_scroll_container()
-        || style.position() == Position::Absolute
+        || style.position().is_absolutely_positioned()
         || padding.top > 0.0
         || padding.bottom > 0.0
         || border.top > 0.0
@@ -196,18 +329,33 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         || matches!(size.height, Some(h) if h > 0.0)
         || matches!(min_size.height, Some(h) if h > 0.0);
 
-    let text_align = style.text_align();
+    // Items are aligned to the inline-start edge by default, which is the right edge in right-to-left containers
+    let text_align = match (style.text_align(), direction) {
+        (TextAlign::Auto, Direction::Rtl) => TextAlign::LegacyRight,
+        (text_align, _) => text_align,
+    };
 
     drop(style);
 
     // 1. Generate items
     let mut items = generate_item_list(tree, node_id, container_content_box_size);
+    resolve_item_sizing_keywords(tree, &mut items, container_content_box_size);
 
     // 2. Compute container width
     let container_outer_width = known_dimensions.width.unwrap_or_else(|| {
         let available_width = available_space.width.maybe_sub(content_box_inset.horizontal_axis_sum());
-        let intrinsic_width = determine_content_based_container_width(tree, &items, available_width)
-            + content_box_inset.horizontal_axis_sum();
+        let mut intrinsic_width = determine_content_based_container_width(tree, &items, available_width);
+        if is_multicol_container {
+            let columns = ColumnStyles {
+                count: column_count,
+                width: column_width.maybe_resolve(None, |val, basis| tree.calc(val, basis)),
+                gap: column_gap.resolve_or_zero(None, |val, basis| tree.calc(val, basis)),
+                fill: column_fill,
+                direction,
+            };
+            intrinsic_width = determine_content_based_multicol_width(intrinsic_width, available_width, columns);
+        }
+        let intrinsic_width = intrinsic_width + content_box_inset.horizontal_axis_sum();
         intrinsic_width.maybe_clamp(min_size.width, max_size.width).maybe_max(Some(padding_border_size.width))
     });
 
@@ -220,16 +368,71 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let resolved_padding = raw_padding.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
     let resolved_border = raw_border.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
     let resolved_content_box_inset = resolved_padding + resolved_border + scrollbar_gutter;
-    let (inflow_content_size, intrinsic_outer_height, first_child_top_margin_set, last_child_bottom_margin_set) =
-        perform_final_layout_on_in_flow_children(
+    let flow_output = if is_multicol_container {
+        let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
+        let columns = ColumnStyles {
+            count: column_count,
+            width: column_width.maybe_resolve(Some(container_inner_width), |val, basis| tree.calc(val, basis)),
+            gap: column_gap.resolve_or_zero(Some(container_inner_width), |val, basis| tree.calc(val, basis)),
+            fill: column_fill,
+            direction,
+        };
+        let max_column_height = known_dimensions
+            .height
+            .or(max_size.height)
+            .map(|height| f32_max(0.0, height - resolved_content_box_inset.vertical_axis_sum()));
+        perform_final_layout_on_multicol_children(
+            tree,
+            &mut items,
+            container_outer_width,
+            content_box_inset,
+            resolved_content_box_inset,
+            text_align,
+            columns,
+            max_column_height,
+        )
+    } else {
+        // Containers which don't establish a new block formatting context continue the float context of their parent
+        let inherited_floats = if establishes_formatting_context { None } else { tree.get_inherited_floats(node_id) };
+        let contains_floats = inherited_floats.is_none();
+        let mut float_context = FloatContext::new(
+            resolved_content_box_inset.left,
+            container_outer_width - resolved_content_box_inset.right,
+        );
+        float_context.floats = inherited_floats.unwrap_or_default();
+
+        let mut output = perform_final_layout_on_in_flow_children(
             tree,
             &mut items,
             container_outer_width,
             content_box_inset,
             resolved_content_box_inset,
             text_align,
+            direction,
             own_margins_collapse_with_children,
+            &mut float_context,
         );
+        if contains_floats {
+            output.contain_floats(&float_context, resolved_content_box_inset.bottom);
+        } else if run_mode == RunMode::PerformLayout {
+            tree.set_placed_floats(node_id, float_context.floats);
+        }
+        output
+    };
+    let InFlowLayoutOutput {
+        content_size: inflow_content_size,
+        intrinsic_outer_height,
+        first_child_top_margin_set,
+        last_child_bottom_margin_set,
+        first_baseline,
+        last_baseline,
+        ..
+    } = flow_output;
+
+    #[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+    if !flow_output.line_boxes.is_empty() {
+        tree.set_detailed_inline_info(node_id, DetailedInlineInfo { lines: flow_output.line_boxes });
+    }
     let container_outer_height = known_dimensions
         .height
         .unwrap_or(intrinsic_outer_height.maybe_clamp(min_size.height, max_size.height))
@@ -245,8 +448,27 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let absolute_position_inset = resolved_border + scrollbar_gutter;
     let absolute_position_area = final_outer_size - absolute_position_inset.sum_axes();
     let absolute_position_offset = Point { x: absolute_position_inset.left, y: absolute_position_inset.top };
-    let absolute_content_size =
-        perform_absolute_layout_on_absolute_children(tree, &items, absolute_position_area, absolute_position_offset);
+    if direction == Direction::Rtl {
+        // The static position of an item in a right-to-left container is the position of its top-right corner, so
+        // it is mirrored within the content box
+        let content_box_edges_sum =
+            resolved_content_box_inset.left + container_outer_width - resolved_content_box_inset.right;
+        for item in items.iter_mut().filter(|item| item.position.is_absolutely_positioned()) {
+            item.static_position.x = content_box_edges_sum - item.static_position.x;
+        }
+    }
+    let mut absolute_content_size = perform_absolute_layout_on_absolute_children(
+        tree,
+        &items,
+        absolute_position_area,
+        absolute_position_offset,
+        direction,
+    );
+    // The absolutely positioned children of a `Position::Static` container are laid out again against their actual
+    // containing block by `TaffyTree`, so they don't contribute to its content size
+    if !tree.get_block_container_style(node_id).position().is_positioned() {
+        absolute_content_size = Size::ZERO;
+    }
 
     // 5. Perform hidden layout on hidden children
     let len = tree.child_count(node_id);
@@ -267,7 +489,7 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
 
     // 7. Determine whether this node can be collapsed through
     let all_in_flow_children_can_be_collapsed_through =
-        items.iter().all(|item| item.position == Position::Absolute || item.can_be_collapsed_through);
+        items.iter().all(|item| item.position.is_absolutely_positioned() || item.can_be_collapsed_through);
     let can_be_collapsed_through =
         !has_styles_preventing_being_collapsed_through && all_in_flow_children_can_be_collapsed_through;
 
@@ -278,7 +500,9 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         size: final_outer_size,
         #[cfg(feature = "content_size")]
         content_size,
-        first_baselines: Point::NONE,
+        scrollbar_size: Size::ZERO,
+        first_baselines: Point { x: None, y: first_baseline },
+        last_baselines: Point { x: None, y: last_baseline },
         top_margin: if own_margins_collapse_with_children.start {
             first_child_top_margin_set
         } else {
@@ -303,21 +527,57 @@ fn generate_item_list(
     node: NodeId,
     node_inner_size: Size<Option<f32>>,
 ) -> Vec<BlockItem> {
+    let direction = tree.resolve_direction(node);
+    let writing_mode = tree.resolve_writing_mode(node);
     tree.child_ids(node)
         .map(|child_node_id| (child_node_id, tree.get_block_child_style(child_node_id)))
         .filter(|(_, style)| style.box_generation_mode() != BoxGenerationMode::None)
         .enumerate()
         .map(|(order, (child_node_id, child_style))| {
-            let aspect_ratio = child_style.aspect_ratio();
+            // An `auto <ratio>` aspect ratio may be overridden by the natural aspect ratio of the child's content, so
+            // it is left to the child's own layout to apply it
+            let aspect_ratio = child_style.aspect_ratio().filter(|_| !child_style.aspect_ratio_auto());
             let padding = child_style.padding().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
             let border = child_style.border().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+            // Absolutely positioned items cannot be floated
+            let position = child_style.position();
+            let float = if position.is_absolutely_positioned() { Float::None } else { child_style.float() };
+            #[cfg(feature = "inline_layout")]
+            let is_inline = child_style.display_outside() == DisplayOutside::Inline
+                && !position.is_absolutely_positioned()
+                && float == Float::None;
+            #[cfg(not(feature = "inline_layout"))]
+            let is_inline = false;
+            let is_orthogonal = tree.resolve_writing_mode(child_node_id) != writing_mode;
+            let is_multicol_container = {
+                let child_container_style = tree.get_block_container_style(child_node_id);
+                child_container_style.column_count().is_some() || !child_container_style.column_width().is_auto()
+            };
             BlockItem {
                 node_id: child_node_id,
                 order: order as u32,
                 is_table: child_style.is_table(),
+                is_orthogonal,
+                #[cfg(feature = "inline_layout")]
+                is_inline,
+                float,
+                clear: child_style.clear(),
+                is_column_spanner: child_style.column_span() == ColumnSpan::All
+                    && !position.is_absolutely_positioned()
+                    && float == Float::None
+                    && !is_inline,
+                continues_float_context: child_style.is_block()
+                    && !is_inline
+                    && !is_orthogonal
+                    && !is_multicol_container
+                    && !child_style.is_table()
+                    && float == Float::None
+                    && !position.is_absolutely_positioned()
+                    && !child_style.overflow().x.is_scroll_container()
+                    && !child_style.overflow().y.is_scroll_container(),
                 size: child_style
                     .size()
                     .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
@@ -335,8 +595,22 @@ fn generate_item_list(
                     .maybe_add(box_sizing_adjustment),
                 overflow: child_style.overflow(),
                 scrollbar_width: child_style.scrollbar_width(),
-                position: child_style.position(),
-                inset: child_style.inset(),
+                scrollbar_gutter: child_style.scrollbar_gutter(),
+                position,
+                inset: match child_style.inset() {
+                    // Offsets do not apply to statically positioned items, and only constrain sticky items once scrolled
+                    _ if matches!(position, Position::Static | Position::Sticky) => Rect {
+                        left: LengthPercentageAuto::auto(),
+                        right: LengthPercentageAuto::auto(),
+                        top: LengthPercentageAuto::auto(),
+                        bottom: LengthPercentageAuto::auto(),
+                    },
+                    // In right-to-left containers the `right` inset takes precedence over the `left` inset
+                    inset if direction == Direction::Rtl && !inset.right.is_auto() => {
+                        Rect { left: LengthPercentageAuto::auto(), ..inset }
+                    }
+                    inset => inset,
+                },
                 margin: child_style.margin(),
                 padding,
                 border,
@@ -351,17 +625,104 @@ fn generate_item_list(
         .collect()
 }
 
+/// Resolve any min-content, max-content, fit-content or stretch values in the items' size, min-size and max-size styles.
+/// These resolve to `None` in [`generate_item_list`].
+#[inline]
+fn resolve_item_sizing_keywords(
+    tree: &mut impl LayoutBlockContainer,
+    items: &mut [BlockItem],
+    node_inner_size: Size<Option<f32>>,
+) {
+    for item in items.iter_mut() {
+        let child_style = tree.get_block_child_style(item.node_id);
+        let size = child_style.size();
+        let min_size = child_style.min_size();
+        let max_size = child_style.max_size();
+        let box_sizing_adjustment =
+            if child_style.box_sizing() == BoxSizing::ContentBox { item.padding_border_sum } else { Size::ZERO };
+        drop(child_style);
+
+        // Stretch values fill the container's content box minus the item's margins. Absolutely positioned items are
+        // sized against their containing block in `perform_absolute_layout_on_absolute_children` instead.
+        if !item.position.is_absolutely_positioned() {
+            let margin = item.margin.resolve_or_zero(node_inner_size.width, |val, basis| tree.calc(val, basis));
+            let stretch_size = node_inner_size.maybe_sub(margin.sum_axes());
+            item.size = resolve_stretch_sizes(size, item.size, stretch_size);
+            item.min_size = resolve_stretch_sizes(min_size, item.min_size, stretch_size);
+            item.max_size = resolve_stretch_sizes(max_size, item.max_size, stretch_size);
+        }
+
+        item.size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            item.node_id,
+            size,
+            item.size,
+            None,
+            node_inner_size,
+            box_sizing_adjustment,
+        );
+        item.min_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            item.node_id,
+            min_size,
+            item.min_size,
+            item.size.width,
+            node_inner_size,
+            box_sizing_adjustment,
+        );
+        item.max_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            item.node_id,
+            max_size,
+            item.max_size,
+            item.size.width,
+            node_inner_size,
+            box_sizing_adjustment,
+        );
+    }
+}
+
 /// Compute the content-based width in the case that the width of the container is not known
 #[inline]
 fn determine_content_based_container_width(
-    tree: &mut impl LayoutPartialTree,
+    tree: &mut impl LayoutBlockContainer,
     items: &[BlockItem],
     available_width: AvailableSpace,
 ) -> f32 {
     let available_space = Size { width: available_width, height: AvailableSpace::MinContent };
 
     let mut max_child_width = 0.0;
-    for item in items.iter().filter(|item| item.position != Position::Absolute) {
+    let mut max_float_width = 0.0;
+    let mut float_width_sum = 0.0;
+
+    // Runs of inline-level items contribute the width of their widest line box
+    #[cfg(feature = "inline_layout")]
+    {
+        let mut index = 0;
+        while index < items.len() {
+            if !items[index].is_inline {
+                index += 1;
+                continue;
+            }
+            let run_end = inline_run_end(items, index);
+            let pieces = generate_inline_pieces(tree, &items[index..run_end], Size::NONE, available_width);
+            let line_width_limit = match available_width {
+                AvailableSpace::Definite(width) => width,
+                AvailableSpace::MinContent => 0.0,
+                AvailableSpace::MaxContent => f32::INFINITY,
+            };
+            let lines = break_inline_pieces_into_lines(&pieces, 0.0, |y, _| (Point { x: 0.0, y }, line_width_limit));
+            max_child_width = lines.iter().fold(max_child_width, |max, line| f32_max(max, line.width));
+            index = run_end;
+        }
+    }
+
+    for item in items.iter().filter(|item| !item.position.is_absolutely_positioned()) {
+        #[cfg(feature = "inline_layout")]
+        if item.is_inline {
+            continue;
+        }
+
         let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
 
         let width = known_dimensions.width.unwrap_or_else(|| {
@@ -375,30 +736,67 @@ fn determine_content_based_container_width(
                 Size::NONE,
                 available_space.map_width(|w| w.maybe_sub(item_x_margin_sum)),
                 SizingMode::InherentSize,
-                Line::TRUE,
+                if item.is_orthogonal { Line::FALSE } else { Line::TRUE },
             );
 
             size_and_baselines.size.width + item_x_margin_sum
         });
         let width = f32_max(width, item.padding_border_sum.width);
 
-        max_child_width = f32_max(max_child_width, width);
+        if item.float == Float::None {
+            max_child_width = f32_max(max_child_width, width);
+        } else {
+            max_float_width = f32_max(max_float_width, width);
+            float_width_sum += width;
+        }
+    }
+
+    // Floats (and the in-flow content beside them) are placed side-by-side when there is room, and are otherwise
+    // placed below one another. So each float must fit on its own, but at most they take up the sum of their widths.
+    let min_width = f32_max(max_child_width, max_float_width);
+    match available_width {
+        AvailableSpace::MinContent => min_width,
+        AvailableSpace::MaxContent => max_child_width + float_width_sum,
+        AvailableSpace::Definite(width) => f32_max(min_width, f32_min(width, max_child_width + float_width_sum)),
     }
+}
 
-    max_child_width
+/// The output of laying out the in-flow children of a block container
+struct InFlowLayoutOutput {
+    /// The contribution of the children to the container's content size
+    #[cfg_attr(not(feature = "content_size"), allow(dead_code))]
+    content_size: Size<f32>,
+    /// The height of the container as determined by its children, including the container's padding and border
+    intrinsic_outer_height: f32,
+    /// The set of margins that collapse with the container's top margin
+    first_child_top_margin_set: CollapsibleMarginSet,
+    /// The set of margins that collapse with the container's bottom margin
+    last_child_bottom_margin_set: CollapsibleMarginSet,
+    /// The position of the container's first baseline, if it has one
+    first_baseline: Option<f32>,
+    /// The position of the container's last baseline, if it has one
+    last_baseline: Option<f32>,
+    /// The line boxes generated by runs of inline-level children
+    #[cfg(feature = "inline_layout")]
+    #[cfg_attr(not(feature = "detailed_layout_info"), allow(dead_code))]
+    line_boxes: Vec<LineBox>,
 }
 
 /// Compute each child's final size and position
 #[inline]
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "inline_layout"), allow(unused_variables))]
 fn perform_final_layout_on_in_flow_children(
-    tree: &mut impl LayoutPartialTree,
+    tree: &mut impl LayoutBlockContainer,
     items: &mut [BlockItem],
     container_outer_width: f32,
     content_box_inset: Rect<f32>,
     resolved_content_box_inset: Rect<f32>,
     text_align: TextAlign,
+    direction: Direction,
     own_margins_collapse_with_children: Line<bool>,
-) -> (Size<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet) {
+    float_context: &mut FloatContext,
+) -> InFlowLayoutOutput {
     // Resolve container_inner_width for sizing child nodes using initial content_box_inset
     let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
     let parent_size = Size { width: Some(container_outer_width), height: None };
@@ -412,15 +810,113 @@ fn perform_final_layout_on_in_flow_children(
     let mut first_child_top_margin_set = CollapsibleMarginSet::ZERO;
     let mut active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
     let mut is_collapsing_with_first_margin_set = true;
-    for item in items.iter_mut() {
-        if item.position == Position::Absolute {
+    #[cfg_attr(not(feature = "inline_layout"), allow(unused_mut))]
+    let mut first_baseline = None;
+    #[cfg_attr(not(feature = "inline_layout"), allow(unused_mut))]
+    let mut last_baseline = None;
+    #[cfg(feature = "inline_layout")]
+    let mut line_boxes: Vec<LineBox> = Vec::new();
+
+    let mut index = 0;
+    while index < items.len() {
+        // Each run of inline-level items is laid out into line boxes as if it were wrapped in an anonymous block box.
+        // Anonymous block boxes have no margins, padding or border and are stretched to fill the container's width.
+        #[cfg(feature = "inline_layout")]
+        if items[index].is_inline {
+            let run_end = inline_run_end(items, index);
+            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                0.0
+            } else {
+                active_collapsible_margin_set.resolve()
+            };
+            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+            let (run_lines, run_content_size) = perform_final_layout_on_inline_run(
+                tree,
+                &mut items[index..run_end],
+                container_outer_width,
+                container_inner_width,
+                Point { x: resolved_content_box_inset.left, y: committed_y_offset + y_margin_offset },
+                text_align,
+                direction,
+                float_context,
+            );
+
+            // An anonymous block box containing no line boxes can be collapsed through
+            if let Some(last_line) = run_lines.last() {
+                committed_y_offset = last_line.location.y + last_line.size.height;
+                active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
+                is_collapsing_with_first_margin_set = false;
+                y_offset_for_absolute = committed_y_offset;
+                first_baseline = first_baseline.or(Some(run_lines[0].location.y + run_lines[0].baseline));
+                last_baseline = Some(last_line.location.y + last_line.baseline);
+            }
+
+            #[cfg(feature = "content_size")]
+            {
+                inflow_content_size = inflow_content_size.f32_max(run_content_size);
+            }
+
+            line_boxes.extend(run_lines);
+            index = run_end;
+            continue;
+        }
+
+        let item = &mut items[index];
+        index += 1;
+        if item.position.is_absolutely_positioned() {
             item.static_position = Point { x: resolved_content_box_inset.left, y: y_offset_for_absolute }
+        } else if item.float != Float::None {
+            // Floats don't participate in margin collapsing, but may not be placed above the hypothetical position
+            // of the top border edge of the next in-flow item
+            let min_y = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                committed_y_offset
+            } else {
+                committed_y_offset + active_collapsible_margin_set.resolve()
+            };
+            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+            let (location, item_layout) = perform_final_layout_on_float(
+                tree,
+                item,
+                float_context,
+                min_y,
+                container_outer_width,
+                container_inner_width,
+            );
+
+            #[cfg(feature = "content_size")]
+            {
+                inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
+                    location,
+                    item_layout.size,
+                    item_layout.content_size,
+                    item.overflow,
+                ));
+            }
         } else {
             let item_margin = item
                 .margin
                 .map(|margin| margin.resolve_to_option(container_outer_width, |val, basis| tree.calc(val, basis)));
             let item_non_auto_margin = item_margin.map(|m| m.unwrap_or(0.0));
             let item_non_auto_x_margin_sum = item_non_auto_margin.horizontal_axis_sum();
+
+            // Items that clear floats are placed below them. Otherwise items which establish a new block formatting
+            // context are placed beside any floats at their hypothetical position, and are shrunk to fit within the
+            // space left over by them. Other block containers are not shrunk, as their content flows around the floats.
+            let hypothetical_y = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                committed_y_offset
+            } else {
+                committed_y_offset
+                    + active_collapsible_margin_set.collapse_with_margin(item_non_auto_margin.top).resolve()
+            };
+            let clearance_y = float_context.clearance_position(item.clear);
+            let hypothetical_y = clearance_y.map_or(hypothetical_y, |y| f32_max(y, hypothetical_y));
+            let band =
+                if item.continues_float_context { None } else { float_context.available_band(hypothetical_y, 0.0) };
+            let (band_x, band_width) = match band {
+                Some((left, right)) => (left, f32_max(0.0, right - left)),
+                None => (resolved_content_box_inset.left, container_inner_width),
+            };
+
             let known_dimensions = if item.is_table {
                 Size::NONE
             } else {
@@ -430,45 +926,13 @@ fn perform_final_layout_on_in_flow_children(
                         // e.g. Table children of blocks do not stretch fit
                         Some(
                             width
-                                .unwrap_or(container_inner_width - item_non_auto_x_margin_sum)
+                                .unwrap_or(band_width - item_non_auto_x_margin_sum)
                                 .maybe_clamp(item.min_size.width, item.max_size.width),
                         )
                     })
                     .maybe_clamp(item.min_size, item.max_size)
             };
 
-            let item_layout = tree.perform_child_layout(
-                item.node_id,
-                known_dimensions,
-                parent_size,
-                available_space.map_width(|w| w.maybe_sub(item_non_auto_x_margin_sum)),
-                SizingMode::InherentSize,
-                Line::TRUE,
-            );
-            let final_size = item_layout.size;
-
-            let top_margin_set = item_layout.top_margin.collapse_with_margin(item_margin.top.unwrap_or(0.0));
-            let bottom_margin_set = item_layout.bottom_margin.collapse_with_margin(item_margin.bottom.unwrap_or(0.0));
-
-            // Expand auto margins to fill available space
-            // Note: Vertical auto-margins for relatively positioned block items simply resolve to 0.
-            // See: https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
-            let free_x_space = f32_max(0.0, container_inner_width - final_size.width - item_non_auto_x_margin_sum);
-            let x_axis_auto_margin_size = {
-                let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
-                if auto_margin_count > 0 {
-                    free_x_space / auto_margin_count as f32
-                } else {
-                    0.0
-                }
-            };
-            let resolved_margin = Rect {
-                left: item_margin.left.unwrap_or(x_axis_auto_margin_size),
-                right: item_margin.right.unwrap_or(x_axis_auto_margin_size),
-                top: top_margin_set.resolve(),
-                bottom: bottom_margin_set.resolve(),
-            };
-
             // Resolve item inset
             let inset = item.inset.zip_size(Size { width: container_inner_width, height: 0.0 }, |p, s| {
                 p.maybe_resolve(s, |val, basis| tree.calc(val, basis))
@@ -478,42 +942,148 @@ fn perform_final_layout_on_in_flow_children(
                 y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
             };
 
-            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
-                0.0
-            } else {
-                active_collapsible_margin_set.collapse_with_margin(resolved_margin.top).resolve()
+            // Items which continue the float context inherit its floats relative to their border box. The position
+            // of the border box depends on the margins that collapse with the item's top margin, so it is assumed to
+            // be the hypothetical position, and the item is laid out again if that turns out to be wrong.
+            let mut float_offset = Point { x: band_x + item_non_auto_margin.left, y: hypothetical_y };
+            let mut is_laid_out_with_actual_float_offset = false;
+            let (
+                item_layout,
+                top_margin_set,
+                bottom_margin_set,
+                resolved_margin,
+                y_margin_offset,
+                has_clearance,
+                border_box_location,
+            ) = loop {
+                let inherited_floats = item.continues_float_context.then(|| {
+                    let offset = Point { x: -float_offset.x, y: -float_offset.y };
+                    float_context.floats.iter().map(|float| float.translate(offset)).collect()
+                });
+                tree.set_inherited_floats(item.node_id, inherited_floats);
+
+                let item_layout = tree.perform_child_layout(
+                    item.node_id,
+                    known_dimensions,
+                    parent_size,
+                    Size { width: AvailableSpace::Definite(band_width), height: available_space.height }
+                        .map_width(|w| w.maybe_sub(item_non_auto_x_margin_sum)),
+                    SizingMode::InherentSize,
+                    if item.is_orthogonal { Line::FALSE } else { Line::TRUE },
+                );
+                let final_size = item_layout.size;
+
+                // The margins of items in an orthogonal flow don't collapse with those of their children
+                let (item_top_margin, item_bottom_margin) = if item.is_orthogonal {
+                    (CollapsibleMarginSet::ZERO, CollapsibleMarginSet::ZERO)
+                } else {
+                    (item_layout.top_margin, item_layout.bottom_margin)
+                };
+                let top_margin_set = item_top_margin.collapse_with_margin(item_margin.top.unwrap_or(0.0));
+                let bottom_margin_set = item_bottom_margin.collapse_with_margin(item_margin.bottom.unwrap_or(0.0));
+
+                // Expand auto margins to fill available space
+                // Note: Vertical auto-margins for relatively positioned block items simply resolve to 0.
+                // See: https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
+                let free_x_space = f32_max(0.0, band_width - final_size.width - item_non_auto_x_margin_sum);
+                let x_axis_auto_margin_size = {
+                    let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
+                    if auto_margin_count > 0 {
+                        free_x_space / auto_margin_count as f32
+                    } else {
+                        0.0
+                    }
+                };
+                let resolved_margin = Rect {
+                    left: item_margin.left.unwrap_or(x_axis_auto_margin_size),
+                    right: item_margin.right.unwrap_or(x_axis_auto_margin_size),
+                    top: top_margin_set.resolve(),
+                    bottom: bottom_margin_set.resolve(),
+                };
+
+                let mut y_margin_offset =
+                    if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                        0.0
+                    } else {
+                        active_collapsible_margin_set.collapse_with_margin(resolved_margin.top).resolve()
+                    };
+
+                // Clearance is introduced above the item's border box if it would otherwise be placed beside the
+                // floats that it clears. Clearance prevents the item's margins from collapsing with those of preceding
+                // items.
+                let has_clearance = match clearance_y {
+                    Some(clearance_y) if clearance_y > committed_y_offset + y_margin_offset => {
+                        y_margin_offset = clearance_y - committed_y_offset;
+                        true
+                    }
+                    _ => false,
+                };
+
+                let mut border_box_location =
+                    Point { x: band_x + resolved_margin.left, y: committed_y_offset + y_margin_offset };
+
+                // Apply alignment
+                let item_outer_width = item_layout.size.width + resolved_margin.horizontal_axis_sum();
+                if item_outer_width < band_width {
+                    match text_align {
+                        TextAlign::Auto => {
+                            // Do nothing
+                        }
+                        TextAlign::LegacyLeft => {
+                            // Do nothing. Left aligned by default.
+                        }
+                        TextAlign::LegacyRight => border_box_location.x += band_width - item_outer_width,
+                        TextAlign::LegacyCenter => border_box_location.x += (band_width - item_outer_width) / 2.0,
+                    }
+                }
+
+                if item.continues_float_context
+                    && !float_context.floats.is_empty()
+                    && border_box_location != float_offset
+                    && !is_laid_out_with_actual_float_offset
+                {
+                    float_offset = border_box_location;
+                    is_laid_out_with_actual_float_offset = true;
+                    continue;
+                }
+                break (
+                    item_layout,
+                    top_margin_set,
+                    bottom_margin_set,
+                    resolved_margin,
+                    y_margin_offset,
+                    has_clearance,
+                    border_box_location,
+                );
             };
+            if has_clearance {
+                is_collapsing_with_first_margin_set = false;
+            }
+
+            // Continue placing floats after those placed among the item's descendants
+            if item.continues_float_context {
+                if let Some(placed_floats) = tree.get_placed_floats(item.node_id) {
+                    float_context.floats =
+                        placed_floats.into_iter().map(|float| float.translate(border_box_location)).collect();
+                }
+            }
 
             item.computed_size = item_layout.size;
-            item.can_be_collapsed_through = item_layout.margins_can_collapse_through;
+            item.can_be_collapsed_through =
+                item_layout.margins_can_collapse_through && !has_clearance && !item.is_orthogonal;
             item.static_position = Point {
                 x: resolved_content_box_inset.left,
                 y: committed_y_offset + active_collapsible_margin_set.resolve(),
             };
-            let mut location = Point {
-                x: resolved_content_box_inset.left + inset_offset.x + resolved_margin.left,
-                y: committed_y_offset + inset_offset.y + y_margin_offset,
-            };
-
-            // Apply alignment
-            let item_outer_width = item_layout.size.width + resolved_margin.horizontal_axis_sum();
-            if item_outer_width < container_inner_width {
-                match text_align {
-                    TextAlign::Auto => {
-                        // Do nothing
-                    }
-                    TextAlign::LegacyLeft => {
-                        // Do nothing. Left aligned by default.
-                    }
-                    TextAlign::LegacyRight => location.x += container_inner_width - item_outer_width,
-                    TextAlign::LegacyCenter => location.x += (container_inner_width - item_outer_width) / 2.0,
-                }
-            }
+            let location =
+                Point { x: border_box_location.x + inset_offset.x, y: border_box_location.y + inset_offset.y };
 
-            let scrollbar_size = Size {
-                width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-                height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-            };
+            let scrollbar_size = child_scrollbar_size(
+                item.overflow,
+                item.scrollbar_width,
+                item.scrollbar_gutter,
+                item_layout.scrollbar_size,
+            );
 
             tree.set_unrounded_layout(
                 item.node_id,
@@ -523,6 +1093,7 @@ fn perform_final_layout_on_in_flow_children(
                     #[cfg(feature = "content_size")]
                     content_size: item_layout.content_size,
                     scrollbar_size,
+                    sticky_offset: Point::ZERO,
                     location,
                     padding: item.padding,
                     border: item.border,
@@ -534,7 +1105,7 @@ fn perform_final_layout_on_in_flow_children(
             {
                 inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
                     location,
-                    final_size,
+                    item_layout.size,
                     item_layout.content_size,
                     item.overflow,
                 ));
@@ -571,8 +1142,906 @@ fn perform_final_layout_on_in_flow_children(
         if own_margins_collapse_with_children.end { 0.0 } else { last_child_bottom_margin_set.resolve() };
 
     committed_y_offset += resolved_content_box_inset.bottom + bottom_y_margin_offset;
-    let content_height = f32_max(0.0, committed_y_offset);
-    (inflow_content_size, content_height, first_child_top_margin_set, last_child_bottom_margin_set)
+
+    InFlowLayoutOutput {
+        content_size: inflow_content_size,
+        intrinsic_outer_height: f32_max(0.0, committed_y_offset),
+        first_child_top_margin_set,
+        last_child_bottom_margin_set,
+        first_baseline,
+        last_baseline,
+        #[cfg(feature = "inline_layout")]
+        line_boxes,
+    }
+}
+
+impl InFlowLayoutOutput {
+    /// Grow the intrinsic outer height to contain the floats of the float context, where `bottom_inset` is the sum of
+    /// the container's bottom padding and border
+    fn contain_floats(&mut self, float_context: &FloatContext, bottom_inset: f32) {
+        if let Some(float_bottom) = float_context.bottom() {
+            self.intrinsic_outer_height = f32_max(self.intrinsic_outer_height, float_bottom + bottom_inset);
+        }
+    }
+
+    /// Combine with the output of laying out a later sequence of children of the same container. The intrinsic
+    /// height and the collapsible margin sets are left unchanged.
+    fn merge(&mut self, other: InFlowLayoutOutput) {
+        self.content_size = self.content_size.f32_max(other.content_size);
+        self.first_baseline = self.first_baseline.or(other.first_baseline);
+        self.last_baseline = other.last_baseline.or(self.last_baseline);
+        #[cfg(feature = "inline_layout")]
+        self.line_boxes.extend(other.line_boxes);
+    }
+}
+
+/// The column styles of a multi-column container, resolved against the width of the container's content box
+#[derive(Debug, Clone, Copy)]
+struct ColumnStyles {
+    /// The specified number of columns (`None` if auto)
+    count: Option<u16>,
+    /// The specified ideal width of each column (`None` if auto)
+    width: Option<f32>,
+    /// The gap between adjacent columns
+    gap: f32,
+    /// How content is distributed between the columns
+    fill: ColumnFill,
+    /// The inline base direction of the container. Columns are ordered from right to left in right-to-left containers.
+    direction: Direction,
+}
+
+impl ColumnStyles {
+    /// Determine the number of columns and the width of each column of a container whose content box has the
+    /// specified width. See: <https://www.w3.org/TR/css-multicol-1/#pseudo-algorithm>
+    fn resolve_count_and_width(&self, available_width: f32) -> (u16, f32) {
+        let count = match self.width {
+            Some(width) if width + self.gap > 0.0 => {
+                // As many columns of at least the specified width as will fit (the cast truncates towards zero)
+                let max_count = ((available_width + self.gap) / (width + self.gap)) as u16;
+                self.count.map_or(max_count, |count| count.min(max_count))
+            }
+            _ => self.count.unwrap_or(1),
+        }
+        .max(1);
+        let width = f32_max(0.0, (available_width + self.gap) / count as f32 - self.gap);
+        (count, width)
+    }
+}
+
+/// Compute the content-based width of a multi-column container from the content-based width of its children
+#[inline]
+fn determine_content_based_multicol_width(
+    content_width: f32,
+    available_width: AvailableSpace,
+    columns: ColumnStyles,
+) -> f32 {
+    let column_width = columns.width.unwrap_or(content_width);
+    match available_width {
+        AvailableSpace::MinContent => f32_min(column_width, content_width),
+        AvailableSpace::MaxContent | AvailableSpace::Definite(_) => {
+            let count = columns.count.unwrap_or(1).max(1) as f32;
+            let width = count * column_width + (count - 1.0) * columns.gap;
+            available_width.into_option().map_or(width, |available_width| f32_min(width, available_width))
+        }
+    }
+}
+
+/// Compute the final size and position of the children of a multi-column container.
+///
+/// In-flow children are distributed between the columns. Children are never fragmented, so each block-level child
+/// (and each run of inline-level children) is placed in a single column. Column spanners split the children into
+/// separate sets of columns, which are stacked vertically with the spanners between them.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn perform_final_layout_on_multicol_children(
+    tree: &mut impl LayoutBlockContainer,
+    items: &mut [BlockItem],
+    container_outer_width: f32,
+    content_box_inset: Rect<f32>,
+    resolved_content_box_inset: Rect<f32>,
+    text_align: TextAlign,
+    columns: ColumnStyles,
+    max_column_height: Option<f32>,
+) -> InFlowLayoutOutput {
+    let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
+    let (column_count, column_width) = columns.resolve_count_and_width(container_inner_width);
+
+    let mut output = InFlowLayoutOutput {
+        content_size: Size::ZERO,
+        intrinsic_outer_height: 0.0,
+        first_child_top_margin_set: CollapsibleMarginSet::ZERO,
+        last_child_bottom_margin_set: CollapsibleMarginSet::ZERO,
+        first_baseline: None,
+        last_baseline: None,
+        #[cfg(feature = "inline_layout")]
+        line_boxes: Vec::new(),
+    };
+    let mut y = resolved_content_box_inset.top;
+    let mut set_start = 0;
+    while set_start < items.len() {
+        let spanner_index = items[set_start..].iter().position(|item| item.is_column_spanner).map(|i| set_start + i);
+        let set_end = spanner_index.unwrap_or(items.len());
+
+        if set_end > set_start {
+            let set_output = perform_final_layout_on_column_set(
+                tree,
+                &mut items[set_start..set_end],
+                Point { x: resolved_content_box_inset.left, y },
+                column_count,
+                column_width,
+                columns,
+                max_column_height,
+                text_align,
+            );
+            y = set_output.intrinsic_outer_height;
+            output.merge(set_output);
+        }
+
+        if let Some(spanner_index) = spanner_index {
+            let spanner_output = perform_final_layout_in_column_box(
+                tree,
+                &mut items[spanner_index..spanner_index + 1],
+                Point { x: resolved_content_box_inset.left, y },
+                container_inner_width,
+                text_align,
+                columns.direction,
+            );
+            y = spanner_output.intrinsic_outer_height;
+            output.merge(spanner_output);
+            set_start = spanner_index + 1;
+        } else {
+            set_start = set_end;
+        }
+    }
+
+    output.intrinsic_outer_height = f32_max(0.0, y + resolved_content_box_inset.bottom);
+    output
+}
+
+/// Distribute a sequence of children (which doesn't contain any column spanners) between a set of columns whose top-left
+/// corner is at `location`, and compute their final size and position.
+///
+/// The returned intrinsic outer height is the position of the bottom of the tallest column.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn perform_final_layout_on_column_set(
+    tree: &mut impl LayoutBlockContainer,
+    items: &mut [BlockItem],
+    location: Point<f32>,
+    column_count: u16,
+    column_width: f32,
+    columns: ColumnStyles,
+    max_column_height: Option<f32>,
+    text_align: TextAlign,
+) -> InFlowLayoutOutput {
+    // Measure the height of each of the units that may not be split between columns
+    let units = split_into_column_units(items);
+    let unit_heights: Vec<f32> = units
+        .iter()
+        .map(|unit| {
+            perform_final_layout_in_column_box(
+                tree,
+                &mut items[unit.clone()],
+                Point::ZERO,
+                column_width,
+                text_align,
+                columns.direction,
+            )
+            .intrinsic_outer_height
+        })
+        .collect();
+
+    // If the height of the columns is constrained then any content which doesn't fit into the specified number of
+    // columns is placed into additional overflow columns
+    let column_height = match columns.fill {
+        ColumnFill::Balance => {
+            let balanced_height = determine_balanced_column_height(&unit_heights, column_count);
+            max_column_height.map_or(balanced_height, |max_height| f32_min(balanced_height, max_height))
+        }
+        ColumnFill::Auto => max_column_height.unwrap_or(f32::INFINITY),
+    };
+    let (column_units, _) = fill_columns(&unit_heights, column_height);
+
+    let mut output = InFlowLayoutOutput {
+        content_size: Size::ZERO,
+        intrinsic_outer_height: location.y,
+        first_child_top_margin_set: CollapsibleMarginSet::ZERO,
+        last_child_bottom_margin_set: CollapsibleMarginSet::ZERO,
+        first_baseline: None,
+        last_baseline: None,
+        #[cfg(feature = "inline_layout")]
+        line_boxes: Vec::new(),
+    };
+    // Columns are placed starting from the inline-start edge of the column set. In right-to-left containers this is
+    // the right edge, and any overflow columns continue past the left edge.
+    let column_pitch = match columns.direction {
+        Direction::Ltr => column_width + columns.gap,
+        Direction::Rtl => -(column_width + columns.gap),
+    };
+    let first_column_x = match columns.direction {
+        Direction::Ltr => location.x,
+        Direction::Rtl => location.x + (column_count - 1) as f32 * (column_width + columns.gap),
+    };
+    for (column_index, column) in column_units.iter().enumerate() {
+        let column_location = Point { x: first_column_x + column_index as f32 * column_pitch, y: location.y };
+        let item_range = units[column.start].start..units[column.end - 1].end;
+        let column_output = perform_final_layout_in_column_box(
+            tree,
+            &mut items[item_range],
+            column_location,
+            column_width,
+            text_align,
+            columns.direction,
+        );
+        output.intrinsic_outer_height = f32_max(output.intrinsic_outer_height, column_output.intrinsic_outer_height);
+        output.merge(column_output);
+    }
+
+    output
+}
+
+/// Lay out a sequence of children of a multi-column container within a column box (or within the box of a column
+/// spanner) whose top-left corner is at `location`. The returned intrinsic outer height is the position of the bottom
+/// of the laid out content (including the bottom margin of the last child).
+#[inline]
+fn perform_final_layout_in_column_box(
+    tree: &mut impl LayoutBlockContainer,
+    items: &mut [BlockItem],
+    location: Point<f32>,
+    width: f32,
+    text_align: TextAlign,
+    direction: Direction,
+) -> InFlowLayoutOutput {
+    // The column box is laid out as the content box of a container which is `width` wide. Offsetting the content box
+    // by a negative right inset keeps percentages resolving against the width of the column box.
+    let inset = Rect { left: location.x, right: -location.x, top: location.y, bottom: 0.0 };
+    let mut float_context = FloatContext::new(location.x, location.x + width);
+    let mut output = perform_final_layout_on_in_flow_children(
+        tree,
+        items,
+        width,
+        Rect::zero(),
+        inset,
+        text_align,
+        direction,
+        Line::FALSE,
+        &mut float_context,
+    );
+    output.contain_floats(&float_context, 0.0);
+    output
+}
+
+/// Split a sequence of children into the units that may not be split between columns: either a single block-level
+/// child or a run of inline-level children. Absolutely positioned children are kept with the preceding unit.
+#[inline]
+fn split_into_column_units(items: &[BlockItem]) -> Vec<Range<usize>> {
+    let mut units = Vec::new();
+    let mut unit_start = 0;
+    let mut unit_has_in_flow_item = false;
+    #[cfg(feature = "inline_layout")]
+    let mut previous_item_is_inline = false;
+    for (index, item) in items.iter().enumerate() {
+        if item.position.is_absolutely_positioned() {
+            continue;
+        }
+
+        #[cfg(feature = "inline_layout")]
+        let continues_unit = item.is_inline && previous_item_is_inline;
+        #[cfg(not(feature = "inline_layout"))]
+        let continues_unit = false;
+        if unit_has_in_flow_item && !continues_unit {
+            units.push(unit_start..index);
+            unit_start = index;
+        }
+        unit_has_in_flow_item = true;
+        #[cfg(feature = "inline_layout")]
+        {
+            previous_item_is_inline = item.is_inline;
+        }
+    }
+    if unit_start < items.len() {
+        units.push(unit_start..items.len());
+    }
+    units
+}
+
+/// Greedily fill columns of the specified height with units of the specified heights. Each column contains at least
+/// one unit, so a unit which is taller than the columns overflows its column.
+///
+/// Returns the range of units in each column, and the smallest column height which would allow a unit to be moved
+/// into an earlier column.
+#[inline]
+fn fill_columns(unit_heights: &[f32], column_height: f32) -> (Vec<Range<usize>>, f32) {
+    let mut columns = Vec::new();
+    let mut column_start = 0;
+    let mut used_height = 0.0;
+    let mut next_column_height = f32::INFINITY;
+    for (index, &height) in unit_heights.iter().enumerate() {
+        if index > column_start && used_height + height > column_height {
+            next_column_height = f32_min(next_column_height, used_height + height);
+            columns.push(column_start..index);
+            column_start = index;
+            used_height = 0.0;
+        }
+        used_height += height;
+    }
+    if column_start < unit_heights.len() {
+        columns.push(column_start..unit_heights.len());
+    }
+    (columns, next_column_height)
+}
+
+/// Determine the smallest column height for which units of the specified heights fit into the specified number of columns
+#[inline]
+fn determine_balanced_column_height(unit_heights: &[f32], column_count: u16) -> f32 {
+    let total_height: f32 = unit_heights.iter().sum();
+    let tallest_unit = unit_heights.iter().fold(0.0, |max, &height| f32_max(max, height));
+
+    // Start at a lower bound for the column height and increase it until the units fit
+    let mut column_height = f32_max(tallest_unit, total_height / column_count as f32);
+    loop {
+        let (columns, next_column_height) = fill_columns(unit_heights, column_height);
+        if columns.len() <= column_count as usize || next_column_height == f32::INFINITY {
+            return column_height;
+        }
+        column_height = next_column_height;
+    }
+}
+
+/// The margin box of a float which has been placed within a block formatting context.
+///
+/// Positions are relative to the border box of the block container whose float context the float is part of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacedFloat {
+    /// Which side of the container the float was floated to
+    pub side: Float,
+    /// The position of the left edge of the float's margin box
+    pub left: f32,
+    /// The position of the right edge of the float's margin box
+    pub right: f32,
+    /// The position of the top edge of the float's margin box
+    pub top: f32,
+    /// The position of the bottom edge of the float's margin box
+    pub bottom: f32,
+}
+
+impl PlacedFloat {
+    /// Whether the float's margin box overlaps the vertical range `y..y + height`
+    #[inline(always)]
+    fn overlaps(&self, y: f32, height: f32) -> bool {
+        self.bottom > y && (self.top <= y || self.top < y + height)
+    }
+
+    /// The float moved by the specified offset
+    #[inline(always)]
+    fn translate(self, offset: Point<f32>) -> Self {
+        Self {
+            left: self.left + offset.x,
+            right: self.right + offset.x,
+            top: self.top + offset.y,
+            bottom: self.bottom + offset.y,
+            ..self
+        }
+    }
+}
+
+/// The floats that have been placed within a block formatting context, which subsequent content flows around.
+///
+/// Block containers which establish a new block formatting context (such as floats, scroll containers and
+/// multi-column containers) start a new float context and grow to contain its floats. Other block containers continue the float context of
+/// their parent, so their content flows around the parent's floats and their own floats affect the content that
+/// follows them in the parent.
+struct FloatContext {
+    /// The position of the left edge of the container's content box
+    content_left: f32,
+    /// The position of the right edge of the container's content box
+    content_right: f32,
+    /// The floats that have been placed so far, in placement order
+    floats: Vec<PlacedFloat>,
+}
+
+impl FloatContext {
+    /// Create an empty float context for a container with the specified content box edges
+    fn new(content_left: f32, content_right: f32) -> Self {
+        Self { content_left, content_right, floats: Vec::new() }
+    }
+
+    /// Returns the left and right edges of the space left over beside the floats which overlap the vertical range
+    /// `y..y + height`, or `None` if no floats overlap that range
+    fn available_band(&self, y: f32, height: f32) -> Option<(f32, f32)> {
+        let mut band = None;
+        for float in self.floats.iter().filter(|float| float.overlaps(y, height)) {
+            let (left, right) = band.get_or_insert((self.content_left, self.content_right));
+            match float.side {
+                Float::Left => *left = f32_max(*left, float.right),
+                Float::Right => *right = f32_min(*right, float.left),
+                Float::None => {}
+            }
+        }
+        band
+    }
+
+    /// Returns the highest position at or below `y` at which a box of the specified size fits beside the floats,
+    /// along with the band of space available at that position (see [`FloatContext::available_band`])
+    fn find_band(&self, mut y: f32, size: Size<f32>) -> (f32, Option<(f32, f32)>) {
+        loop {
+            match self.available_band(y, size.height) {
+                Some((left, right)) if right - left < size.width => {
+                    // Move down to the point at which the first of the overlapping floats ends
+                    y = self
+                        .floats
+                        .iter()
+                        .filter(|float| float.overlaps(y, size.height))
+                        .fold(f32::INFINITY, |min, float| f32_min(min, float.bottom));
+                }
+                band => return (y, band),
+            }
+        }
+    }
+
+    /// Place a float with the specified margin box size as high as possible (but no higher than `min_y`) and as far
+    /// towards its side of the container as possible. Returns the position of the float's margin box.
+    fn place(&mut self, side: Float, size: Size<f32>, min_y: f32) -> Point<f32> {
+        // A float may not be placed higher than any earlier float
+        let min_y = self.floats.iter().fold(min_y, |min_y, float| f32_max(min_y, float.top));
+        let (y, band) = self.find_band(min_y, size);
+        let (left, right) = band.unwrap_or((self.content_left, self.content_right));
+        let x = if side == Float::Right { right - size.width } else { left };
+        self.floats.push(PlacedFloat { side, left: x, right: x + size.width, top: y, bottom: y + size.height });
+        Point { x, y }
+    }
+
+    /// Returns the position of the bottom of the lowest float that must be cleared by an item with the specified
+    /// `clear` style, or `None` if there are no such floats
+    fn clearance_position(&self, clear: Clear) -> Option<f32> {
+        self.floats
+            .iter()
+            .filter(|float| match clear {
+                Clear::None => false,
+                Clear::Left => float.side == Float::Left,
+                Clear::Right => float.side == Float::Right,
+                Clear::Both => true,
+            })
+            .map(|float| float.bottom)
+            .reduce(f32_max)
+    }
+
+    /// Returns the position of the bottom of the lowest float, or `None` if there are no floats
+    fn bottom(&self) -> Option<f32> {
+        self.clearance_position(Clear::Both)
+    }
+}
+
+/// Lay out a floated item and place it within the float context.
+///
+/// Floats are sized using the shrink-to-fit algorithm, and their auto margins resolve to zero.
+/// Returns the location and layout output of the item.
+#[inline]
+fn perform_final_layout_on_float(
+    tree: &mut impl LayoutBlockContainer,
+    item: &mut BlockItem,
+    float_context: &mut FloatContext,
+    min_y: f32,
+    container_outer_width: f32,
+    container_inner_width: f32,
+) -> (Point<f32>, LayoutOutput) {
+    let margin = item.margin.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
+    let min_y = match float_context.clearance_position(item.clear) {
+        Some(clearance_y) => f32_max(min_y, clearance_y),
+        None => min_y,
+    };
+
+    let item_layout = tree.perform_child_layout(
+        item.node_id,
+        item.size.maybe_clamp(item.min_size, item.max_size),
+        Size { width: Some(container_outer_width), height: None },
+        Size {
+            width: AvailableSpace::Definite(container_inner_width - margin.horizontal_axis_sum()),
+            height: AvailableSpace::MinContent,
+        },
+        SizingMode::InherentSize,
+        Line::FALSE,
+    );
+
+    let margin_box_size = Size {
+        width: item_layout.size.width + margin.horizontal_axis_sum(),
+        height: item_layout.size.height + margin.vertical_axis_sum(),
+    };
+    let margin_box_location = float_context.place(item.float, margin_box_size, min_y);
+    let location = Point { x: margin_box_location.x + margin.left, y: margin_box_location.y + margin.top };
+
+    item.computed_size = item_layout.size;
+    item.can_be_collapsed_through = false;
+    item.static_position = location;
+
+    let scrollbar_size =
+        child_scrollbar_size(item.overflow, item.scrollbar_width, item.scrollbar_gutter, item_layout.scrollbar_size);
+    tree.set_unrounded_layout(
+        item.node_id,
+        &Layout {
+            order: item.order,
+            size: item_layout.size,
+            #[cfg(feature = "content_size")]
+            content_size: item_layout.content_size,
+            scrollbar_size,
+            sticky_offset: Point::ZERO,
+            location,
+            padding: item.padding,
+            border: item.border,
+            margin,
+        },
+    );
+
+    (location, item_layout)
+}
+
+/// Returns the (exclusive) end index of the run of inline-level items starting at `start`. Absolutely positioned
+/// items between inline-level items do not break the run.
+#[cfg(feature = "inline_layout")]
+#[inline]
+fn inline_run_end(items: &[BlockItem], start: usize) -> usize {
+    let mut end = start;
+    for (index, item) in items.iter().enumerate().skip(start) {
+        if item.is_inline {
+            end = index + 1;
+        } else if !item.position.is_absolutely_positioned() {
+            break;
+        }
+    }
+    end
+}
+
+/// A single unbreakable piece of inline-level content: either a segment of a text run or an atomic inline box
+#[cfg(feature = "inline_layout")]
+struct InlinePiece {
+    /// The index (within the run) of the item that the piece belongs to
+    item_index: usize,
+    /// The index of the segment within the item's shaped text run. Always 0 for atomic inline boxes.
+    segment_index: usize,
+    /// The width the piece takes up within a line. For atomic inline boxes this is the width of the margin box.
+    width: f32,
+    /// The width of any hanging whitespace following the piece
+    trailing_whitespace: f32,
+    /// Whether the line must be broken after the piece
+    forced_break: bool,
+    /// The height the piece contributes to its line box. For atomic inline boxes this is the height of the margin box.
+    height: f32,
+    /// The distance from the top of the piece to its baseline
+    baseline: f32,
+    /// The layout output and resolved margins of the item if it is an atomic inline box
+    atomic: Option<(LayoutOutput, Rect<f32>)>,
+}
+
+/// The range of pieces which have been placed into a single line box
+#[cfg(feature = "inline_layout")]
+struct LineSpan {
+    /// The range of pieces within the line
+    pieces: Range<usize>,
+    /// The width of the line's content, excluding any trailing whitespace
+    width: f32,
+    /// The position of the line box
+    location: Point<f32>,
+    /// The width of the line box, which is the space available to the line's content
+    available_width: f32,
+    /// The height of the line box
+    height: f32,
+    /// The distance from the top of the line box to its baseline
+    baseline: f32,
+}
+
+/// Shape the text runs and measure the atomic inline boxes of a run of inline-level items
+#[cfg(feature = "inline_layout")]
+#[inline]
+fn generate_inline_pieces(
+    tree: &mut impl LayoutBlockContainer,
+    items: &[BlockItem],
+    parent_size: Size<Option<f32>>,
+    available_width: AvailableSpace,
+) -> Vec<InlinePiece> {
+    let mut pieces = Vec::new();
+    for (item_index, item) in items.iter().enumerate().filter(|(_, item)| item.is_inline) {
+        if let Some(text_run) = tree.shape_text_run(item.node_id) {
+            pieces.extend(text_run.segments.iter().enumerate().map(|(segment_index, segment)| InlinePiece {
+                item_index,
+                segment_index,
+                width: segment.width,
+                trailing_whitespace: segment.trailing_whitespace,
+                forced_break: segment.forced_break,
+                height: text_run.line_height,
+                baseline: text_run.baseline,
+                atomic: None,
+            }));
+            continue;
+        }
+
+        // Auto margins of atomic inline boxes resolve to zero
+        let margin = item.margin.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+        let layout_output = tree.perform_child_layout(
+            item.node_id,
+            item.size.maybe_clamp(item.min_size, item.max_size),
+            parent_size,
+            Size { width: available_width.maybe_sub(margin.horizontal_axis_sum()), height: AvailableSpace::MinContent },
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+
+        // Atomic inline boxes without a baseline are aligned using the bottom edge of their margin box
+        let size = layout_output.size;
+        pieces.push(InlinePiece {
+            item_index,
+            segment_index: 0,
+            width: size.width + margin.horizontal_axis_sum(),
+            trailing_whitespace: 0.0,
+            forced_break: false,
+            height: size.height + margin.vertical_axis_sum(),
+            baseline: margin.top + layout_output.first_baselines.y.unwrap_or(size.height),
+            atomic: Some((layout_output, margin)),
+        });
+    }
+    pieces
+}
+
+/// Greedily break a sequence of inline pieces into lines, starting at the vertical position `top`. A line is always
+/// allowed to contain at least one piece (even if that piece overflows the line).
+///
+/// The position and width of each line box are determined by `line_box_at`, which is passed the position of the top
+/// of the line and the minimum size of the line: the width of its first piece and the height of the line. As the
+/// height of the line depends on the pieces placed within it, the line is first sized using the height of its first
+/// piece, and `line_box_at` is called again (and the line broken again) whenever the line turns out to be taller.
+#[cfg(feature = "inline_layout")]
+#[inline]
+fn break_inline_pieces_into_lines(
+    pieces: &[InlinePiece],
+    top: f32,
+    line_box_at: impl Fn(f32, Size<f32>) -> (Point<f32>, f32),
+) -> Vec<LineSpan> {
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    let mut y = top;
+    while line_start < pieces.len() {
+        let mut min_line_size = Size { width: pieces[line_start].width, height: pieces[line_start].height };
+        let line = loop {
+            let (location, line_width_limit) = line_box_at(y, min_line_size);
+
+            let mut line_end = line_start;
+            let mut line_width = 0.0;
+            let mut pending_whitespace = 0.0;
+            while let Some(piece) = pieces.get(line_end) {
+                if line_end > line_start && line_width + pending_whitespace + piece.width > line_width_limit {
+                    break;
+                }
+                line_width += pending_whitespace + piece.width;
+                pending_whitespace = piece.trailing_whitespace;
+                line_end += 1;
+                if piece.forced_break {
+                    break;
+                }
+            }
+
+            // Align the baselines of all of the pieces in the line
+            let line_pieces = &pieces[line_start..line_end];
+            let baseline = line_pieces.iter().fold(0.0, |max, piece| f32_max(max, piece.baseline));
+            let descent = line_pieces.iter().fold(0.0, |max, piece| f32_max(max, piece.height - piece.baseline));
+
+            let line = LineSpan {
+                pieces: line_start..line_end,
+                width: line_width,
+                location,
+                available_width: line_width_limit,
+                height: baseline + descent,
+                baseline,
+            };
+            if line.height <= min_line_size.height {
+                break line;
+            }
+            min_line_size.height = line.height;
+        };
+
+        y = line.location.y + line.height;
+        line_start = line.pieces.end;
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Lay out a run of inline-level items into line boxes, and set the final layout of each item in the run.
+/// The layout of a text run is set to the bounding box of its fragments.
+///
+/// Returns the generated line boxes and the content size contribution of the run.
+#[cfg(feature = "inline_layout")]
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn perform_final_layout_on_inline_run(
+    tree: &mut impl LayoutBlockContainer,
+    items: &mut [BlockItem],
+    container_outer_width: f32,
+    container_inner_width: f32,
+    offset: Point<f32>,
+    text_align: TextAlign,
+    direction: Direction,
+    float_context: &FloatContext,
+) -> (Vec<LineBox>, Size<f32>) {
+    let parent_size = Size { width: Some(container_outer_width), height: None };
+    let available_width = AvailableSpace::Definite(container_inner_width);
+    let pieces = generate_inline_pieces(tree, items, parent_size, available_width);
+
+    // Line boxes are shortened to fit beside any floats that overlap any part of their height. If a line's first
+    // piece doesn't fit beside the floats then the line is moved down until it does (or until there are no more
+    // floats beside it).
+    let line_spans =
+        break_inline_pieces_into_lines(&pieces, offset.y, |y, min_size| match float_context.find_band(y, min_size) {
+            (y, Some((left, right))) => (Point { x: left, y }, f32_max(0.0, right - left)),
+            (y, None) => (Point { x: offset.x, y }, container_inner_width),
+        });
+
+    // The bounding box (min and max corners) of the fragments of each item, and the first line each item appears in
+    let mut item_bounds: Vec<Option<(Point<f32>, Point<f32>)>> = (0..items.len()).map(|_| None).collect();
+    let mut item_first_line: Vec<Option<usize>> = (0..items.len()).map(|_| None).collect();
+
+    let mut lines = Vec::with_capacity(line_spans.len());
+    for (line_index, span) in line_spans.iter().enumerate() {
+        let line_pieces = &pieces[span.pieces.clone()];
+        let line_y = span.location.y;
+        let line_baseline = span.baseline;
+
+        let free_space = f32_max(0.0, span.available_width - span.width);
+        let line_start = span.location.x
+            + match text_align {
+                TextAlign::Auto | TextAlign::LegacyLeft => 0.0,
+                TextAlign::LegacyRight => free_space,
+                TextAlign::LegacyCenter => free_space / 2.0,
+            };
+        let line_end = line_start + span.width;
+
+        // The offset of the next piece from the inline-start edge of the line's content. Pieces are placed from the
+        // left edge of the content in left-to-right containers and from its right edge in right-to-left containers.
+        let mut inline_offset = 0.0;
+        let mut fragments: Vec<InlineFragment> = Vec::new();
+        for piece in line_pieces {
+            let node_id = items[piece.item_index].node_id;
+            let x = match direction {
+                Direction::Ltr => line_start + inline_offset,
+                Direction::Rtl => line_end - inline_offset - piece.width,
+            };
+            let y = line_y + line_baseline - piece.baseline;
+            match &piece.atomic {
+                Some((layout_output, margin)) => fragments.push(InlineFragment {
+                    node_id,
+                    segments: 0..0,
+                    location: Point { x: x + margin.left, y: y + margin.top },
+                    size: layout_output.size,
+                }),
+                None => match fragments.last_mut() {
+                    Some(fragment) if fragment.node_id == node_id && fragment.segments.end == piece.segment_index => {
+                        let fragment_start = f32_min(fragment.location.x, x);
+                        let fragment_end = f32_max(fragment.location.x + fragment.size.width, x + piece.width);
+                        fragment.segments.end += 1;
+                        fragment.location.x = fragment_start;
+                        fragment.size.width = fragment_end - fragment_start;
+                    }
+                    _ => fragments.push(InlineFragment {
+                        node_id,
+                        segments: piece.segment_index..piece.segment_index + 1,
+                        location: Point { x, y },
+                        size: Size { width: piece.width, height: piece.height },
+                    }),
+                },
+            }
+
+            let fragment = fragments.last().unwrap();
+            let fragment_end =
+                Point { x: fragment.location.x + fragment.size.width, y: fragment.location.y + fragment.size.height };
+            let bounds = &mut item_bounds[piece.item_index];
+            *bounds = match *bounds {
+                Some((min, max)) => Some((
+                    Point { x: f32_min(min.x, fragment.location.x), y: f32_min(min.y, fragment.location.y) },
+                    Point { x: f32_max(max.x, fragment_end.x), y: f32_max(max.y, fragment_end.y) },
+                )),
+                None => Some((fragment.location, fragment_end)),
+            };
+            item_first_line[piece.item_index].get_or_insert(line_index);
+
+            inline_offset += piece.width + piece.trailing_whitespace;
+        }
+
+        lines.push(LineBox {
+            location: span.location,
+            size: Size { width: span.available_width, height: span.height },
+            baseline: line_baseline,
+            fragments,
+        });
+    }
+    let line_y = lines.last().map_or(offset.y, |line| line.location.y + line.size.height);
+
+    // Set the final layout of atomic inline boxes
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut content_size = Size::ZERO;
+    let mut item_is_atomic: Vec<bool> = (0..items.len()).map(|_| false).collect();
+    for piece in pieces.iter() {
+        let Some((layout_output, margin)) = piece.atomic else { continue };
+        let item = &mut items[piece.item_index];
+        let location = item_bounds[piece.item_index].map(|(min, _)| min).unwrap_or(offset);
+        item_is_atomic[piece.item_index] = true;
+        item.computed_size = layout_output.size;
+        let scrollbar_size = child_scrollbar_size(
+            item.overflow,
+            item.scrollbar_width,
+            item.scrollbar_gutter,
+            layout_output.scrollbar_size,
+        );
+        tree.set_unrounded_layout(
+            item.node_id,
+            &Layout {
+                order: item.order,
+                size: layout_output.size,
+                #[cfg(feature = "content_size")]
+                content_size: layout_output.content_size,
+                scrollbar_size,
+                sticky_offset: Point::ZERO,
+                location,
+                padding: item.padding,
+                border: item.border,
+                margin,
+            },
+        );
+
+        #[cfg(feature = "content_size")]
+        {
+            content_size = content_size.f32_max(compute_content_size_contribution(
+                location,
+                layout_output.size,
+                layout_output.content_size,
+                item.overflow,
+            ));
+        }
+    }
+
+    // Set the final layout of text runs and position absolutely positioned items at the start of the line
+    // containing the next inline-level item
+    let can_be_collapsed_through = lines.is_empty();
+    for index in 0..items.len() {
+        if items[index].position.is_absolutely_positioned() {
+            let next_line = item_first_line[index..].iter().find_map(|line_index| *line_index);
+            let y = next_line.map(|line_index| lines[line_index].location.y).unwrap_or(line_y);
+            items[index].static_position = Point { x: offset.x, y };
+            continue;
+        }
+
+        let item = &mut items[index];
+        item.can_be_collapsed_through = can_be_collapsed_through;
+        if item_is_atomic[index] {
+            continue;
+        }
+
+        let (location, size) = match item_bounds[index] {
+            Some((min, max)) => (min, Size { width: max.x - min.x, height: max.y - min.y }),
+            None => (offset, Size::ZERO),
+        };
+        item.computed_size = size;
+        tree.set_unrounded_layout(
+            item.node_id,
+            &Layout {
+                order: item.order,
+                size,
+                #[cfg(feature = "content_size")]
+                content_size: size,
+                scrollbar_size: Size::ZERO,
+                sticky_offset: Point::ZERO,
+                location,
+                padding: Rect::zero(),
+                border: Rect::zero(),
+                margin: Rect::zero(),
+            },
+        );
+
+        #[cfg(feature = "content_size")]
+        {
+            content_size = content_size.f32_max(compute_content_size_contribution(location, size, size, item.overflow));
+        }
+    }
+
+    (lines, content_size)
 }
 
 /// Perform absolute layout on all absolutely positioned children.
@@ -582,6 +2051,7 @@ fn perform_absolute_layout_on_absolute_children(
     items: &[BlockItem],
     area_size: Size<f32>,
     area_offset: Point<f32>,
+    direction: Direction,
 ) -> Size<f32> {
     let area_width = area_size.width;
     let area_height = area_size.height;
@@ -589,16 +2059,17 @@ fn perform_absolute_layout_on_absolute_children(
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut absolute_content_size = Size::ZERO;
 
-    for item in items.iter().filter(|item| item.position == Position::Absolute) {
+    for item in items.iter().filter(|item| item.position.is_absolutely_positioned()) {
         let child_style = tree.get_block_child_style(item.node_id);
 
         // Skip items that are display:none or are not position:absolute
-        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
+        if child_style.box_generation_mode() == BoxGenerationMode::None
+            || !child_style.position().is_absolutely_positioned()
         {
             continue;
         }
 
-        let aspect_ratio = child_style.aspect_ratio();
+        let aspect_ratio = child_style.aspect_ratio().filter(|_| !child_style.aspect_ratio_auto());
         let margin =
             child_style.margin().map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(val, basis)));
         let padding = child_style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
@@ -631,6 +2102,24 @@ fn perform_absolute_layout_on_absolute_children(
             .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
+
+        // Stretch values fill the containing block minus the item's insets and margins
+        let stretch_size = Size {
+            width: area_width
+                - left.unwrap_or(0.0)
+                - right.unwrap_or(0.0)
+                - margin.left.unwrap_or(0.0)
+                - margin.right.unwrap_or(0.0),
+            height: area_height
+                - top.unwrap_or(0.0)
+                - bottom.unwrap_or(0.0)
+                - margin.top.unwrap_or(0.0)
+                - margin.bottom.unwrap_or(0.0),
+        }
+        .map(Some);
+        let style_size = resolve_stretch_sizes(child_style.size(), style_size, stretch_size);
+        let min_size = resolve_stretch_sizes(child_style.min_size(), min_size, stretch_size);
+        let max_size = resolve_stretch_sizes(child_style.max_size(), max_size, stretch_size);
         let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
 
         drop(child_style);
@@ -739,24 +2228,32 @@ fn perform_absolute_layout_on_absolute_children(
             bottom: margin.bottom.unwrap_or(auto_margin.bottom),
         };
 
+        // Over-constrained horizontal insets are resolved in favour of the inline-start inset
+        let x_from_left = left.map(|left| left + resolved_margin.left);
+        let x_from_right = right.map(|right| area_size.width - final_size.width - right - resolved_margin.right);
         let location = Point {
-            x: left
-                .map(|left| left + resolved_margin.left)
-                .or(right.map(|right| area_size.width - final_size.width - right - resolved_margin.right))
-                .maybe_add(area_offset.x)
-                .unwrap_or(item.static_position.x + resolved_margin.left),
+            x: match direction {
+                Direction::Ltr => x_from_left
+                    .or(x_from_right)
+                    .maybe_add(area_offset.x)
+                    .unwrap_or(item.static_position.x + resolved_margin.left),
+                Direction::Rtl => x_from_right
+                    .or(x_from_left)
+                    .maybe_add(area_offset.x)
+                    .unwrap_or(item.static_position.x - final_size.width - resolved_margin.right),
+            },
             y: top
                 .map(|top| top + resolved_margin.top)
                 .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
                 .maybe_add(area_offset.y)
                 .unwrap_or(item.static_position.y + resolved_margin.top),
         };
-        // Note: axis intentionally switched here as scrollbars take up space in the opposite axis
-        // to the axis in which scrolling is enabled.
-        let scrollbar_size = Size {
-            width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-            height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-        };
+        let scrollbar_size = child_scrollbar_size(
+            item.overflow,
+            item.scrollbar_width,
+            item.scrollbar_gutter,
+            layout_output.scrollbar_size,
+        );
 
         tree.set_unrounded_layout(
             item.node_id,
@@ -766,6 +2263,7 @@ fn perform_absolute_layout_on_absolute_children(
                 #[cfg(feature = "content_size")]
                 content_size: layout_output.content_size,
                 scrollbar_size,
+                sticky_offset: Point::ZERO,
                 location,
                 padding,
                 border,
@@ -773,8 +2271,9 @@ fn perform_absolute_layout_on_absolute_children(
             },
         );
 
+        // Fixed items are positioned relative to the viewport, so don't contribute to the content size of their parent
         #[cfg(feature = "content_size")]
-        {
+        if item.position != Position::Fixed {
             absolute_content_size = absolute_content_size.f32_max(compute_content_size_contribution(
                 location,
                 final_size,
@@ -1,16 +1,20 @@
 //! Generic CSS alignment code that is shared between both the Flexbox and CSS Grid algorithms.
-use crate::style::AlignContent;
+use crate::style::{AlignContent, AlignItems};
 
 /// Implement fallback alignment.
 ///
 /// In addition to the spec at https://www.w3.org/TR/css-align-3/ this implementation follows
 /// the resolution of https://github.com/w3c/csswg-drafts/issues/10154
+///
+/// Note that the fallback to `Start` is a fallback to the start edge of the axis in the writing mode. So in axes
+/// whose start edge is on the right this should be applied before swapping the `Start` and `End` values.
 pub(crate) fn apply_alignment_fallback(
     free_space: f32,
     num_items: usize,
     mut alignment_mode: AlignContent,
-    mut is_safe: bool,
 ) -> AlignContent {
+    let mut is_safe = alignment_mode.is_safe();
+
     // Fallback occurs in two cases:
 
     // 1. If there is only a single item being aligned and alignment is a distributed alignment keyword
@@ -33,6 +37,18 @@ pub(crate) fn apply_alignment_fallback(
     alignment_mode
 }
 
+/// Implement fallback alignment for self-alignment (`align-self` and `justify-self`): if the item overflows its
+/// alignment container (the free space is negative) then the "safe" alignment variants fallback to Start alignment.
+/// As with [`apply_alignment_fallback`], this should be applied before swapping the `Start` and `End` values.
+#[inline]
+pub(crate) fn apply_self_alignment_fallback(free_space: f32, alignment_mode: AlignItems) -> AlignItems {
+    if free_space < 0.0 && alignment_mode.is_safe() {
+        AlignItems::Start
+    } else {
+        alignment_mode
+    }
+}
+
 /// Generic alignment function that is used:
 ///   - For both align-content and justify-content alignment
 ///   - For both the Flexbox and CSS Grid algorithms
@@ -49,23 +65,23 @@ pub(crate) fn compute_alignment_offset(
 ) -> f32 {
     if is_first {
         match alignment_mode {
-            AlignContent::Start => 0.0,
-            AlignContent::FlexStart => {
+            AlignContent::Start | AlignContent::SafeStart => 0.0,
+            AlignContent::FlexStart | AlignContent::SafeFlexStart => {
                 if layout_is_flex_reversed {
                     free_space
                 } else {
                     0.0
                 }
             }
-            AlignContent::End => free_space,
-            AlignContent::FlexEnd => {
+            AlignContent::End | AlignContent::SafeEnd => free_space,
+            AlignContent::FlexEnd | AlignContent::SafeFlexEnd => {
                 if layout_is_flex_reversed {
                     0.0
                 } else {
                     free_space
                 }
             }
-            AlignContent::Center => free_space / 2.0,
+            AlignContent::Center | AlignContent::SafeCenter => free_space / 2.0,
             AlignContent::Stretch => 0.0,
             AlignContent::SpaceBetween => 0.0,
             AlignContent::SpaceAround => {
@@ -86,11 +102,11 @@ pub(crate) fn compute_alignment_offset(
     } else {
         let free_space = free_space.max(0.0);
         gap + match alignment_mode {
-            AlignContent::Start => 0.0,
-            AlignContent::FlexStart => 0.0,
-            AlignContent::End => 0.0,
-            AlignContent::FlexEnd => 0.0,
-            AlignContent::Center => 0.0,
+            AlignContent::Start | AlignContent::SafeStart => 0.0,
+            AlignContent::FlexStart | AlignContent::SafeFlexStart => 0.0,
+            AlignContent::End | AlignContent::SafeEnd => 0.0,
+            AlignContent::FlexEnd | AlignContent::SafeFlexEnd => 0.0,
+            AlignContent::Center | AlignContent::SafeCenter => 0.0,
             AlignContent::Stretch => 0.0,
             AlignContent::SpaceBetween => free_space / (num_items - 1) as f32,
             AlignContent::SpaceAround => free_space / num_items as f32,
@@ -0,0 +1,505 @@
+//! Layout of absolutely positioned nodes against a containing block other than their parent
+//!
+//! The layout algorithms lay out absolutely positioned children relative to their parent. When the parent is
+//! `Position::Static` it does not establish a containing block, and the node is instead laid out against the padding
+//! box of its nearest positioned ancestor. `Position::Fixed` nodes are laid out against the viewport instead. The
+//! position computed by the parent is used as the node's static position.
+//!
+//! Nodes positioned relative to an anchor are laid out here too, as their `anchor()` insets and `PositionArea` can
+//! only be resolved once the anchor has been laid out.
+//!
+//! The offsets of `Position::Sticky` nodes are also computed here, as they depend on the scroll offsets of the node's
+//! ancestors rather than on the layout of its parent alone.
+use crate::compute::common::scrollbars::{child_scrollbar_size, resolve_scrollbar_gutter_inset};
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+use crate::compute::common::sizing_keywords::resolve_stretch_sizes;
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{
+    AlignSelf, AnchorSide, AvailableSpace, BoxSizing, CoreStyle, Direction, LengthPercentageAuto, PositionArea,
+    PositionAreaSpan,
+};
+use crate::tree::{Layout, LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
+use crate::util::sys::{f32_max, f32_min};
+use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
+
+/// The padding box of a positioned node, which is the containing block of its absolutely positioned descendants
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ContainingBlock {
+    /// The node establishing the containing block
+    pub(crate) node: NodeId,
+    /// The offset of the padding box from the node's border box
+    pub(crate) offset: Point<f32>,
+    /// The size of the padding box
+    pub(crate) size: Size<f32>,
+    /// The inline base direction of the node
+    pub(crate) direction: Direction,
+}
+
+impl ContainingBlock {
+    /// The containing block established by `node`, given its final layout
+    pub(crate) fn new(tree: &impl LayoutPartialTree, node: NodeId, layout: &Layout) -> Self {
+        let direction = tree.resolve_direction(node);
+        let scrollbar_gutter = tree.get_core_container_style(node).scrollbar_gutter();
+        let inset = layout.border + resolve_scrollbar_gutter_inset(layout.scrollbar_size, scrollbar_gutter, direction);
+        Self {
+            node,
+            offset: Point { x: inset.left, y: inset.top },
+            size: Size {
+                width: f32_max(layout.size.width - inset.horizontal_axis_sum(), 0.0),
+                height: f32_max(layout.size.height - inset.vertical_axis_sum(), 0.0),
+            },
+            direction,
+        }
+    }
+}
+
+/// The insets and margins that an absolutely positioned node is laid out with, and how it is aligned between its
+/// insets if it is positioned in a [`PositionArea`]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct AbsolutePlacement {
+    /// The insets of the node resolved against its containing block, or `None` for `auto` insets
+    pub(crate) inset: Rect<Option<f32>>,
+    /// The margins of the node
+    pub(crate) margin: Rect<LengthPercentageAuto>,
+    /// The alignment of the node between its insets in each axis, or `None` if it is positioned by its insets alone
+    pub(crate) align: Point<Option<AlignSelf>>,
+}
+
+impl AbsolutePlacement {
+    /// The placement given by the insets and margins of `node`
+    pub(crate) fn new(tree: &impl LayoutPartialTree, node: NodeId, containing_block: &ContainingBlock) -> Self {
+        let style = tree.get_core_container_style(node);
+        let inset = style.inset();
+        let area_size = containing_block.size;
+        Self {
+            inset: Rect {
+                left: inset.left.maybe_resolve(area_size.width, |val, basis| tree.calc(val, basis)),
+                right: inset.right.maybe_resolve(area_size.width, |val, basis| tree.calc(val, basis)),
+                top: inset.top.maybe_resolve(area_size.height, |val, basis| tree.calc(val, basis)),
+                bottom: inset.bottom.maybe_resolve(area_size.height, |val, basis| tree.calc(val, basis)),
+            },
+            margin: style.margin(),
+            align: Point { x: None, y: None },
+        }
+    }
+
+    /// The placement of `node` relative to its anchor, whose border box is at `anchor` relative to the padding box of
+    /// the containing block. The insets and margins of the node are swapped in each axis in which `flip` is set.
+    pub(crate) fn anchored(
+        tree: &impl LayoutPartialTree,
+        node: NodeId,
+        containing_block: &ContainingBlock,
+        anchor: Rect<f32>,
+        position_area: Option<PositionArea>,
+        flip: Point<bool>,
+    ) -> Self {
+        let style = tree.get_core_container_style(node);
+        let inset = style.inset();
+        let margin = style.margin();
+        let (horizontal_inset, align_x) = resolve_anchored_axis(
+            tree,
+            inset.horizontal_components(),
+            anchor.horizontal_components(),
+            containing_block.size.width,
+            position_area.map(|area| area.x),
+            flip.x,
+        );
+        let (vertical_inset, align_y) = resolve_anchored_axis(
+            tree,
+            inset.vertical_components(),
+            anchor.vertical_components(),
+            containing_block.size.height,
+            position_area.map(|area| area.y),
+            flip.y,
+        );
+        Self {
+            inset: Rect {
+                left: horizontal_inset.start,
+                right: horizontal_inset.end,
+                top: vertical_inset.start,
+                bottom: vertical_inset.end,
+            },
+            margin: Rect {
+                left: if flip.x { margin.right } else { margin.left },
+                right: if flip.x { margin.left } else { margin.right },
+                top: if flip.y { margin.bottom } else { margin.top },
+                bottom: if flip.y { margin.top } else { margin.bottom },
+            },
+            align: Point { x: align_x, y: align_y },
+        }
+    }
+}
+
+/// Resolves the insets of an anchor-positioned node in one axis, returning them along with the alignment of the node
+/// between them (if it is positioned in a [`PositionArea`]).
+///
+/// `anchor` is the position of the start and end edges of the anchor relative to the start of the containing block,
+/// whose size in the axis is `space`. If `flip` is set then the insets are swapped, and the sides of `anchor()` insets
+/// are mirrored.
+fn resolve_anchored_axis(
+    tree: &impl LayoutPartialTree,
+    inset: Line<LengthPercentageAuto>,
+    anchor: Line<f32>,
+    space: f32,
+    span: Option<PositionAreaSpan>,
+    flip: bool,
+) -> (Line<Option<f32>>, Option<AlignSelf>) {
+    let inset = if flip { Line { start: inset.end, end: inset.start } } else { inset };
+
+    let Some(span) = span else {
+        // The position of the side of the anchor that an `anchor()` inset refers to, relative to the start of the
+        // containing block
+        let anchor_position = |side: AnchorSide, is_start: bool| {
+            let fraction = match side {
+                AnchorSide::Start => 0.0,
+                AnchorSide::End => 1.0,
+                AnchorSide::Center => 0.5,
+                AnchorSide::Percent(fraction) => fraction,
+                AnchorSide::Inside => return if is_start { anchor.start } else { anchor.end },
+                AnchorSide::Outside => return if is_start { anchor.end } else { anchor.start },
+            };
+            let fraction = if flip { 1.0 - fraction } else { fraction };
+            anchor.start + fraction * (anchor.end - anchor.start)
+        };
+        let resolve = |value: LengthPercentageAuto, is_start: bool| match value.anchor_side() {
+            Some(side) if is_start => Some(anchor_position(side, true)),
+            Some(side) => Some(space - anchor_position(side, false)),
+            None => value.maybe_resolve(space, |val, basis| tree.calc(val, basis)),
+        };
+        return (Line { start: resolve(inset.start, true), end: resolve(inset.end, false) }, None);
+    };
+
+    // The tracks spanned by the area, and the alignment towards the anchor within them
+    let span = if flip {
+        match span {
+            PositionAreaSpan::Start => PositionAreaSpan::End,
+            PositionAreaSpan::End => PositionAreaSpan::Start,
+            PositionAreaSpan::SpanStart => PositionAreaSpan::SpanEnd,
+            PositionAreaSpan::SpanEnd => PositionAreaSpan::SpanStart,
+            PositionAreaSpan::Center | PositionAreaSpan::SpanAll => span,
+        }
+    } else {
+        span
+    };
+    let (area, align) = match span {
+        PositionAreaSpan::Start => (Line { start: 0.0, end: anchor.start }, AlignSelf::End),
+        PositionAreaSpan::Center => (anchor, AlignSelf::Center),
+        PositionAreaSpan::End => (Line { start: anchor.end, end: space }, AlignSelf::Start),
+        PositionAreaSpan::SpanStart => (Line { start: 0.0, end: anchor.end }, AlignSelf::End),
+        PositionAreaSpan::SpanEnd => (Line { start: anchor.start, end: space }, AlignSelf::Start),
+        PositionAreaSpan::SpanAll => {
+            // The node is centered on the anchor, so the area only extends as far from the anchor's center as the
+            // containing block allows on both sides
+            let center = (anchor.start + anchor.end) / 2.0;
+            let extent = f32_max(f32_min(center, space - center), 0.0);
+            (Line { start: center - extent, end: center + extent }, AlignSelf::Center)
+        }
+    };
+
+    // Insets are resolved against the area, with `auto` insets treated as zero
+    let area_size = f32_max(area.end - area.start, 0.0);
+    let resolve =
+        |value: LengthPercentageAuto| value.resolve_or_zero(Some(area_size), |val, basis| tree.calc(val, basis));
+    (
+        Line { start: Some(area.start + resolve(inset.start)), end: Some(space - area.end + resolve(inset.end)) },
+        Some(align),
+    )
+}
+
+/// Returns true if the margin box of `layout` overflows the padding box of `containing_block`
+pub(crate) fn overflows_containing_block(layout: &Layout, containing_block: &ContainingBlock) -> bool {
+    // Allow for rounding errors in the positions computed from the insets
+    const EPSILON: f32 = 0.001;
+    let left = layout.location.x - layout.margin.left - containing_block.offset.x;
+    let top = layout.location.y - layout.margin.top - containing_block.offset.y;
+    let right = left + layout.margin.left + layout.size.width + layout.margin.right;
+    let bottom = top + layout.margin.top + layout.size.height + layout.margin.bottom;
+    left < -EPSILON
+        || top < -EPSILON
+        || right > containing_block.size.width + EPSILON
+        || bottom > containing_block.size.height + EPSILON
+}
+
+/// Lays out the absolutely positioned `node` within `containing_block` with the given `placement`, returning its
+/// layout relative to the border box of the node establishing the containing block.
+///
+/// `static_layout` is the layout computed for the node by its parent, translated to be relative to the containing
+/// block's node. It is used to position the node in axes in which both insets are `auto`. `static_direction` is the
+/// inline base direction of the parent.
+pub(crate) fn compute_absolute_layout_in_containing_block(
+    tree: &mut impl LayoutPartialTree,
+    node: NodeId,
+    containing_block: &ContainingBlock,
+    placement: &AbsolutePlacement,
+    static_layout: &Layout,
+    static_direction: Direction,
+) -> Layout {
+    let area_size = containing_block.size;
+    let area_offset = containing_block.offset;
+    let area_width = area_size.width;
+    let area_height = area_size.height;
+
+    let style = tree.get_core_container_style(node);
+    let aspect_ratio = style.aspect_ratio().filter(|_| !style.aspect_ratio_auto());
+    let margin =
+        placement.margin.map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(val, basis)));
+    let padding = style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+    let padding_border_sum = (padding + border).sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+    let overflow = style.overflow();
+    let scrollbar_width = style.scrollbar_width();
+    let scrollbar_gutter = style.scrollbar_gutter();
+
+    let Rect { left, right, top, bottom } = placement.inset;
+
+    // The space left for the node between its insets and margins
+    let inset_modified_size = Size {
+        width: area_width
+            - left.unwrap_or(0.0)
+            - right.unwrap_or(0.0)
+            - margin.left.unwrap_or(0.0)
+            - margin.right.unwrap_or(0.0),
+        height: area_height
+            - top.unwrap_or(0.0)
+            - bottom.unwrap_or(0.0)
+            - margin.top.unwrap_or(0.0)
+            - margin.bottom.unwrap_or(0.0),
+    };
+
+    // Compute known dimensions from min/max/inherent size styles
+    let style_size = style
+        .size()
+        .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let min_size = style
+        .min_size()
+        .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment)
+        .or(padding_border_sum.map(Some))
+        .maybe_max(padding_border_sum);
+    let max_size = style
+        .max_size()
+        .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+
+    // Stretch values fill the containing block minus the node's insets and margins
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+    let (style_size, min_size, max_size) = {
+        let stretch_size = inset_modified_size.map(Some);
+        (
+            resolve_stretch_sizes(style.size(), style_size, stretch_size),
+            resolve_stretch_sizes(style.min_size(), min_size, stretch_size),
+            resolve_stretch_sizes(style.max_size(), max_size, stretch_size),
+        )
+    };
+    let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+
+    drop(style);
+
+    // Fill in width from left/right and reapply aspect ratio if the width is not already known. Nodes aligned between
+    // their insets are instead sized to fit their content.
+    if let (None, Some(left), Some(right), None) = (known_dimensions.width, left, right, placement.align.x) {
+        let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
+        known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
+        known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+    }
+
+    // Fill in height from top/bottom and reapply aspect ratio if the height is not already known
+    if let (None, Some(top), Some(bottom), None) = (known_dimensions.height, top, bottom, placement.align.y) {
+        let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
+        known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
+        known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+    }
+
+    let layout_output = tree.perform_child_layout(
+        node,
+        known_dimensions,
+        area_size.map(Some),
+        Size {
+            width: AvailableSpace::Definite(
+                match placement.align.x {
+                    Some(_) => f32_max(inset_modified_size.width, 0.0),
+                    None => area_width,
+                }
+                .maybe_clamp(min_size.width, max_size.width),
+            ),
+            height: AvailableSpace::Definite(
+                match placement.align.y {
+                    Some(_) => f32_max(inset_modified_size.height, 0.0),
+                    None => area_height,
+                }
+                .maybe_clamp(min_size.height, max_size.height),
+            ),
+        },
+        SizingMode::ContentSize,
+        Line::FALSE,
+    );
+    let final_size = known_dimensions.unwrap_or(layout_output.size).maybe_clamp(min_size, max_size);
+
+    // Auto margins only resolve to a non-zero value if both insets are set in that axis (and the node is not aligned
+    // between them)
+    // https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
+    let resolve_auto_margins =
+        |start: Option<f32>, end: Option<f32>, inset: Line<Option<f32>>, space: f32, size: f32| {
+            let (Some(inset_start), Some(inset_end)) = (inset.start, inset.end) else {
+                return Line { start: start.unwrap_or(0.0), end: end.unwrap_or(0.0) };
+            };
+            let free_space = space - inset_start - inset_end - size - start.unwrap_or(0.0) - end.unwrap_or(0.0);
+            match (start, end) {
+                (None, None) => Line { start: f32_max(free_space / 2.0, 0.0), end: f32_max(free_space / 2.0, 0.0) },
+                (None, Some(end)) => Line { start: free_space, end },
+                (Some(start), None) => Line { start, end: free_space },
+                (Some(start), Some(end)) => Line { start, end },
+            }
+        };
+    let horizontal_inset = match placement.align.x {
+        Some(_) => Line { start: None, end: None },
+        None => Line { start: left, end: right },
+    };
+    let vertical_inset = match placement.align.y {
+        Some(_) => Line { start: None, end: None },
+        None => Line { start: top, end: bottom },
+    };
+    let horizontal_margin =
+        resolve_auto_margins(margin.left, margin.right, horizontal_inset, area_width, final_size.width);
+    let vertical_margin =
+        resolve_auto_margins(margin.top, margin.bottom, vertical_inset, area_height, final_size.height);
+    let resolved_margin = Rect {
+        left: horizontal_margin.start,
+        right: horizontal_margin.end,
+        top: vertical_margin.start,
+        bottom: vertical_margin.end,
+    };
+
+    // The static position is the margin box position computed by the parent. In right-to-left parents it is the
+    // position of the right edge of the margin box.
+    let static_position = Point {
+        x: match static_direction {
+            Direction::Ltr => static_layout.location.x - static_layout.margin.left,
+            Direction::Rtl => {
+                static_layout.location.x + static_layout.size.width + static_layout.margin.right
+                    - final_size.width
+                    - resolved_margin.horizontal_axis_sum()
+            }
+        },
+        y: static_layout.location.y - static_layout.margin.top,
+    };
+
+    // Over-constrained horizontal insets are resolved in favour of the inline-start inset of the containing block
+    let x_from_left = left.map(|left| left + resolved_margin.left);
+    let x_from_right = right.map(|right| area_width - final_size.width - right - resolved_margin.right);
+    let x = match containing_block.direction {
+        Direction::Ltr => x_from_left.or(x_from_right),
+        Direction::Rtl => x_from_right.or(x_from_left),
+    };
+    let y = top
+        .map(|top| top + resolved_margin.top)
+        .or(bottom.map(|bottom| area_height - final_size.height - bottom - resolved_margin.bottom));
+
+    // Nodes positioned in a `PositionArea` are aligned within the space left between their insets
+    let aligned_offset = |align: AlignSelf, free_space: f32| match align {
+        AlignSelf::End | AlignSelf::FlexEnd => free_space,
+        AlignSelf::Center => free_space / 2.0,
+        _ => 0.0,
+    };
+    let x = match placement.align.x {
+        Some(align) => Some(
+            left.unwrap_or(0.0)
+                + resolved_margin.left
+                + aligned_offset(align, inset_modified_size.width - final_size.width),
+        ),
+        None => x,
+    };
+    let y = match placement.align.y {
+        Some(align) => Some(
+            top.unwrap_or(0.0)
+                + resolved_margin.top
+                + aligned_offset(align, inset_modified_size.height - final_size.height),
+        ),
+        None => y,
+    };
+    let location = Point {
+        x: x.map(|x| x + area_offset.x).unwrap_or(static_position.x + resolved_margin.left),
+        y: y.map(|y| y + area_offset.y).unwrap_or(static_position.y + resolved_margin.top),
+    };
+
+    Layout {
+        order: static_layout.order,
+        location,
+        size: final_size,
+        #[cfg(feature = "content_size")]
+        content_size: layout_output.content_size,
+        scrollbar_size: child_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, layout_output.scrollbar_size),
+        sticky_offset: Point::ZERO,
+        border,
+        padding,
+        margin: resolved_margin,
+    }
+}
+
+/// The offset of the `Position::Sticky` `node` from its in-flow position, which keeps it within the visible area of
+/// its nearest scroll container (deflated by its insets) as far as its containing block allows.
+///
+/// `containing_block` and `scrollport` are the positions of the left, right, top and bottom edges of the content box of
+/// the node's parent and of the visible area of its nearest scroll container, relative to the node's parent.
+pub(crate) fn compute_sticky_offset(
+    tree: &impl LayoutPartialTree,
+    node: NodeId,
+    layout: &Layout,
+    containing_block: Rect<f32>,
+    scrollport: Rect<f32>,
+) -> Point<f32> {
+    let style = tree.get_core_container_style(node);
+    let scrollport_width = Some(scrollport.right - scrollport.left);
+    let scrollport_height = Some(scrollport.bottom - scrollport.top);
+    let inset = Rect {
+        left: style.inset().left.maybe_resolve(scrollport_width, |val, basis| tree.calc(val, basis)),
+        right: style.inset().right.maybe_resolve(scrollport_width, |val, basis| tree.calc(val, basis)),
+        top: style.inset().top.maybe_resolve(scrollport_height, |val, basis| tree.calc(val, basis)),
+        bottom: style.inset().bottom.maybe_resolve(scrollport_height, |val, basis| tree.calc(val, basis)),
+    };
+
+    Point {
+        x: sticky_axis_offset(
+            Line { start: inset.left, end: inset.right },
+            Line { start: scrollport.left, end: scrollport.right },
+            Line { start: containing_block.left, end: containing_block.right },
+            Line { start: layout.location.x, end: layout.location.x + layout.size.width },
+            Line { start: layout.margin.left, end: layout.margin.right },
+        ),
+        y: sticky_axis_offset(
+            Line { start: inset.top, end: inset.bottom },
+            Line { start: scrollport.top, end: scrollport.bottom },
+            Line { start: containing_block.top, end: containing_block.bottom },
+            Line { start: layout.location.y, end: layout.location.y + layout.size.height },
+            Line { start: layout.margin.top, end: layout.margin.bottom },
+        ),
+    }
+}
+
+/// The sticky offset of a node in a single axis. If the insets of both edges apply, the start inset wins.
+fn sticky_axis_offset(
+    inset: Line<Option<f32>>,
+    scrollport: Line<f32>,
+    containing_block: Line<f32>,
+    border_box: Line<f32>,
+    margin: Line<f32>,
+) -> f32 {
+    // The margin box of the node may not be moved out of its containing block
+    let max_forwards = f32_max(containing_block.end - border_box.end - margin.end, 0.0);
+    let max_backwards = f32_max(border_box.start - margin.start - containing_block.start, 0.0);
+
+    let from_start =
+        inset.start.map(|inset| scrollport.start + inset - border_box.start).filter(|offset| *offset > 0.0);
+    let from_end = inset.end.map(|inset| scrollport.end - inset - border_box.end).filter(|offset| *offset < 0.0);
+    match (from_start, from_end) {
+        (Some(offset), _) => f32_min(offset, max_forwards),
+        (None, Some(offset)) => f32_max(offset, -max_backwards),
+        (None, None) => 0.0,
+    }
+}
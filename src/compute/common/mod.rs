@@ -1,5 +1,20 @@
 //! Generic code that is shared between multiple layout algorithms
+#[cfg(any(feature = "flexbox", feature = "grid"))]
 pub(crate) mod alignment;
 
+#[cfg(all(
+    feature = "taffy_tree",
+    any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table")
+))]
+pub(crate) mod containing_block;
+
 #[cfg(feature = "content_size")]
 pub(crate) mod content_size;
+
+pub(crate) mod scrollbars;
+
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+pub(crate) mod sizing_keywords;
+
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+pub(crate) mod writing_mode;
@@ -0,0 +1,171 @@
+//! Reservation of space for scrollbars
+//!
+//! Space is always reserved for the scrollbar of an `Overflow::Scroll` axis. Whether an `Overflow::Auto` axis has a
+//! scrollbar depends on whether the node's content overflows it, which is only known once the node has been laid out.
+//! Containers are therefore laid out again once they are found to need a scrollbar, like browsers do. The
+//! `scrollbar_gutter` style can be used to reserve space for the inline-axis scrollbar regardless, or to never reserve
+//! any space for overlay scrollbars.
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{Direction, Overflow, ScrollbarGutter};
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+use crate::{
+    style::CoreStyle,
+    tree::{LayoutInput, LayoutOutput, LayoutPartialTree, NodeId},
+};
+#[cfg(all(
+    feature = "content_size",
+    any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table")
+))]
+use crate::{
+    tree::{LayoutPartialTreeExt, RunMode},
+    util::ResolveOrZero,
+};
+
+/// The size of the gutters reserved for a node's scrollbars on its end edges, given the scroll axes in which an
+/// `Overflow::Auto` node shows a scrollbar. This is the `scrollbar_size` reported in the node's [`Layout`](crate::Layout).
+///
+/// The axes are switched (transposed) because a node that scrolls vertically needs *horizontal* space to be reserved
+/// for a scrollbar.
+#[inline(always)]
+pub(crate) fn resolve_scrollbar_size(
+    overflow: Point<Overflow>,
+    scrollbar_width: f32,
+    scrollbar_gutter: ScrollbarGutter,
+    auto_scrollbars: Point<bool>,
+) -> Size<f32> {
+    let shown = |overflow: Overflow, auto_scrollbar: bool| match overflow {
+        Overflow::Scroll => true,
+        Overflow::Auto => auto_scrollbar,
+        _ => false,
+    };
+    // `scrollbar_gutter` only applies to the scrollbar in the inline axis
+    let inline_gutter = match scrollbar_gutter {
+        ScrollbarGutter::Auto => shown(overflow.y, auto_scrollbars.y),
+        ScrollbarGutter::Stable | ScrollbarGutter::StableBothEdges => overflow.y.is_scroll_container(),
+        ScrollbarGutter::Overlay => false,
+    };
+    let block_gutter = scrollbar_gutter != ScrollbarGutter::Overlay && shown(overflow.x, auto_scrollbars.x);
+    Size {
+        width: if inline_gutter { scrollbar_width } else { 0.0 },
+        height: if block_gutter { scrollbar_width } else { 0.0 },
+    }
+}
+
+/// The inset of a node's content box caused by its scrollbar gutters
+///
+/// The vertical scrollbar is placed on the inline-end side of the node. With `ScrollbarGutter::StableBothEdges` a gutter
+/// of the same size is also reserved on the inline-start side.
+#[inline(always)]
+pub(crate) fn resolve_scrollbar_gutter_inset(
+    scrollbar_size: Size<f32>,
+    scrollbar_gutter: ScrollbarGutter,
+    direction: Direction,
+) -> Rect<f32> {
+    let start = if scrollbar_gutter == ScrollbarGutter::StableBothEdges { scrollbar_size.width } else { 0.0 };
+    match direction {
+        Direction::Ltr => Rect { top: 0.0, left: start, right: scrollbar_size.width, bottom: scrollbar_size.height },
+        Direction::Rtl => Rect { top: 0.0, left: scrollbar_size.width, right: start, bottom: scrollbar_size.height },
+    }
+}
+
+/// The `Overflow::Auto` scroll axes in which a node's content overflows its padding box (less the space already
+/// reserved for scrollbars), and which therefore need a scrollbar.
+///
+/// `content_size` is measured from the node's border-box origin, as in [`Layout::scroll_width`](crate::Layout::scroll_width).
+#[inline(always)]
+pub(crate) fn overflowing_auto_axes(
+    overflow: Point<Overflow>,
+    scrollbar_size: Size<f32>,
+    border: Rect<f32>,
+    size: Size<f32>,
+    content_size: Size<f32>,
+) -> Point<bool> {
+    Point {
+        x: overflow.x == Overflow::Auto && content_size.width > size.width - border.right - scrollbar_size.width,
+        y: overflow.y == Overflow::Auto && content_size.height > size.height - border.bottom - scrollbar_size.height,
+    }
+}
+
+/// The size of the scrollbar gutters of a child node, as stored in its [`Layout`](crate::Layout).
+///
+/// This is determined by the child's styles, except in the axes in which it depends on whether the child's own layout
+/// found that its content overflows an `Overflow::Auto` axis.
+#[inline(always)]
+pub(crate) fn child_scrollbar_size(
+    overflow: Point<Overflow>,
+    scrollbar_width: f32,
+    scrollbar_gutter: ScrollbarGutter,
+    output_scrollbar_size: Size<f32>,
+) -> Size<f32> {
+    let without_auto_scrollbars =
+        resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, Point { x: false, y: false });
+    let with_auto_scrollbars =
+        resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, Point { x: true, y: true });
+    Size {
+        width: if without_auto_scrollbars.width == with_auto_scrollbars.width {
+            without_auto_scrollbars.width
+        } else {
+            output_scrollbar_size.width
+        },
+        height: if without_auto_scrollbars.height == with_auto_scrollbars.height {
+            without_auto_scrollbars.height
+        } else {
+            output_scrollbar_size.height
+        },
+    }
+}
+
+/// Lays out a container using `compute`, which is passed the scroll axes in which `Overflow::Auto` scrollbars are shown.
+///
+/// The container is first laid out without any `Overflow::Auto` scrollbars. If its content then overflows an
+/// `Overflow::Auto` axis it is laid out again with a scrollbar in that axis. Scrollbars are only ever added, so the
+/// container is laid out at most three times. As the content size is only computed when performing a full layout,
+/// `Overflow::Auto` scrollbars do not affect the size computed by other run modes. The final result is cached by
+/// the caller like any other, so the extra passes only happen when the container's layout is not already cached.
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+pub(crate) fn compute_with_auto_scrollbars<Tree: LayoutPartialTree>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    mut compute: impl FnMut(&mut Tree, NodeId, LayoutInput, Point<bool>) -> LayoutOutput,
+) -> LayoutOutput {
+    let style = tree.get_core_container_style(node);
+    let overflow = style.overflow();
+    let scrollbar_width = style.scrollbar_width();
+    let scrollbar_gutter = style.scrollbar_gutter();
+    drop(style);
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut auto_scrollbars = Point { x: false, y: false };
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut output = compute(tree, node, inputs, auto_scrollbars);
+
+    #[cfg(feature = "content_size")]
+    if inputs.run_mode == RunMode::PerformLayout
+        && scrollbar_width > 0.0
+        && (overflow.x == Overflow::Auto || overflow.y == Overflow::Auto)
+    {
+        let border = tree
+            .get_core_container_style(node)
+            .border()
+            .resolve_or_zero(inputs.parent_size.width, |val, basis| tree.calc(val, basis));
+        loop {
+            let scrollbar_size = resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, auto_scrollbars);
+            let overflowing = overflowing_auto_axes(overflow, scrollbar_size, border, output.size, output.content_size);
+            let needed = Point { x: auto_scrollbars.x || overflowing.x, y: auto_scrollbars.y || overflowing.y };
+            if needed == auto_scrollbars {
+                break;
+            }
+            auto_scrollbars = needed;
+
+            // The container only needs to be laid out again if showing the scrollbar changes the space reserved
+            // for it, which is not the case for stable gutters or overlay scrollbars
+            if resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, auto_scrollbars) != scrollbar_size {
+                output = compute(tree, node, inputs, auto_scrollbars);
+            }
+        }
+    }
+
+    let scrollbar_size = resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, auto_scrollbars);
+    LayoutOutput { scrollbar_size, ..output }
+}
@@ -0,0 +1,114 @@
+//! Resolution of the sizing keywords (`min-content`, `max-content`, `fit-content()` and `stretch`) in size styles
+//!
+//! These keywords cannot be resolved against the parent's size alone. The intrinsic sizing keywords are resolved by
+//! measuring the box's content under a min-content or max-content constraint, and `stretch` is resolved against the
+//! space left in the containing block once the box's margins have been accounted for.
+use crate::geometry::{AbsoluteAxis, Line, Size};
+use crate::style::{AvailableSpace, CompactLength, Dimension};
+use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
+use crate::util::sys::f32_max;
+
+/// Resolve a single intrinsic sizing keyword to a border-box size.
+///
+/// `measure` is called with the constraint (`MinContent` or `MaxContent`) under which the box's content should be
+/// measured, and should return the resulting border-box size. Returns `None` if the value is not a keyword.
+pub(crate) fn resolve_intrinsic_sizing_keyword(
+    dimension: Dimension,
+    parent_size: Option<f32>,
+    box_sizing_adjustment: f32,
+    mut measure: impl FnMut(AvailableSpace) -> f32,
+) -> Option<f32> {
+    match dimension.tag() {
+        CompactLength::MIN_CONTENT_TAG => Some(measure(AvailableSpace::MinContent)),
+        CompactLength::MAX_CONTENT_TAG => Some(measure(AvailableSpace::MaxContent)),
+        CompactLength::FIT_CONTENT_PX_TAG | CompactLength::FIT_CONTENT_PERCENT_TAG => {
+            let max_content_size = measure(AvailableSpace::MaxContent);
+
+            // An unresolvable percentage limit behaves as max-content
+            let limit = match dimension.tag() {
+                CompactLength::FIT_CONTENT_PX_TAG => Some(dimension.value()),
+                _ => parent_size.map(|size| size * dimension.value()),
+            };
+            let Some(limit) = limit else { return Some(max_content_size) };
+            let limit = limit + box_sizing_adjustment;
+
+            // fit-content(limit) = max(min-content, min(max-content, limit))
+            if limit >= max_content_size {
+                return Some(max_content_size);
+            }
+            let min_content_size = measure(AvailableSpace::MinContent);
+            Some(f32_max(limit, min_content_size))
+        }
+        _ => None,
+    }
+}
+
+/// Resolve any intrinsic sizing keywords in a child's `size`, `min_size` or `max_size` style by measuring the child.
+///
+/// `resolved` is the style resolved against the parent size (in which keywords resolve to `None`), and the return
+/// value is `resolved` with the keywords filled in. The width is resolved first, so that the height can be measured
+/// with a known width: either `known_width` or the width resolved by this call.
+pub(crate) fn resolve_child_intrinsic_sizing_keywords(
+    tree: &mut impl LayoutPartialTree,
+    child: NodeId,
+    style: Size<Dimension>,
+    resolved: Size<Option<f32>>,
+    known_width: Option<f32>,
+    parent_size: Size<Option<f32>>,
+    box_sizing_adjustment: Size<f32>,
+) -> Size<Option<f32>> {
+    if !style.width.is_intrinsic_sizing_keyword() && !style.height.is_intrinsic_sizing_keyword() {
+        return resolved;
+    }
+
+    let width = resolved.width.or_else(|| {
+        resolve_intrinsic_sizing_keyword(style.width, parent_size.width, box_sizing_adjustment.width, |space| {
+            tree.measure_child_size(
+                child,
+                Size::NONE,
+                parent_size,
+                Size { width: space, height: AvailableSpace::from(parent_size.height) },
+                SizingMode::ContentSize,
+                AbsoluteAxis::Horizontal,
+                Line::FALSE,
+            )
+        })
+    });
+
+    let known_width = known_width.or(width);
+    let height = resolved.height.or_else(|| {
+        resolve_intrinsic_sizing_keyword(style.height, parent_size.height, box_sizing_adjustment.height, |space| {
+            tree.measure_child_size(
+                child,
+                Size { width: known_width, height: None },
+                parent_size,
+                Size { width: AvailableSpace::from(parent_size.width).maybe_set(known_width), height: space },
+                SizingMode::ContentSize,
+                AbsoluteAxis::Vertical,
+                Line::FALSE,
+            )
+        })
+    });
+
+    Size { width, height }
+}
+
+/// Resolve any `stretch` values in a `size`, `min_size` or `max_size` style to `stretch_size`: the size of the box's
+/// containing block minus its margins. `stretch` behaves as `auto` in axes where `stretch_size` is indefinite.
+pub(crate) fn resolve_stretch_sizes(
+    style: Size<Dimension>,
+    resolved: Size<Option<f32>>,
+    stretch_size: Size<Option<f32>>,
+) -> Size<Option<f32>> {
+    let resolve = |style: Dimension, resolved: Option<f32>, stretch_size: Option<f32>| {
+        if style.is_stretch() {
+            stretch_size.map(|size| f32_max(size, 0.0))
+        } else {
+            resolved
+        }
+    };
+    Size {
+        width: resolve(style.width, resolved.width, stretch_size.width),
+        height: resolve(style.height, resolved.height, stretch_size.height),
+    }
+}
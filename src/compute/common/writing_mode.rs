@@ -0,0 +1,694 @@
+//! Support for laying out containers with a vertical writing mode
+//!
+//! The layout algorithms are written in terms of a horizontal writing mode: the inline axis is the horizontal axis
+//! and blocks are stacked from top to bottom. Containers with a vertical writing mode are laid out by wrapping the tree
+//! in a [`WritingModeTree`] which presents the container and its children to the algorithm in the container's
+//! logical coordinate space (where "width" is the inline size and "height" is the block size), and which maps the
+//! resulting layouts back into physical coordinates.
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{
+    BoxGenerationMode, BoxSizing, CoreStyle, Dimension, Direction, LengthPercentage, LengthPercentageAuto, Overflow,
+    Position, ScrollbarGutter, Visibility, WritingMode,
+};
+use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, RequestedAxis, TraversePartialTree};
+use crate::util::sys::Vec;
+
+#[cfg(feature = "inline_layout")]
+use crate::style::DisplayOutside;
+#[cfg(feature = "flexbox")]
+use crate::style::{
+    AlignContent, AlignItems, AlignSelf, FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle,
+    JustifyContent,
+};
+#[cfg(all(feature = "grid", not(feature = "flexbox")))]
+use crate::style::{AlignContent, AlignItems, AlignSelf, JustifyContent};
+#[cfg(feature = "block_layout")]
+use crate::style::{BlockContainerStyle, BlockItemStyle, Clear, ColumnFill, ColumnSpan, Float, TextAlign};
+#[cfg(feature = "flexbox")]
+use crate::LayoutFlexboxContainer;
+#[cfg(feature = "grid")]
+use crate::LayoutGridContainer;
+#[cfg(feature = "block_layout")]
+use crate::{compute::block::PlacedFloat, LayoutBlockContainer};
+#[cfg(feature = "grid")]
+use crate::{
+    compute::grid::SubgridTracks,
+    geometry::{AbsoluteAxis, Line},
+    style::{GridAutoFlow, GridContainerStyle, GridItemStyle, GridPlacement, GridTemplateArea, MasonryAutoFlow},
+    util::sys::{GridTrackVec, String},
+};
+
+#[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+use crate::compute::block::DetailedInlineInfo;
+#[cfg(feature = "inline_layout")]
+use crate::compute::block::ShapedTextRun;
+#[cfg(all(feature = "grid", feature = "detailed_layout_info"))]
+use crate::compute::grid::DetailedGridInfo;
+
+/// Swap the width and height of a size
+#[inline(always)]
+fn transpose<T>(size: Size<T>) -> Size<T> {
+    Size { width: size.height, height: size.width }
+}
+
+/// Convert a rect of physical edges into the logical edges of the writing mode
+#[inline(always)]
+fn rect_to_logical<T>(writing_mode: WritingMode, rect: Rect<T>) -> Rect<T> {
+    match writing_mode {
+        WritingMode::HorizontalTb => rect,
+        WritingMode::VerticalLr => Rect { left: rect.top, right: rect.bottom, top: rect.left, bottom: rect.right },
+        WritingMode::VerticalRl => Rect { left: rect.top, right: rect.bottom, top: rect.right, bottom: rect.left },
+    }
+}
+
+/// Convert a rect of logical edges in the writing mode into physical edges
+#[inline(always)]
+fn rect_to_physical<T>(writing_mode: WritingMode, rect: Rect<T>) -> Rect<T> {
+    match writing_mode {
+        WritingMode::HorizontalTb => rect,
+        WritingMode::VerticalLr => Rect { left: rect.top, right: rect.bottom, top: rect.left, bottom: rect.right },
+        WritingMode::VerticalRl => Rect { left: rect.bottom, right: rect.top, top: rect.left, bottom: rect.right },
+    }
+}
+
+/// Transpose the sizes and axis of a [`LayoutInput`]. As this is its own inverse, it is used to convert both
+/// physical inputs into logical inputs and logical inputs into physical inputs.
+#[inline(always)]
+fn transpose_input(inputs: LayoutInput) -> LayoutInput {
+    LayoutInput {
+        known_dimensions: transpose(inputs.known_dimensions),
+        parent_size: transpose(inputs.parent_size),
+        available_space: transpose(inputs.available_space),
+        axis: match inputs.axis {
+            RequestedAxis::Horizontal => RequestedAxis::Vertical,
+            RequestedAxis::Vertical => RequestedAxis::Horizontal,
+            RequestedAxis::Both => RequestedAxis::Both,
+        },
+        ..inputs
+    }
+}
+
+/// Convert the physical [`LayoutOutput`] of a child into the logical coordinate space of the writing mode
+#[inline(always)]
+fn output_to_logical(writing_mode: WritingMode, output: LayoutOutput) -> LayoutOutput {
+    let baselines_to_logical = |baselines: Point<Option<f32>>| Point {
+        x: baselines.y,
+        y: match writing_mode {
+            WritingMode::VerticalRl => baselines.x.map(|x| output.size.width - x),
+            _ => baselines.x,
+        },
+    };
+    LayoutOutput {
+        size: transpose(output.size),
+        #[cfg(feature = "content_size")]
+        content_size: transpose(output.content_size),
+        scrollbar_size: transpose(output.scrollbar_size),
+        first_baselines: baselines_to_logical(output.first_baselines),
+        last_baselines: baselines_to_logical(output.last_baselines),
+        ..output
+    }
+}
+
+/// Convert the logical [`LayoutOutput`] of a container into physical coordinates
+#[inline(always)]
+fn output_to_physical(writing_mode: WritingMode, output: LayoutOutput) -> LayoutOutput {
+    let baselines_to_physical = |baselines: Point<Option<f32>>| Point {
+        x: match writing_mode {
+            WritingMode::VerticalRl => baselines.y.map(|y| output.size.height - y),
+            _ => baselines.y,
+        },
+        y: baselines.x,
+    };
+    LayoutOutput {
+        size: transpose(output.size),
+        #[cfg(feature = "content_size")]
+        content_size: transpose(output.content_size),
+        scrollbar_size: transpose(output.scrollbar_size),
+        first_baselines: baselines_to_physical(output.first_baselines),
+        last_baselines: baselines_to_physical(output.last_baselines),
+        ..output
+    }
+}
+
+/// Convert the logical [`Layout`] of a child into physical coordinates, given the logical size of its container
+#[inline(always)]
+fn layout_to_physical(writing_mode: WritingMode, layout: Layout, container_size: Size<f32>) -> Layout {
+    Layout {
+        order: layout.order,
+        location: match writing_mode {
+            WritingMode::VerticalRl => {
+                Point { x: container_size.height - layout.location.y - layout.size.height, y: layout.location.x }
+            }
+            _ => layout.location.transpose(),
+        },
+        size: transpose(layout.size),
+        #[cfg(feature = "content_size")]
+        content_size: transpose(layout.content_size),
+        scrollbar_size: transpose(layout.scrollbar_size),
+        sticky_offset: layout.sticky_offset.transpose(),
+        border: rect_to_physical(writing_mode, layout.border),
+        padding: rect_to_physical(writing_mode, layout.padding),
+        margin: rect_to_physical(writing_mode, layout.margin),
+    }
+}
+
+/// Compute the layout of a container with a vertical writing mode
+///
+/// The `compute` closure runs the layout algorithm on a [`WritingModeTree`], which presents the container and its
+/// children in the container's logical coordinate space. The inputs and outputs of this function are physical.
+pub(crate) fn compute_in_writing_mode<Tree: LayoutPartialTree>(
+    tree: &mut Tree,
+    writing_mode: WritingMode,
+    inputs: LayoutInput,
+    compute: impl FnOnce(&mut WritingModeTree<'_, Tree>, LayoutInput) -> LayoutOutput,
+) -> LayoutOutput {
+    let mut logical_tree = WritingModeTree { tree, writing_mode, layouts: Vec::new() };
+    let output = compute(&mut logical_tree, transpose_input(inputs));
+
+    // The physical position of a child depends on the size of the container in vertical-rl mode,
+    // so the layouts of the children are only stored once the algorithm has finished.
+    let WritingModeTree { tree, layouts, .. } = logical_tree;
+    for (child, layout) in layouts {
+        tree.set_unrounded_layout(child, &layout_to_physical(writing_mode, layout, output.size));
+    }
+
+    output_to_physical(writing_mode, output)
+}
+
+/// A view of a tree in the logical coordinate space of a container with a vertical writing mode
+pub(crate) struct WritingModeTree<'t, Tree: LayoutPartialTree> {
+    /// The underlying (physical) tree
+    tree: &'t mut Tree,
+    /// The writing mode of the container being laid out
+    writing_mode: WritingMode,
+    /// The logical layouts of the container's children
+    layouts: Vec<(NodeId, Layout)>,
+}
+
+impl<Tree: LayoutPartialTree> TraversePartialTree for WritingModeTree<'_, Tree> {
+    type ChildIter<'a>
+        = Tree::ChildIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        self.tree.child_ids(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.tree.child_count(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.tree.get_child_id(parent_node_id, child_index)
+    }
+}
+
+impl<Tree: LayoutPartialTree> LayoutPartialTree for WritingModeTree<'_, Tree> {
+    type CoreContainerStyle<'a>
+        = WritingModeStyle<Tree::CoreContainerStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_> {
+        WritingModeStyle { style: self.tree.get_core_container_style(node_id), writing_mode: self.writing_mode }
+    }
+
+    #[inline(always)]
+    fn resolve_calc_value(&self, val: *const (), basis: f32) -> f32 {
+        self.tree.resolve_calc_value(val, basis)
+    }
+
+    #[inline(always)]
+    fn resolve_direction(&self, node_id: NodeId) -> Direction {
+        self.tree.resolve_direction(node_id)
+    }
+
+    #[inline(always)]
+    fn resolve_writing_mode(&self, node_id: NodeId) -> WritingMode {
+        self.tree.resolve_writing_mode(node_id)
+    }
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.layouts.push((node_id, *layout));
+    }
+
+    #[inline(always)]
+    fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        let output = self.tree.compute_child_layout(node_id, transpose_input(inputs));
+        output_to_logical(self.writing_mode, output)
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<Tree: LayoutFlexboxContainer> LayoutFlexboxContainer for WritingModeTree<'_, Tree> {
+    type FlexboxContainerStyle<'a>
+        = WritingModeStyle<Tree::FlexboxContainerStyle<'a>>
+    where
+        Self: 'a;
+
+    type FlexboxItemStyle<'a>
+        = WritingModeStyle<Tree::FlexboxItemStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_flexbox_container_style(&self, node_id: NodeId) -> Self::FlexboxContainerStyle<'_> {
+        WritingModeStyle { style: self.tree.get_flexbox_container_style(node_id), writing_mode: self.writing_mode }
+    }
+
+    #[inline(always)]
+    fn get_flexbox_child_style(&self, child_node_id: NodeId) -> Self::FlexboxItemStyle<'_> {
+        WritingModeStyle { style: self.tree.get_flexbox_child_style(child_node_id), writing_mode: self.writing_mode }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<Tree: LayoutGridContainer> LayoutGridContainer for WritingModeTree<'_, Tree> {
+    type GridContainerStyle<'a>
+        = WritingModeStyle<Tree::GridContainerStyle<'a>>
+    where
+        Self: 'a;
+
+    type GridItemStyle<'a>
+        = WritingModeStyle<Tree::GridItemStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_grid_container_style(&self, node_id: NodeId) -> Self::GridContainerStyle<'_> {
+        WritingModeStyle { style: self.tree.get_grid_container_style(node_id), writing_mode: self.writing_mode }
+    }
+
+    #[inline(always)]
+    fn get_grid_child_style(&self, child_node_id: NodeId) -> Self::GridItemStyle<'_> {
+        WritingModeStyle { style: self.tree.get_grid_child_style(child_node_id), writing_mode: self.writing_mode }
+    }
+
+    #[cfg(feature = "detailed_layout_info")]
+    #[inline(always)]
+    fn set_detailed_grid_info(&mut self, node_id: NodeId, detailed_grid_info: DetailedGridInfo) {
+        self.tree.set_detailed_grid_info(node_id, detailed_grid_info)
+    }
+
+    #[inline(always)]
+    fn get_subgrid_tracks(&self, node_id: NodeId) -> SubgridTracks {
+        self.tree.get_subgrid_tracks(node_id)
+    }
+
+    #[inline(always)]
+    fn set_subgrid_tracks(&mut self, node_id: NodeId, tracks: SubgridTracks) {
+        self.tree.set_subgrid_tracks(node_id, tracks)
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<Tree: LayoutBlockContainer> LayoutBlockContainer for WritingModeTree<'_, Tree> {
+    type BlockContainerStyle<'a>
+        = WritingModeStyle<Tree::BlockContainerStyle<'a>>
+    where
+        Self: 'a;
+
+    type BlockItemStyle<'a>
+        = WritingModeStyle<Tree::BlockItemStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_block_container_style(&self, node_id: NodeId) -> Self::BlockContainerStyle<'_> {
+        WritingModeStyle { style: self.tree.get_block_container_style(node_id), writing_mode: self.writing_mode }
+    }
+
+    #[inline(always)]
+    fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_> {
+        WritingModeStyle { style: self.tree.get_block_child_style(child_node_id), writing_mode: self.writing_mode }
+    }
+
+    #[cfg(feature = "inline_layout")]
+    #[inline(always)]
+    fn shape_text_run(&mut self, child_node_id: NodeId) -> Option<ShapedTextRun> {
+        self.tree.shape_text_run(child_node_id)
+    }
+
+    #[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+    #[inline(always)]
+    fn set_detailed_inline_info(&mut self, node_id: NodeId, detailed_inline_info: DetailedInlineInfo) {
+        self.tree.set_detailed_inline_info(node_id, detailed_inline_info)
+    }
+
+    #[inline(always)]
+    fn get_inherited_floats(&self, node_id: NodeId) -> Option<Vec<PlacedFloat>> {
+        self.tree.get_inherited_floats(node_id)
+    }
+
+    #[inline(always)]
+    fn set_inherited_floats(&mut self, node_id: NodeId, floats: Option<Vec<PlacedFloat>>) {
+        self.tree.set_inherited_floats(node_id, floats)
+    }
+
+    #[inline(always)]
+    fn get_placed_floats(&self, node_id: NodeId) -> Option<Vec<PlacedFloat>> {
+        self.tree.get_placed_floats(node_id)
+    }
+
+    #[inline(always)]
+    fn set_placed_floats(&mut self, node_id: NodeId, floats: Vec<PlacedFloat>) {
+        self.tree.set_placed_floats(node_id, floats)
+    }
+}
+
+/// A node's style as seen from the logical coordinate space of a container with the specified writing mode
+///
+/// Physical styles (sizes, edges and overflow) are mapped into the logical coordinate space. Styles that are
+/// already logical (alignment, gaps, grid tracks, flex direction, etc) are passed through unchanged.
+pub(crate) struct WritingModeStyle<S> {
+    /// The underlying (physical) style
+    style: S,
+    /// The writing mode of the container being laid out
+    writing_mode: WritingMode,
+}
+
+impl<S: CoreStyle> CoreStyle for WritingModeStyle<S> {
+    #[inline(always)]
+    fn box_generation_mode(&self) -> BoxGenerationMode {
+        self.style.box_generation_mode()
+    }
+    #[inline(always)]
+    fn is_block(&self) -> bool {
+        self.style.is_block()
+    }
+    #[inline(always)]
+    fn is_compressible_replaced(&self) -> bool {
+        self.style.is_compressible_replaced()
+    }
+    #[inline(always)]
+    fn box_sizing(&self) -> BoxSizing {
+        self.style.box_sizing()
+    }
+    #[inline(always)]
+    fn direction(&self) -> Option<Direction> {
+        self.style.direction()
+    }
+    #[inline(always)]
+    fn writing_mode(&self) -> Option<WritingMode> {
+        self.style.writing_mode()
+    }
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        self.style.visibility()
+    }
+    #[inline(always)]
+    fn overflow(&self) -> Point<Overflow> {
+        match self.writing_mode {
+            WritingMode::HorizontalTb => self.style.overflow(),
+            _ => self.style.overflow().transpose(),
+        }
+    }
+    #[inline(always)]
+    fn scrollbar_width(&self) -> f32 {
+        self.style.scrollbar_width()
+    }
+    #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        self.style.scrollbar_gutter()
+    }
+    #[inline(always)]
+    fn position(&self) -> Position {
+        self.style.position()
+    }
+    #[inline(always)]
+    fn inset(&self) -> Rect<LengthPercentageAuto> {
+        rect_to_logical(self.writing_mode, self.style.inset())
+    }
+    #[inline(always)]
+    fn size(&self) -> Size<Dimension> {
+        match self.writing_mode {
+            WritingMode::HorizontalTb => self.style.size(),
+            _ => transpose(self.style.size()),
+        }
+    }
+    #[inline(always)]
+    fn min_size(&self) -> Size<Dimension> {
+        match self.writing_mode {
+            WritingMode::HorizontalTb => self.style.min_size(),
+            _ => transpose(self.style.min_size()),
+        }
+    }
+    #[inline(always)]
+    fn max_size(&self) -> Size<Dimension> {
+        match self.writing_mode {
+            WritingMode::HorizontalTb => self.style.max_size(),
+            _ => transpose(self.style.max_size()),
+        }
+    }
+    #[inline(always)]
+    fn aspect_ratio(&self) -> Option<f32> {
+        match self.writing_mode {
+            WritingMode::HorizontalTb => self.style.aspect_ratio(),
+            _ => self.style.aspect_ratio().map(|ratio| 1.0 / ratio),
+        }
+    }
+    #[inline(always)]
+    fn aspect_ratio_auto(&self) -> bool {
+        self.style.aspect_ratio_auto()
+    }
+    #[inline(always)]
+    fn margin(&self) -> Rect<LengthPercentageAuto> {
+        rect_to_logical(self.writing_mode, self.style.margin())
+    }
+    #[inline(always)]
+    fn padding(&self) -> Rect<LengthPercentage> {
+        rect_to_logical(self.writing_mode, self.style.padding())
+    }
+    #[inline(always)]
+    fn border(&self) -> Rect<LengthPercentage> {
+        rect_to_logical(self.writing_mode, self.style.border())
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<S: FlexboxContainerStyle> FlexboxContainerStyle for WritingModeStyle<S> {
+    #[inline(always)]
+    fn flex_direction(&self) -> FlexDirection {
+        self.style.flex_direction()
+    }
+    #[inline(always)]
+    fn flex_wrap(&self) -> FlexWrap {
+        self.style.flex_wrap()
+    }
+    #[inline(always)]
+    fn gap(&self) -> Size<LengthPercentage> {
+        self.style.gap()
+    }
+    #[inline(always)]
+    fn align_content(&self) -> Option<AlignContent> {
+        self.style.align_content()
+    }
+    #[inline(always)]
+    fn align_items(&self) -> Option<AlignItems> {
+        self.style.align_items()
+    }
+    #[inline(always)]
+    fn justify_content(&self) -> Option<JustifyContent> {
+        self.style.justify_content()
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<S: FlexboxItemStyle> FlexboxItemStyle for WritingModeStyle<S> {
+    #[inline(always)]
+    fn flex_basis(&self) -> Dimension {
+        self.style.flex_basis()
+    }
+    #[inline(always)]
+    fn flex_grow(&self) -> f32 {
+        self.style.flex_grow()
+    }
+    #[inline(always)]
+    fn flex_shrink(&self) -> f32 {
+        self.style.flex_shrink()
+    }
+    #[inline(always)]
+    fn align_self(&self) -> Option<AlignSelf> {
+        self.style.align_self()
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.style.order()
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<S: GridContainerStyle> GridContainerStyle for WritingModeStyle<S> {
+    type TemplateTrackList<'a>
+        = S::TemplateTrackList<'a>
+    where
+        Self: 'a;
+    type AutoTrackList<'a>
+        = S::AutoTrackList<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn grid_template_rows(&self) -> Self::TemplateTrackList<'_> {
+        self.style.grid_template_rows()
+    }
+    #[inline(always)]
+    fn grid_template_columns(&self) -> Self::TemplateTrackList<'_> {
+        self.style.grid_template_columns()
+    }
+    #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<String>] {
+        self.style.grid_template_row_names()
+    }
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<String>] {
+        self.style.grid_template_column_names()
+    }
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea] {
+        self.style.grid_template_areas()
+    }
+    #[inline(always)]
+    fn grid_auto_rows(&self) -> Self::AutoTrackList<'_> {
+        self.style.grid_auto_rows()
+    }
+    #[inline(always)]
+    fn grid_auto_columns(&self) -> Self::AutoTrackList<'_> {
+        self.style.grid_auto_columns()
+    }
+    #[inline(always)]
+    fn subgrid_rows(&self) -> bool {
+        self.style.subgrid_rows()
+    }
+    #[inline(always)]
+    fn subgrid_columns(&self) -> bool {
+        self.style.subgrid_columns()
+    }
+    #[inline(always)]
+    fn grid_auto_flow(&self) -> GridAutoFlow {
+        self.style.grid_auto_flow()
+    }
+    #[inline(always)]
+    fn masonry_rows(&self) -> bool {
+        self.style.masonry_rows()
+    }
+    #[inline(always)]
+    fn masonry_columns(&self) -> bool {
+        self.style.masonry_columns()
+    }
+    #[inline(always)]
+    fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        self.style.masonry_auto_flow()
+    }
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        self.style.align_tracks()
+    }
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<AlignContent> {
+        self.style.justify_tracks()
+    }
+    #[inline(always)]
+    fn gap(&self) -> Size<LengthPercentage> {
+        self.style.gap()
+    }
+    #[inline(always)]
+    fn align_content(&self) -> Option<AlignContent> {
+        self.style.align_content()
+    }
+    #[inline(always)]
+    fn justify_content(&self) -> Option<JustifyContent> {
+        self.style.justify_content()
+    }
+    #[inline(always)]
+    fn align_items(&self) -> Option<AlignItems> {
+        self.style.align_items()
+    }
+    #[inline(always)]
+    fn justify_items(&self) -> Option<AlignItems> {
+        self.style.justify_items()
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<S: GridItemStyle> GridItemStyle for WritingModeStyle<S> {
+    #[inline(always)]
+    fn grid_row(&self) -> Line<GridPlacement> {
+        self.style.grid_row()
+    }
+    #[inline(always)]
+    fn grid_column(&self) -> Line<GridPlacement> {
+        self.style.grid_column()
+    }
+    #[inline(always)]
+    fn align_self(&self) -> Option<AlignSelf> {
+        self.style.align_self()
+    }
+    #[inline(always)]
+    fn justify_self(&self) -> Option<AlignSelf> {
+        self.style.justify_self()
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.style.order()
+    }
+    #[inline(always)]
+    fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        self.style.is_subgrid(axis)
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<S: BlockContainerStyle> BlockContainerStyle for WritingModeStyle<S> {
+    #[inline(always)]
+    fn text_align(&self) -> TextAlign {
+        self.style.text_align()
+    }
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        self.style.column_count()
+    }
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        self.style.column_width()
+    }
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        self.style.column_gap()
+    }
+    #[inline(always)]
+    fn column_fill(&self) -> ColumnFill {
+        self.style.column_fill()
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<S: BlockItemStyle> BlockItemStyle for WritingModeStyle<S> {
+    #[inline(always)]
+    fn is_table(&self) -> bool {
+        self.style.is_table()
+    }
+    #[inline(always)]
+    fn float(&self) -> Float {
+        self.style.float()
+    }
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        self.style.clear()
+    }
+    #[inline(always)]
+    fn column_span(&self) -> ColumnSpan {
+        self.style.column_span()
+    }
+    #[cfg(feature = "inline_layout")]
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        self.style.display_outside()
+    }
+}
@@ -1,9 +1,16 @@
 //! Computes the [flexbox](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) layout algorithm on [`TaffyTree`](crate::TaffyTree) according to the [spec](https://www.w3.org/TR/css-flexbox-1/)
 use crate::compute::common::alignment::compute_alignment_offset;
+use crate::compute::common::scrollbars::{
+    child_scrollbar_size, compute_with_auto_scrollbars, resolve_scrollbar_gutter_inset, resolve_scrollbar_size,
+};
+use crate::compute::common::sizing_keywords::{
+    resolve_child_intrinsic_sizing_keywords, resolve_intrinsic_sizing_keyword, resolve_stretch_sizes,
+};
+use crate::compute::common::writing_mode::compute_in_writing_mode;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{
-    AlignContent, AlignItems, AlignSelf, AvailableSpace, FlexWrap, JustifyContent, LengthPercentageAuto, Overflow,
-    Position,
+    AlignContent, AlignItems, AlignSelf, AvailableSpace, Direction, FlexWrap, JustifyContent, LengthPercentageAuto,
+    Overflow, Position, ScrollbarGutter, Visibility,
 };
 use crate::style::{CoreStyle, FlexDirection, FlexboxContainerStyle, FlexboxItemStyle};
 use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
@@ -15,7 +22,7 @@ use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxGenerationMode, BoxSizing};
 
-use super::common::alignment::apply_alignment_fallback;
+use super::common::alignment::{apply_alignment_fallback, apply_self_alignment_fallback};
 #[cfg(feature = "content_size")]
 use super::common::content_size::compute_content_size_contribution;
 
@@ -35,11 +42,17 @@ struct FlexItem {
     max_size: Size<Option<f32>>,
     /// The cross-alignment of this item
     align_self: AlignSelf,
+    /// Whether the item has `visibility: collapse`
+    is_collapsed: bool,
+    /// The cross size of the line that a collapsed item was placed in during the first round of layout
+    strut_size: f32,
 
     /// The overflow style of the item
     overflow: Point<Overflow>,
     /// The width of the scrollbars (if it has any)
     scrollbar_width: f32,
+    /// The space reserved for the item's scrollbars
+    scrollbar_gutter: ScrollbarGutter,
     /// The flex shrink style of the item
     flex_shrink: f32,
     /// The flex grow style of the item
@@ -84,6 +97,8 @@ struct FlexItem {
 
     /// The position of the bottom edge of this item
     baseline: f32,
+    /// The position of the last baseline of this item
+    last_baseline: f32,
 
     /// A temporary value for the main offset
     ///
@@ -102,6 +117,11 @@ impl FlexItem {
     fn is_scroll_container(&self) -> bool {
         self.overflow.x.is_scroll_container() | self.overflow.y.is_scroll_container()
     }
+
+    /// The distance between the item's last baseline and its hypothetical outer cross-end edge
+    fn last_baseline_descent(&self, constants: &AlgoConstants) -> f32 {
+        self.hypothetical_outer_size.cross(constants.dir) - self.last_baseline
+    }
 }
 
 /// A line of [`FlexItem`] used for intermediate computation
@@ -112,19 +132,26 @@ struct FlexLine<'a> {
     cross_size: f32,
     /// The relative offset of the cross-axis
     offset_cross: f32,
+    /// The largest strut size among the collapsed items in this line. The cross size of the line is floored by this value.
+    strut_size: f32,
 }
 
 /// Values that can be cached during the flexbox algorithm
+#[derive(Clone)]
 struct AlgoConstants {
-    /// The direction of the current segment being laid out
+    /// The direction of the current segment being laid out. In right-to-left containers the row directions are
+    /// swapped, as the main-start edge of a row is on the right.
     dir: FlexDirection,
+    /// The inline base direction of the container
+    direction: Direction,
     /// Is this segment a row
     is_row: bool,
     /// Is this segment a column
     is_column: bool,
     /// Is wrapping enabled (in either direction)
     is_wrap: bool,
-    /// Is the wrap direction inverted
+    /// Is the wrap direction inverted. In right-to-left column containers this is inverted relative to the
+    /// `flex_wrap` style, as the cross-start edge of a column is on the right.
     is_wrap_reverse: bool,
 
     /// The item's min_size style
@@ -140,6 +167,8 @@ struct AlgoConstants {
     content_box_inset: Rect<f32>,
     /// The size reserved for scrollbar gutters in each axis
     scrollbar_gutter: Point<f32>,
+    /// The size reserved for scrollbar gutters on each side
+    scrollbar_gutter_inset: Rect<f32>,
     /// The gap of this section
     gap: Size<f32>,
     /// The align_items property of this node
@@ -166,6 +195,17 @@ pub fn compute_flexbox_layout(
     node: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
+    let writing_mode = tree.resolve_writing_mode(node);
+    if writing_mode.is_vertical() {
+        return compute_in_writing_mode(tree, writing_mode, inputs, |tree, inputs| {
+            compute_logical_layout(tree, node, inputs)
+        });
+    }
+    compute_logical_layout(tree, node, inputs)
+}
+
+/// Computes the layout of a box according to the flexbox algorithm in the logical coordinate space of the box
+fn compute_logical_layout(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
     let style = tree.get_flexbox_container_style(node);
 
@@ -219,15 +259,28 @@ pub fn compute_flexbox_layout(
     debug_log!("FLEX:", dbg:style.flex_direction());
     drop(style);
 
-    compute_preliminary(tree, node, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+    let inputs = LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs };
+    compute_with_auto_scrollbars(tree, node, inputs, compute_preliminary)
 }
 
 /// Compute a preliminary size for an item
-fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+fn compute_preliminary(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    inputs: LayoutInput,
+    auto_scrollbars: Point<bool>,
+) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
     // Define some general constants we will need for the remainder of the algorithm.
-    let mut constants = compute_constants(tree, tree.get_flexbox_container_style(node), known_dimensions, parent_size);
+    let mut constants = compute_constants(
+        tree,
+        tree.get_flexbox_container_style(node),
+        tree.resolve_direction(node),
+        known_dimensions,
+        parent_size,
+        auto_scrollbars,
+    );
 
     // 9. Flex Layout Algorithm
 
@@ -236,6 +289,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     // 1. Generate anonymous flex items as described in §4 Flex Items.
     debug_log!("generate_anonymous_flex_items");
     let mut flex_items = generate_anonymous_flex_items(tree, node, &constants);
+    resolve_flex_item_sizing_keywords(tree, &constants, &mut flex_items);
 
     // 9.2. Line Length Determination
 
@@ -259,66 +313,6 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     // 4. Determine the main size of the flex container
     // This has already been done as part of compute_constants. The inner size is exposed as constants.node_inner_size.
 
-    // 9.3. Main Size Determination
-
-    // 5. Collect flex items into flex lines.
-    debug_log!("collect_flex_lines");
-    let mut flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items);
-
-    // If container size is undefined, determine the container's main size
-    // and then re-resolve gaps based on newly determined size
-    debug_log!("determine_container_main_size");
-    if let Some(inner_main_size) = constants.node_inner_size.main(constants.dir) {
-        let outer_main_size = inner_main_size + constants.content_box_inset.main_axis_sum(constants.dir);
-        constants.inner_container_size.set_main(constants.dir, inner_main_size);
-        constants.container_size.set_main(constants.dir, outer_main_size);
-    } else {
-        // Sets constants.container_size and constants.outer_container_size
-        determine_container_main_size(tree, available_space, &mut flex_lines, &mut constants);
-        constants.node_inner_size.set_main(constants.dir, Some(constants.inner_container_size.main(constants.dir)));
-        constants.node_outer_size.set_main(constants.dir, Some(constants.container_size.main(constants.dir)));
-
-        debug_log!("constants.node_outer_size", dbg:constants.node_outer_size);
-        debug_log!("constants.node_inner_size", dbg:constants.node_inner_size);
-
-        // Re-resolve percentage gaps
-        let style = tree.get_flexbox_container_style(node);
-        let inner_container_size = constants.inner_container_size.main(constants.dir);
-        let new_gap = style
-            .gap()
-            .main(constants.dir)
-            .maybe_resolve(inner_container_size, |val, basis| tree.calc(val, basis))
-            .unwrap_or(0.0);
-        constants.gap.set_main(constants.dir, new_gap);
-    }
-
-    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
-    debug_log!("resolve_flexible_lengths");
-    for line in &mut flex_lines {
-        resolve_flexible_lengths(line, &constants);
-    }
-
-    // 9.4. Cross Size Determination
-
-    // 7. Determine the hypothetical cross size of each item.
-    debug_log!("determine_hypothetical_cross_size");
-    for line in &mut flex_lines {
-        determine_hypothetical_cross_size(tree, line, &constants, available_space);
-    }
-
-    // Calculate child baselines. This function is internally smart and only computes child baselines
-    // if they are necessary.
-    debug_log!("calculate_children_base_lines");
-    calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, &constants);
-
-    // 8. Calculate the cross size of each flex line.
-    debug_log!("calculate_cross_size");
-    calculate_cross_size(&mut flex_lines, known_dimensions, &constants);
-
-    // 9. Handle 'align-content: stretch'.
-    debug_log!("handle_align_content_stretch");
-    handle_align_content_stretch(&mut flex_lines, known_dimensions, &constants);
-
     // 10. Collapse visibility:collapse items. If any flex items have visibility: collapse,
     //     note the cross size of the line they’re in as the item’s strut size, and restart
     //     layout from the beginning.
@@ -331,8 +325,44 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     //     that strut size.
     //
     //     Skip this step in the second layout round.
+    //
+    // Note: the flex base sizes computed above are unaffected by collapsed items, so layout is restarted from step 5.
+    let has_collapsed_items = flex_items.iter().any(|item| item.is_collapsed);
+    if has_collapsed_items {
+        debug_log!("first_round_for_collapsed_items");
+        let mut first_round_constants = constants.clone();
+        let flex_lines = determine_flex_lines(
+            tree,
+            node,
+            known_dimensions,
+            available_space,
+            &mut flex_items,
+            &mut first_round_constants,
+            false,
+        );
+        for line in flex_lines {
+            for item in line.items.iter_mut().filter(|item| item.is_collapsed) {
+                item.strut_size = line.cross_size;
+            }
+        }
+        for item in flex_items.iter_mut() {
+            item.frozen = false;
+            if item.is_collapsed {
+                item.hypothetical_outer_size.set_main(constants.dir, 0.0);
+            }
+        }
+    }
 
-    // TODO implement once (if ever) we support visibility:collapse
+    // Steps 5 to 9
+    let mut flex_lines = determine_flex_lines(
+        tree,
+        node,
+        known_dimensions,
+        available_space,
+        &mut flex_items,
+        &mut constants,
+        has_collapsed_items,
+    );
 
     // 11. Determine the used cross size of each flex item.
     debug_log!("determine_used_cross_size");
@@ -368,15 +398,22 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     debug_log!("final_layout_pass");
     let inflow_content_size = final_layout_pass(tree, &mut flex_lines, &constants);
 
-    // Before returning we perform absolute layout on all absolutely positioned children
+    // Before returning we perform absolute layout on all absolutely positioned children. Those of a `Position::Static`
+    // container are laid out again against their actual containing block by `TaffyTree`, so they don't contribute to
+    // its content size.
     debug_log!("perform_absolute_layout_on_absolute_children");
-    let absolute_content_size = perform_absolute_layout_on_absolute_children(tree, node, &constants);
+    let mut absolute_content_size = perform_absolute_layout_on_absolute_children(tree, node, &constants);
+    if !tree.get_flexbox_container_style(node).position().is_positioned() {
+        absolute_content_size = Size::ZERO;
+    }
 
     debug_log!("hidden_layout");
     let len = tree.child_count(node);
     for order in 0..len {
         let child = tree.get_child_id(node, order);
-        if tree.get_flexbox_child_style(child).box_generation_mode() == BoxGenerationMode::None {
+        let child_style = tree.get_flexbox_child_style(child);
+        if child_style.box_generation_mode() == BoxGenerationMode::None {
+            drop(child_style);
             tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
             tree.perform_child_layout(
                 child,
@@ -386,45 +423,164 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
                 SizingMode::InherentSize,
                 Line::FALSE,
             );
+        } else if has_collapsed_items
+            && child_style.visibility() == Visibility::Collapse
+            && !child_style.position().is_absolutely_positioned()
+        {
+            // Collapsed items are laid out as if they were `display: none`
+            drop(child_style);
+            tree.compute_child_layout(child, LayoutInput::HIDDEN);
+            tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
         }
     }
 
-    // 8.5. Flex Container Baselines: calculate the flex container's first baseline
+    // 8.5. Flex Container Baselines: calculate the flex container's first and last baselines
     // See https://www.w3.org/TR/css-flexbox-1/#flex-baselines
-    let first_vertical_baseline = if flex_lines.is_empty() {
-        None
-    } else {
-        flex_lines[0]
-            .items
+    let first_vertical_baseline = flex_lines.first().and_then(|line| {
+        line.items
             .iter()
             .find(|item| constants.is_column || item.align_self == AlignSelf::Baseline)
-            .or_else(|| flex_lines[0].items.iter().next())
+            .or_else(|| line.items.first())
             .map(|child| {
                 let offset_vertical = if constants.is_row { child.offset_cross } else { child.offset_main };
                 offset_vertical + child.baseline
             })
-    };
+    });
+    let last_vertical_baseline = flex_lines.last().and_then(|line| {
+        line.items
+            .iter()
+            .rev()
+            .find(|item| constants.is_column || item.align_self == AlignSelf::LastBaseline)
+            .or_else(|| line.items.last())
+            .map(|child| {
+                let offset_vertical = if constants.is_row { child.offset_cross } else { child.offset_main };
+                offset_vertical + child.last_baseline
+            })
+    });
 
-    LayoutOutput::from_sizes_and_baselines(
+    LayoutOutput::from_sizes_and_first_and_last_baselines(
         constants.container_size,
         inflow_content_size.f32_max(absolute_content_size),
         Point { x: None, y: first_vertical_baseline },
+        Point { x: None, y: last_vertical_baseline },
     )
 }
 
+/// Collect the flex items into flex lines and determine the cross size of each line (steps 5 to 9 of the algorithm)
+///
+/// If `collapse` is true then this is the second layout round for a container with `visibility: collapse` items.
+#[inline]
+fn determine_flex_lines<'a>(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    flex_items: &'a mut Vec<FlexItem>,
+    constants: &mut AlgoConstants,
+    collapse: bool,
+) -> Vec<FlexLine<'a>> {
+    // 9.3. Main Size Determination
+
+    // 5. Collect flex items into flex lines.
+    debug_log!("collect_flex_lines");
+    let mut flex_lines = collect_flex_lines(constants, available_space, flex_items);
+
+    // In the second layout round collapsed items are removed from their lines (after contributing to line breaking
+    // with a zero main size) and only contribute their strut size to the cross size of their line.
+    if collapse {
+        for line in flex_lines.iter_mut() {
+            let items = core::mem::take(&mut line.items);
+            // `order` is the item's index in the tree, so visible items keep their relative order
+            items.sort_unstable_by_key(|item| (item.is_collapsed, item.order));
+            let visible_item_count = items.iter().take_while(|item| !item.is_collapsed).count();
+            let (visible_items, collapsed_items) = items.split_at_mut(visible_item_count);
+            line.strut_size = collapsed_items.iter().map(|item| item.strut_size).fold(0.0, f32_max);
+            line.items = visible_items;
+        }
+    }
+
+    // If container size is undefined, determine the container's main size
+    // and then re-resolve gaps based on newly determined size
+    debug_log!("determine_container_main_size");
+    if let Some(inner_main_size) = constants.node_inner_size.main(constants.dir) {
+        let outer_main_size = inner_main_size + constants.content_box_inset.main_axis_sum(constants.dir);
+        constants.inner_container_size.set_main(constants.dir, inner_main_size);
+        constants.container_size.set_main(constants.dir, outer_main_size);
+    } else {
+        // Sets constants.container_size and constants.outer_container_size
+        determine_container_main_size(tree, available_space, &mut flex_lines, constants);
+        constants.node_inner_size.set_main(constants.dir, Some(constants.inner_container_size.main(constants.dir)));
+        constants.node_outer_size.set_main(constants.dir, Some(constants.container_size.main(constants.dir)));
+
+        debug_log!("constants.node_outer_size", dbg:constants.node_outer_size);
+        debug_log!("constants.node_inner_size", dbg:constants.node_inner_size);
+
+        // Re-resolve percentage gaps
+        let style = tree.get_flexbox_container_style(node);
+        let inner_container_size = constants.inner_container_size.main(constants.dir);
+        let new_gap = style
+            .gap()
+            .main(constants.dir)
+            .maybe_resolve(inner_container_size, |val, basis| tree.calc(val, basis))
+            .unwrap_or(0.0);
+        constants.gap.set_main(constants.dir, new_gap);
+    }
+
+    // 6. Resolve the flexible lengths of all the flex items to find their used main size.
+    debug_log!("resolve_flexible_lengths");
+    for line in &mut flex_lines {
+        resolve_flexible_lengths(line, constants);
+    }
+
+    // 9.4. Cross Size Determination
+
+    // 7. Determine the hypothetical cross size of each item.
+    debug_log!("determine_hypothetical_cross_size");
+    for line in &mut flex_lines {
+        determine_hypothetical_cross_size(tree, line, constants, available_space);
+    }
+
+    // Calculate child baselines. This function is internally smart and only computes child baselines
+    // if they are necessary.
+    debug_log!("calculate_children_base_lines");
+    calculate_children_base_lines(tree, known_dimensions, available_space, &mut flex_lines, constants);
+
+    // 8. Calculate the cross size of each flex line.
+    debug_log!("calculate_cross_size");
+    calculate_cross_size(&mut flex_lines, known_dimensions, constants);
+    if collapse {
+        for line in flex_lines.iter_mut() {
+            line.cross_size = f32_max(line.cross_size, line.strut_size);
+        }
+    }
+
+    // 9. Handle 'align-content: stretch'.
+    debug_log!("handle_align_content_stretch");
+    handle_align_content_stretch(&mut flex_lines, known_dimensions, constants);
+
+    flex_lines
+}
+
 /// Compute constants that can be reused during the flexbox algorithm.
 #[inline]
 fn compute_constants(
     tree: &impl LayoutFlexboxContainer,
     style: impl FlexboxContainerStyle,
+    direction: Direction,
     known_dimensions: Size<Option<f32>>,
     parent_size: Size<Option<f32>>,
+    auto_scrollbars: Point<bool>,
 ) -> AlgoConstants {
-    let dir = style.flex_direction();
+    let is_rtl = direction.is_rtl();
+    let dir = match (style.flex_direction(), is_rtl) {
+        (FlexDirection::Row, true) => FlexDirection::RowReverse,
+        (FlexDirection::RowReverse, true) => FlexDirection::Row,
+        (dir, _) => dir,
+    };
     let is_row = dir.is_row();
     let is_column = dir.is_column();
     let is_wrap = matches!(style.flex_wrap(), FlexWrap::Wrap | FlexWrap::WrapReverse);
-    let is_wrap_reverse = style.flex_wrap() == FlexWrap::WrapReverse;
+    let is_wrap_reverse = (style.flex_wrap() == FlexWrap::WrapReverse) != (is_rtl && is_column);
 
     let aspect_ratio = style.aspect_ratio();
     let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
@@ -438,17 +594,14 @@ fn compute_constants(
     let align_content = style.align_content().unwrap_or(AlignContent::Stretch);
     let justify_content = style.justify_content();
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding + border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`, or to
+    // `Overflow::Auto` and the content was found to overflow the node, unless overridden by `scrollbar_gutter`.
+    let scrollbar_size =
+        resolve_scrollbar_size(style.overflow(), style.scrollbar_width(), style.scrollbar_gutter(), auto_scrollbars);
+    let scrollbar_gutter_inset = resolve_scrollbar_gutter_inset(scrollbar_size, style.scrollbar_gutter(), direction);
+    let scrollbar_gutter =
+        Point { x: scrollbar_gutter_inset.horizontal_axis_sum(), y: scrollbar_gutter_inset.vertical_axis_sum() };
+    let content_box_inset = padding + border + scrollbar_gutter_inset;
 
     let node_outer_size = known_dimensions;
     let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes());
@@ -459,6 +612,7 @@ fn compute_constants(
 
     AlgoConstants {
         dir,
+        direction,
         is_row,
         is_column,
         is_wrap,
@@ -478,6 +632,7 @@ fn compute_constants(
         gap,
         content_box_inset,
         scrollbar_gutter,
+        scrollbar_gutter_inset,
         align_items,
         align_content,
         justify_content,
@@ -488,6 +643,29 @@ fn compute_constants(
     }
 }
 
+impl AlgoConstants {
+    /// Resolve the physical `Start` and `End` alignment of an item in the cross axis. In right-to-left column
+    /// containers the cross axis is the inline axis, whose start edge is on the right.
+    #[inline(always)]
+    fn resolve_cross_alignment(&self, align_self: AlignSelf) -> AlignSelf {
+        if self.is_column && self.direction.is_rtl() {
+            align_self.swap_start_end()
+        } else {
+            align_self
+        }
+    }
+
+    /// Resolve over-constrained horizontal insets. In right-to-left containers the `right` inset takes precedence
+    /// over the `left` inset when both are set.
+    #[inline(always)]
+    fn resolve_horizontal_inset(&self, inset: Rect<Option<f32>>) -> Rect<Option<f32>> {
+        match (self.direction, inset.right) {
+            (Direction::Rtl, Some(_)) => Rect { left: None, ..inset },
+            _ => inset,
+        }
+    }
+}
+
 /// Generate anonymous flex items.
 ///
 /// # [9.1. Initial Setup](https://www.w3.org/TR/css-flexbox-1/#box-manip)
@@ -499,13 +677,16 @@ fn generate_anonymous_flex_items(
     node: NodeId,
     constants: &AlgoConstants,
 ) -> Vec<FlexItem> {
-    tree.child_ids(node)
+    let mut flex_items: Vec<FlexItem> = tree
+        .child_ids(node)
         .enumerate()
         .map(|(index, child)| (index, child, tree.get_flexbox_child_style(child)))
-        .filter(|(_, _, style)| style.position() != Position::Absolute)
+        .filter(|(_, _, style)| !style.position().is_absolutely_positioned())
         .filter(|(_, _, style)| style.box_generation_mode() != BoxGenerationMode::None)
         .map(|(index, child, child_style)| {
-            let aspect_ratio = child_style.aspect_ratio();
+            // An `auto <ratio>` aspect ratio may be overridden by the natural aspect ratio of the child's content, so
+            // it is left to the child's own layout to apply it
+            let aspect_ratio = child_style.aspect_ratio().filter(|_| !child_style.aspect_ratio_auto());
             let padding = child_style
                 .padding()
                 .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis));
@@ -534,9 +715,15 @@ fn generate_anonymous_flex_items(
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
 
-                inset: child_style
-                    .inset()
-                    .zip_size(constants.node_inner_size, |p, s| p.maybe_resolve(s, |val, basis| tree.calc(val, basis))),
+                // Offsets do not apply to statically positioned items, and only constrain sticky items once scrolled
+                inset: match child_style.position() {
+                    Position::Static | Position::Sticky => Rect { left: None, right: None, top: None, bottom: None },
+                    _ => constants.resolve_horizontal_inset(
+                        child_style.inset().zip_size(constants.node_inner_size, |p, s| {
+                            p.maybe_resolve(s, |val, basis| tree.calc(val, basis))
+                        }),
+                    ),
+                },
                 margin: child_style
                     .margin()
                     .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
@@ -548,8 +735,11 @@ fn generate_anonymous_flex_items(
                     .border()
                     .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
                 align_self: child_style.align_self().unwrap_or(constants.align_items),
+                is_collapsed: child_style.visibility() == Visibility::Collapse,
+                strut_size: 0.0,
                 overflow: child_style.overflow(),
                 scrollbar_width: child_style.scrollbar_width(),
+                scrollbar_gutter: child_style.scrollbar_gutter(),
                 flex_grow: child_style.flex_grow(),
                 flex_shrink: child_style.flex_shrink(),
                 flex_basis: 0.0,
@@ -565,12 +755,77 @@ fn generate_anonymous_flex_items(
                 content_flex_fraction: 0.0,
 
                 baseline: 0.0,
+                last_baseline: 0.0,
 
                 offset_main: 0.0,
                 offset_cross: 0.0,
             }
         })
-        .collect()
+        .collect();
+
+    // Flex items are laid out in "order-modified document order". Each item's `order` is its index in the tree (which
+    // is also used as its paint order), so using it as a tie-break keeps items with the same order in tree order.
+    // An unstable sort is used because `Vec` is an `ArrayVec` in no-alloc builds, which has no stable sort.
+    // See: https://www.w3.org/TR/css-flexbox-1/#order-property
+    flex_items.sort_unstable_by_key(|item| (tree.get_flexbox_child_style(item.node).order(), item.order));
+    flex_items
+}
+
+/// Resolve any min-content, max-content, fit-content or stretch values in the items' size, min-size and max-size styles.
+/// These resolve to `None` in [`generate_anonymous_flex_items`].
+#[inline]
+fn resolve_flex_item_sizing_keywords(
+    tree: &mut impl LayoutFlexboxContainer,
+    constants: &AlgoConstants,
+    flex_items: &mut [FlexItem],
+) {
+    for item in flex_items.iter_mut() {
+        let child_style = tree.get_flexbox_child_style(item.node);
+        let size = child_style.size();
+        let min_size = child_style.min_size();
+        let max_size = child_style.max_size();
+        let box_sizing_adjustment = if child_style.box_sizing() == BoxSizing::ContentBox {
+            (item.padding + item.border).sum_axes()
+        } else {
+            Size::ZERO
+        };
+        drop(child_style);
+
+        // Stretch values fill the container's content box minus the item's margins
+        let parent_size = constants.node_inner_size;
+        let stretch_size = parent_size.maybe_sub(item.margin.sum_axes());
+        item.size = resolve_stretch_sizes(size, item.size, stretch_size);
+        item.min_size = resolve_stretch_sizes(min_size, item.min_size, stretch_size);
+        item.max_size = resolve_stretch_sizes(max_size, item.max_size, stretch_size);
+
+        item.size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            item.node,
+            size,
+            item.size,
+            None,
+            parent_size,
+            box_sizing_adjustment,
+        );
+        item.min_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            item.node,
+            min_size,
+            item.min_size,
+            item.size.width,
+            parent_size,
+            box_sizing_adjustment,
+        );
+        item.max_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            item.node,
+            max_size,
+            item.max_size,
+            item.size.width,
+            parent_size,
+            box_sizing_adjustment,
+        );
+    }
 }
 
 /// Determine the available main and cross space for the flex items.
@@ -694,13 +949,28 @@ fn determine_flex_base_size(
             Size::ZERO
         }
         .main(dir);
-        let flex_basis = child_style
-            .flex_basis()
+        let flex_basis_style = child_style.flex_basis();
+        let flex_basis = flex_basis_style
             .maybe_resolve(container_width, |val, basis| tree.calc(val, basis))
             .maybe_add(box_sizing_adjustment);
 
         drop(child_style);
 
+        // An intrinsic sizing keyword in flex-basis is resolved by measuring the item in the main axis
+        let flex_basis = flex_basis.or_else(|| {
+            resolve_intrinsic_sizing_keyword(flex_basis_style, container_width, box_sizing_adjustment, |main_space| {
+                tree.measure_child_size(
+                    child.node,
+                    child_known_dimensions,
+                    child_parent_size,
+                    Size::MAX_CONTENT.with_main(dir, main_space).with_cross(dir, cross_axis_available_space),
+                    SizingMode::ContentSize,
+                    dir.main_axis(),
+                    Line::FALSE,
+                )
+            })
+        });
+
         child.flex_basis = 'flex_basis: {
             // A. If the item has a definite used flex basis, that’s the flex base size.
 
@@ -731,7 +1001,8 @@ fn determine_flex_base_size(
             //    for a box in an orthogonal flow [CSS3-WRITING-MODES]. The flex base size
             //    is the item’s max-content main size.
 
-            // TODO if/when vertical writing modes are supported
+            // This is also covered by the implementation of E below, which always sizes the item under a
+            // max-content constraint in the main axis.
 
             // E. Otherwise, size the item into the available space using its used flex basis
             //    in place of its main size, treating a value of content as max-content.
@@ -850,7 +1121,7 @@ fn collect_flex_lines<'a>(
 ) -> Vec<FlexLine<'a>> {
     if !constants.is_wrap {
         let mut lines = new_vec_with_capacity(1);
-        lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
+        lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0, strut_size: 0.0 });
         lines
     } else {
         let main_axis_available_space = match constants.max_size.main(constants.dir) {
@@ -869,7 +1140,12 @@ fn collect_flex_lines<'a>(
             // (at least for now - future extensions to the CSS spec may add provisions for forced wrap points)
             AvailableSpace::MaxContent => {
                 let mut lines = new_vec_with_capacity(1);
-                lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
+                lines.push(FlexLine {
+                    items: flex_items.as_mut_slice(),
+                    cross_size: 0.0,
+                    offset_cross: 0.0,
+                    strut_size: 0.0,
+                });
                 lines
             }
             // If flex-wrap is Wrap and we're sizing under a min-content constraint, then we take every possible wrapping opportunity
@@ -879,7 +1155,7 @@ fn collect_flex_lines<'a>(
                 let mut items = &mut flex_items[..];
                 while !items.is_empty() {
                     let (line_items, rest) = items.split_at_mut(1);
-                    lines.push(FlexLine { items: line_items, cross_size: 0.0, offset_cross: 0.0 });
+                    lines.push(FlexLine { items: line_items, cross_size: 0.0, offset_cross: 0.0, strut_size: 0.0 });
                     items = rest;
                 }
                 lines
@@ -907,7 +1183,7 @@ fn collect_flex_lines<'a>(
                         .unwrap_or(flex_items.len());
 
                     let (items, rest) = flex_items.split_at_mut(index);
-                    lines.push(FlexLine { items, cross_size: 0.0, offset_cross: 0.0 });
+                    lines.push(FlexLine { items, cross_size: 0.0, offset_cross: 0.0, strut_size: 0.0 });
                     flex_items = rest;
                 }
                 lines
@@ -1415,23 +1691,27 @@ fn calculate_children_base_lines(
     constants: &AlgoConstants,
 ) {
     // Only compute baselines for flex rows because we only support baseline alignment in the cross axis
-    // where that axis is also the inline axis
-    // TODO: this may need revisiting if/when we support vertical writing modes
+    // where that axis is also the inline axis. Layout is performed in the container's logical coordinate
+    // space, so rows are always in the inline axis regardless of the container's writing mode.
     if !constants.is_row {
         return;
     }
 
     for line in flex_lines {
-        // If a flex line has one or zero items participating in baseline alignment then baseline alignment is a no-op so we skip
-        let line_baseline_child_count =
-            line.items.iter().filter(|child| child.align_self == AlignSelf::Baseline).count();
-        if line_baseline_child_count <= 1 {
+        // If a flex line has one or zero items participating in a baseline-sharing group then baseline alignment
+        // is a no-op for that group so we skip it
+        let has_baseline_group = line.items.iter().filter(|child| child.align_self == AlignSelf::Baseline).count() > 1;
+        let has_last_baseline_group =
+            line.items.iter().filter(|child| child.align_self == AlignSelf::LastBaseline).count() > 1;
+        if !has_baseline_group && !has_last_baseline_group {
             continue;
         }
 
         for child in line.items.iter_mut() {
             // Only calculate baselines for children participating in baseline alignment
-            if child.align_self != AlignSelf::Baseline {
+            let is_first_baseline_participant = has_baseline_group && child.align_self == AlignSelf::Baseline;
+            let is_last_baseline_participant = has_last_baseline_group && child.align_self == AlignSelf::LastBaseline;
+            if !is_first_baseline_participant && !is_last_baseline_participant {
                 continue;
             }
 
@@ -1466,10 +1746,14 @@ fn calculate_children_base_lines(
                 Line::FALSE,
             );
 
-            let baseline = measured_size_and_baselines.first_baselines.y;
             let height = measured_size_and_baselines.size.height;
-
-            child.baseline = baseline.unwrap_or(height) + child.margin.top;
+            if is_first_baseline_participant {
+                let baseline = measured_size_and_baselines.first_baselines.y;
+                child.baseline = baseline.unwrap_or(height) + child.margin.top;
+            } else {
+                let last_baseline = measured_size_and_baselines.last_baselines.y;
+                child.last_baseline = last_baseline.unwrap_or(height) + child.margin.top;
+            }
         }
     }
 }
@@ -1502,6 +1786,8 @@ fn calculate_cross_size(flex_lines: &mut [FlexLine], node_size: Size<Option<f32>
         //       cross-start edge, and the largest of the distances between each item’s baseline
         //       and its hypothetical outer cross-end edge, and sum these two values.
 
+        //       Do the same for the items whose align-self is last baseline, measuring from their last baselines.
+
         //    2. Among all the items not collected by the previous step, find the largest
         //       outer hypothetical cross size.
 
@@ -1509,17 +1795,22 @@ fn calculate_cross_size(flex_lines: &mut [FlexLine], node_size: Size<Option<f32>
         //       previous two steps and zero.
         for line in flex_lines.iter_mut() {
             let max_baseline: f32 = line.items.iter().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+            let max_last_baseline_descent = max_last_baseline_descent(line, constants);
             line.cross_size = line
                 .items
                 .iter()
                 .map(|child| {
-                    if child.align_self == AlignSelf::Baseline
-                        && !child.margin_is_auto.cross_start(constants.dir)
-                        && !child.margin_is_auto.cross_end(constants.dir)
-                    {
-                        max_baseline - child.baseline + child.hypothetical_outer_size.cross(constants.dir)
-                    } else {
-                        child.hypothetical_outer_size.cross(constants.dir)
+                    let has_auto_cross_margin = child.margin_is_auto.cross_start(constants.dir)
+                        || child.margin_is_auto.cross_end(constants.dir);
+                    match child.align_self {
+                        AlignSelf::Baseline if !has_auto_cross_margin => {
+                            max_baseline - child.baseline + child.hypothetical_outer_size.cross(constants.dir)
+                        }
+                        AlignSelf::LastBaseline if !has_auto_cross_margin => {
+                            max_last_baseline_descent - child.last_baseline_descent(constants)
+                                + child.hypothetical_outer_size.cross(constants.dir)
+                        }
+                        _ => child.hypothetical_outer_size.cross(constants.dir),
                     }
                 })
                 .fold(0.0, |acc, x| acc.max(x));
@@ -1613,15 +1904,22 @@ fn determine_used_cross_size(
                     let box_sizing_adjustment =
                         if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
 
-                    let max_size_ignoring_aspect_ratio = child_style
-                        .max_size()
+                    let max_size_style = child_style.max_size();
+                    let max_size_ignoring_aspect_ratio = max_size_style
                         .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
                         .maybe_add(box_sizing_adjustment);
 
-                    (line_cross_size - child.margin.cross_axis_sum(constants.dir)).maybe_clamp(
-                        child.min_size.cross(constants.dir),
-                        max_size_ignoring_aspect_ratio.cross(constants.dir),
-                    )
+                    // Sizing keywords have already been resolved in `resolve_flex_item_sizing_keywords`
+                    let max_cross_style = max_size_style.cross(constants.dir);
+                    let max_cross_size =
+                        if max_cross_style.is_intrinsic_sizing_keyword() || max_cross_style.is_stretch() {
+                            child.max_size.cross(constants.dir)
+                        } else {
+                            max_size_ignoring_aspect_ratio.cross(constants.dir)
+                        };
+
+                    (line_cross_size - child.margin.cross_axis_sum(constants.dir))
+                        .maybe_clamp(child.min_size.cross(constants.dir), max_cross_size)
                 } else {
                     child.hypothetical_inner_size.cross(constants.dir)
                 },
@@ -1686,10 +1984,13 @@ fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &Algo
             let num_items = line.items.len();
             let layout_reverse = constants.dir.is_reverse();
             let gap = constants.gap.main(constants.dir);
-            let is_safe = false; // TODO: Implement safe alignment
             let raw_justify_content_mode = constants.justify_content.unwrap_or(JustifyContent::FlexStart);
-            let justify_content_mode =
-                apply_alignment_fallback(free_space, num_items, raw_justify_content_mode, is_safe);
+            let justify_content_mode = apply_alignment_fallback(free_space, num_items, raw_justify_content_mode);
+            let justify_content_mode = if constants.is_row && constants.direction.is_rtl() {
+                justify_content_mode.swap_start_end()
+            } else {
+                justify_content_mode
+            };
 
             let justify_item = |(i, child): (usize, &mut FlexItem)| {
                 child.offset_main =
@@ -1722,6 +2023,7 @@ fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &Algo
     for line in flex_lines {
         let line_cross_size = line.cross_size;
         let max_baseline: f32 = line.items.iter_mut().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+        let max_last_baseline_descent = max_last_baseline_descent(line, constants);
 
         for child in line.items.iter_mut() {
             let free_space = line_cross_size - child.outer_target_size.cross(constants.dir);
@@ -1748,12 +2050,29 @@ fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &Algo
                 }
             } else {
                 // 14. Align all flex items along the cross-axis.
-                child.offset_cross = align_flex_items_along_cross_axis(child, free_space, max_baseline, constants);
+                child.offset_cross = align_flex_items_along_cross_axis(
+                    child,
+                    free_space,
+                    max_baseline,
+                    max_last_baseline_descent,
+                    constants,
+                );
             }
         }
     }
 }
 
+/// The largest distance between the last baseline and the hypothetical outer cross-end edge of the items in the
+/// line which participate in last baseline alignment
+#[inline]
+fn max_last_baseline_descent(line: &FlexLine, constants: &AlgoConstants) -> f32 {
+    line.items
+        .iter()
+        .filter(|child| child.align_self == AlignSelf::LastBaseline)
+        .map(|child| child.last_baseline_descent(constants))
+        .fold(0.0, |acc, x| acc.max(x))
+}
+
 /// Align all flex items along the cross-axis.
 ///
 /// # [9.6. Cross-Axis Alignment](https://www.w3.org/TR/css-flexbox-1/#cross-alignment)
@@ -1765,32 +2084,34 @@ fn align_flex_items_along_cross_axis(
     child: &FlexItem,
     free_space: f32,
     max_baseline: f32,
+    max_last_baseline_descent: f32,
     constants: &AlgoConstants,
 ) -> f32 {
-    match child.align_self {
-        AlignSelf::Start => 0.0,
-        AlignSelf::FlexStart => {
+    let align_self = apply_self_alignment_fallback(free_space, child.align_self);
+    match constants.resolve_cross_alignment(align_self) {
+        AlignSelf::Start | AlignSelf::SafeStart => 0.0,
+        AlignSelf::FlexStart | AlignSelf::SafeFlexStart => {
             if constants.is_wrap_reverse {
                 free_space
             } else {
                 0.0
             }
         }
-        AlignSelf::End => free_space,
-        AlignSelf::FlexEnd => {
+        AlignSelf::End | AlignSelf::SafeEnd => free_space,
+        AlignSelf::FlexEnd | AlignSelf::SafeFlexEnd => {
             if constants.is_wrap_reverse {
                 0.0
             } else {
                 free_space
             }
         }
-        AlignSelf::Center => free_space / 2.0,
+        AlignSelf::Center | AlignSelf::SafeCenter => free_space / 2.0,
         AlignSelf::Baseline => {
             if constants.is_row {
                 max_baseline - child.baseline
             } else {
-                // Until we support vertical writing modes, baseline alignment only makes sense if
-                // the constants.direction is row, so we treat it as flex-start alignment in columns.
+                // Baseline alignment only makes sense if the main axis is the inline axis (i.e. the
+                // constants.direction is row), so we treat it as flex-start alignment in columns.
                 if constants.is_wrap_reverse {
                     free_space
                 } else {
@@ -1798,6 +2119,18 @@ fn align_flex_items_along_cross_axis(
                 }
             }
         }
+        AlignSelf::LastBaseline => {
+            if constants.is_row {
+                free_space - (max_last_baseline_descent - child.last_baseline_descent(constants))
+            } else {
+                // Likewise, last baseline alignment is treated as flex-end alignment in columns
+                if constants.is_wrap_reverse {
+                    0.0
+                } else {
+                    free_space
+                }
+            }
+        }
         AlignSelf::Stretch => {
             if constants.is_wrap_reverse {
                 free_space
@@ -1855,9 +2188,13 @@ fn align_flex_lines_per_align_content(flex_lines: &mut [FlexLine], constants: &A
     let gap = constants.gap.cross(constants.dir);
     let total_cross_axis_gap = sum_axis_gaps(gap, num_lines);
     let free_space = constants.inner_container_size.cross(constants.dir) - total_cross_size - total_cross_axis_gap;
-    let is_safe = false; // TODO: Implement safe alignment
 
-    let align_content_mode = apply_alignment_fallback(free_space, num_lines, constants.align_content, is_safe);
+    let align_content_mode = apply_alignment_fallback(free_space, num_lines, constants.align_content);
+    let align_content_mode = if constants.is_column && constants.direction.is_rtl() {
+        align_content_mode.swap_start_end()
+    } else {
+        align_content_mode
+    };
 
     let align_line = |(i, line): (usize, &mut FlexLine)| {
         line.offset_cross =
@@ -1912,22 +2249,20 @@ fn calculate_flex_item(
 
     if direction.is_row() {
         let baseline_offset_cross = total_offset_cross + item.offset_cross + item.margin.cross_start(direction);
-        let inner_baseline = layout_output.first_baselines.y.unwrap_or(size.height);
-        item.baseline = baseline_offset_cross + inner_baseline;
+        item.baseline = baseline_offset_cross + layout_output.first_baselines.y.unwrap_or(size.height);
+        item.last_baseline = baseline_offset_cross + layout_output.last_baselines.y.unwrap_or(size.height);
     } else {
         let baseline_offset_main = *total_offset_main + item.offset_main + item.margin.main_start(direction);
-        let inner_baseline = layout_output.first_baselines.y.unwrap_or(size.height);
-        item.baseline = baseline_offset_main + inner_baseline;
+        item.baseline = baseline_offset_main + layout_output.first_baselines.y.unwrap_or(size.height);
+        item.last_baseline = baseline_offset_main + layout_output.last_baselines.y.unwrap_or(size.height);
     }
 
     let location = match direction.is_row() {
         true => Point { x: offset_main, y: offset_cross },
         false => Point { x: offset_cross, y: offset_main },
     };
-    let scrollbar_size = Size {
-        width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-        height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-    };
+    let scrollbar_size =
+        child_scrollbar_size(item.overflow, item.scrollbar_width, item.scrollbar_gutter, layout_output.scrollbar_size);
 
     tree.set_unrounded_layout(
         item.node,
@@ -1937,6 +2272,7 @@ fn calculate_flex_item(
             #[cfg(feature = "content_size")]
             content_size,
             scrollbar_size,
+            sticky_offset: Point::ZERO,
             location,
             padding: item.padding,
             border: item.border,
@@ -2045,8 +2381,10 @@ fn final_layout_pass(
         }
     }
 
-    content_size.width += constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.x;
-    content_size.height += constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.y;
+    content_size.width +=
+        constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter_inset.right;
+    content_size.height +=
+        constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter_inset.bottom;
 
     content_size
 }
@@ -2071,14 +2409,18 @@ fn perform_absolute_layout_on_absolute_children(
         let child_style = tree.get_flexbox_child_style(child);
 
         // Skip items that are display:none or are not position:absolute
-        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
+        if child_style.box_generation_mode() == BoxGenerationMode::None
+            || !child_style.position().is_absolutely_positioned()
         {
             continue;
         }
 
+        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+        let position = child_style.position();
         let overflow = child_style.overflow();
         let scrollbar_width = child_style.scrollbar_width();
-        let aspect_ratio = child_style.aspect_ratio();
+        let scrollbar_gutter = child_style.scrollbar_gutter();
+        let aspect_ratio = child_style.aspect_ratio().filter(|_| !child_style.aspect_ratio_auto());
         let align_self = child_style.align_self().unwrap_or(constants.align_items);
         let margin = child_style
             .margin()
@@ -2119,6 +2461,24 @@ fn perform_absolute_layout_on_absolute_children(
             .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
+
+        // Stretch values fill the containing block minus the item's insets and margins
+        let stretch_size = Size {
+            width: inset_relative_size.width
+                - left.unwrap_or(0.0)
+                - right.unwrap_or(0.0)
+                - margin.left.unwrap_or(0.0)
+                - margin.right.unwrap_or(0.0),
+            height: inset_relative_size.height
+                - top.unwrap_or(0.0)
+                - bottom.unwrap_or(0.0)
+                - margin.top.unwrap_or(0.0)
+                - margin.bottom.unwrap_or(0.0),
+        }
+        .map(Some);
+        let style_size = resolve_stretch_sizes(child_style.size(), style_size, stretch_size);
+        let min_size = resolve_stretch_sizes(child_style.min_size(), min_size, stretch_size);
+        let max_size = resolve_stretch_sizes(child_style.max_size(), max_size, stretch_size);
         let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
 
         drop(child_style);
@@ -2192,6 +2552,9 @@ fn perform_absolute_layout_on_absolute_children(
             }
         };
 
+        // Over-constrained horizontal insets are resolved in favour of the inline-start inset
+        let Rect { left, right, .. } = constants.resolve_horizontal_inset(Rect { left, right, top, bottom });
+
         // Determine flex-relative insets
         let (start_main, end_main) = if constants.is_row { (left, right) } else { (top, bottom) };
         let (start_cross, end_cross) = if constants.is_row { (top, bottom) } else { (left, right) };
@@ -2199,35 +2562,59 @@ fn perform_absolute_layout_on_absolute_children(
         // Apply main-axis alignment
         // let free_main_space = free_space.main(constants.dir) - resolved_margin.main_axis_sum(constants.dir);
         let offset_main = if let Some(start) = start_main {
-            start + constants.border.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
+            start
+                + constants.border.main_start(constants.dir)
+                + constants.scrollbar_gutter_inset.main_start(constants.dir)
+                + resolved_margin.main_start(constants.dir)
         } else if let Some(end) = end_main {
             constants.container_size.main(constants.dir)
                 - constants.border.main_end(constants.dir)
-                - constants.scrollbar_gutter.main(constants.dir)
+                - constants.scrollbar_gutter_inset.main_end(constants.dir)
                 - final_size.main(constants.dir)
                 - end
                 - resolved_margin.main_end(constants.dir)
         } else {
             // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
             // treat it as if it wasn't set (and thus we default to FlexStart behaviour)
-            match (constants.justify_content.unwrap_or(JustifyContent::Start), constants.is_wrap_reverse) {
+            let justify_content = constants.justify_content.unwrap_or(JustifyContent::Start);
+            // Safe alignment falls back to start alignment if the item overflows the content box of the container
+            let free_main_space = constants.container_size.main(constants.dir)
+                - constants.content_box_inset.main_axis_sum(constants.dir)
+                - final_size.main(constants.dir)
+                - resolved_margin.main_axis_sum(constants.dir);
+            let justify_content = if justify_content.is_safe() && free_main_space < 0.0 {
+                JustifyContent::Start
+            } else {
+                justify_content
+            };
+            // In right-to-left rows both the inline-start and the main-start edges are on the right
+            let justify_content = if constants.is_row && constants.direction.is_rtl() {
+                justify_content.reversed()
+            } else {
+                justify_content
+            };
+            // The main axis static position depends on the `flex_wrap` style itself, which is not affected by the direction
+            let is_wrap_reverse = constants.is_wrap_reverse != (constants.is_column && constants.direction.is_rtl());
+            match (justify_content, is_wrap_reverse) {
                 (JustifyContent::SpaceBetween, _)
-                | (JustifyContent::Start, _)
+                | (JustifyContent::Start | JustifyContent::SafeStart, _)
                 | (JustifyContent::Stretch, false)
-                | (JustifyContent::FlexStart, false)
-                | (JustifyContent::FlexEnd, true) => {
+                | (JustifyContent::FlexStart | JustifyContent::SafeFlexStart, false)
+                | (JustifyContent::FlexEnd | JustifyContent::SafeFlexEnd, true) => {
                     constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
                 }
-                (JustifyContent::End, _)
-                | (JustifyContent::FlexEnd, false)
-                | (JustifyContent::FlexStart, true)
+                (JustifyContent::End | JustifyContent::SafeEnd, _)
+                | (JustifyContent::FlexEnd | JustifyContent::SafeFlexEnd, false)
+                | (JustifyContent::FlexStart | JustifyContent::SafeFlexStart, true)
                 | (JustifyContent::Stretch, true) => {
                     constants.container_size.main(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
                         - final_size.main(constants.dir)
                         - resolved_margin.main_end(constants.dir)
                 }
-                (JustifyContent::SpaceEvenly, _) | (JustifyContent::SpaceAround, _) | (JustifyContent::Center, _) => {
+                (JustifyContent::SpaceEvenly, _)
+                | (JustifyContent::SpaceAround, _)
+                | (JustifyContent::Center | JustifyContent::SafeCenter, _) => {
                     (constants.container_size.main(constants.dir)
                         + constants.content_box_inset.main_start(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
@@ -2242,33 +2629,41 @@ fn perform_absolute_layout_on_absolute_children(
         // Apply cross-axis alignment
         // let free_cross_space = free_space.cross(constants.dir) - resolved_margin.cross_axis_sum(constants.dir);
         let offset_cross = if let Some(start) = start_cross {
-            start + constants.border.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
+            start
+                + constants.border.cross_start(constants.dir)
+                + constants.scrollbar_gutter_inset.cross_start(constants.dir)
+                + resolved_margin.cross_start(constants.dir)
         } else if let Some(end) = end_cross {
             constants.container_size.cross(constants.dir)
                 - constants.border.cross_end(constants.dir)
-                - constants.scrollbar_gutter.cross(constants.dir)
+                - constants.scrollbar_gutter_inset.cross_end(constants.dir)
                 - final_size.cross(constants.dir)
                 - end
                 - resolved_margin.cross_end(constants.dir)
         } else {
-            match (align_self, constants.is_wrap_reverse) {
+            let free_cross_space = constants.container_size.cross(constants.dir)
+                - constants.content_box_inset.cross_axis_sum(constants.dir)
+                - final_size.cross(constants.dir)
+                - resolved_margin.cross_axis_sum(constants.dir);
+            let align_self = apply_self_alignment_fallback(free_cross_space, align_self);
+            match (constants.resolve_cross_alignment(align_self), constants.is_wrap_reverse) {
                 // Stretch alignment does not apply to absolutely positioned items
                 // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
                 // Note: Stretch should be FlexStart not Start when we support both
-                (AlignSelf::Start, _)
-                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, false)
-                | (AlignSelf::FlexEnd, true) => {
+                (AlignSelf::Start | AlignSelf::SafeStart, _)
+                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart | AlignSelf::SafeFlexStart, false)
+                | (AlignSelf::LastBaseline | AlignSelf::FlexEnd | AlignSelf::SafeFlexEnd, true) => {
                     constants.content_box_inset.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
                 }
-                (AlignSelf::End, _)
-                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart, true)
-                | (AlignSelf::FlexEnd, false) => {
+                (AlignSelf::End | AlignSelf::SafeEnd, _)
+                | (AlignSelf::Baseline | AlignSelf::Stretch | AlignSelf::FlexStart | AlignSelf::SafeFlexStart, true)
+                | (AlignSelf::LastBaseline | AlignSelf::FlexEnd | AlignSelf::SafeFlexEnd, false) => {
                     constants.container_size.cross(constants.dir)
                         - constants.content_box_inset.cross_end(constants.dir)
                         - final_size.cross(constants.dir)
                         - resolved_margin.cross_end(constants.dir)
                 }
-                (AlignSelf::Center, _) => {
+                (AlignSelf::Center | AlignSelf::SafeCenter, _) => {
                     (constants.container_size.cross(constants.dir)
                         + constants.content_box_inset.cross_start(constants.dir)
                         - constants.content_box_inset.cross_end(constants.dir)
@@ -2284,10 +2679,8 @@ fn perform_absolute_layout_on_absolute_children(
             true => Point { x: offset_main, y: offset_cross },
             false => Point { x: offset_cross, y: offset_main },
         };
-        let scrollbar_size = Size {
-            width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
-            height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
-        };
+        let scrollbar_size =
+            child_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, layout_output.scrollbar_size);
         tree.set_unrounded_layout(
             child,
             &Layout {
@@ -2296,6 +2689,7 @@ fn perform_absolute_layout_on_absolute_children(
                 #[cfg(feature = "content_size")]
                 content_size: layout_output.content_size,
                 scrollbar_size,
+                sticky_offset: Point::ZERO,
                 location,
                 padding,
                 border,
@@ -2303,8 +2697,9 @@ fn perform_absolute_layout_on_absolute_children(
             },
         );
 
+        // Fixed items are positioned relative to the viewport, so don't contribute to the content size of their parent
         #[cfg(feature = "content_size")]
-        {
+        if position != Position::Fixed {
             let size_content_size_contribution = Size {
                 width: match overflow.x {
                     Overflow::Visible => f32_max(final_size.width, layout_output.content_size.width),
@@ -1,8 +1,14 @@
 //! Alignment of tracks and final positioning of items
 use super::types::GridTrack;
-use crate::compute::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
+use crate::compute::common::alignment::{
+    apply_alignment_fallback, apply_self_alignment_fallback, compute_alignment_offset,
+};
+use crate::compute::common::scrollbars::child_scrollbar_size;
+use crate::compute::common::sizing_keywords::{resolve_child_intrinsic_sizing_keywords, resolve_stretch_sizes};
 use crate::geometry::{InBothAbsAxis, Line, Point, Rect, Size};
-use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, CoreStyle, GridItemStyle, Overflow, Position};
+use crate::style::{
+    AlignContent, AlignItems, AlignSelf, AvailableSpace, CoreStyle, Direction, GridItemStyle, Position,
+};
 use crate::tree::{Layout, LayoutPartialTreeExt, NodeId, SizingMode};
 use crate::util::sys::f32_max;
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
@@ -32,8 +38,7 @@ pub(super) fn align_tracks(
     // simply pass zero here. Grid layout is never reversed.
     let gap = 0.0;
     let layout_is_reversed = false;
-    let is_safe = false; // TODO: Implement safe alignment
-    let track_alignment = apply_alignment_fallback(free_space, num_tracks, track_alignment_style, is_safe);
+    let track_alignment = apply_alignment_fallback(free_space, num_tracks, track_alignment_style);
 
     // Compute offsets
     let mut total_offset = origin;
@@ -55,14 +60,75 @@ pub(super) fn align_tracks(
     });
 }
 
-/// Align and size a grid item into it's final position
+/// Convert the horizontal position of a column line into its physical position within the container. Columns are
+/// always sized and aligned from left to right, so in right-to-left containers the position is mirrored within the
+/// container's content box. `content_box_edges` are the left and right edges of the content box.
+pub(super) fn resolve_column_position(position: f32, content_box_edges: Line<f32>, direction: Direction) -> f32 {
+    match direction {
+        Direction::Ltr => position,
+        Direction::Rtl => content_box_edges.start + content_box_edges.end - position,
+    }
+}
+
+/// Convert the horizontal range spanned by a set of columns into the physical range that it occupies within the
+/// container (see [`resolve_column_position`])
+pub(super) fn resolve_column_range(range: Line<f32>, content_box_edges: Line<f32>, direction: Direction) -> Line<f32> {
+    match direction {
+        Direction::Ltr => range,
+        Direction::Rtl => Line {
+            start: resolve_column_position(range.end, content_box_edges, direction),
+            end: resolve_column_position(range.start, content_box_edges, direction),
+        },
+    }
+}
+
+/// Compute the baseline shim of an absolutely positioned grid item. Absolutely positioned items don't participate in
+/// baseline-sharing groups, so instead the item is shimmed such that its baseline lines up with the `shared_baselines`
+/// of the tracks at the start of its grid area (in each axis in which it is baseline aligned).
+pub(super) fn absolutely_positioned_item_baseline_shim(
+    tree: &mut impl LayoutGridContainer,
+    node: NodeId,
+    grid_area: Rect<f32>,
+    shared_baselines: Point<Option<f32>>,
+) -> Rect<f32> {
+    if shared_baselines.x.is_none() && shared_baselines.y.is_none() {
+        return Rect::ZERO;
+    }
+
+    let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
+    let margin = tree
+        .get_grid_child_style(node)
+        .margin()
+        .resolve_or_zero(Some(grid_area_size.width), |val, basis| tree.calc(val, basis));
+    let measured_size_and_baselines = tree.perform_child_layout(
+        node,
+        Size::NONE,
+        grid_area_size.map(Some),
+        grid_area_size.map(AvailableSpace::Definite),
+        SizingMode::InherentSize,
+        Line::FALSE,
+    );
+    let baselines = measured_size_and_baselines.first_baselines;
+    let size = measured_size_and_baselines.size;
+
+    Rect {
+        left: shared_baselines.x.map(|shared| shared - baselines.x.unwrap_or(size.width) - margin.left).unwrap_or(0.0),
+        right: 0.0,
+        top: shared_baselines.y.map(|shared| shared - baselines.y.unwrap_or(size.height) - margin.top).unwrap_or(0.0),
+        bottom: 0.0,
+    }
+}
+
+/// Align and size a grid item into it's final position. The `baseline_shim` acts as an extra margin on each side of
+/// the item: the left and top shims implement baseline alignment and the right and bottom shims last baseline alignment.
 pub(super) fn align_and_position_item(
     tree: &mut impl LayoutGridContainer,
     node: NodeId,
     order: u32,
     grid_area: Rect<f32>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-    baseline_shim: f32,
+    baseline_shim: Rect<f32>,
+    direction: Direction,
 ) -> (Size<f32>, f32, f32) {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
@@ -70,7 +136,11 @@ pub(super) fn align_and_position_item(
 
     let overflow = style.overflow();
     let scrollbar_width = style.scrollbar_width();
-    let aspect_ratio = style.aspect_ratio();
+    let scrollbar_gutter = style.scrollbar_gutter();
+    // An `auto <ratio>` aspect ratio may be overridden by the natural aspect ratio of the item's content, so it is
+    // left to the item's own layout to apply it. It still counts as an aspect ratio when choosing default alignment.
+    let has_aspect_ratio = style.aspect_ratio().is_some();
+    let aspect_ratio = style.aspect_ratio().filter(|_| !style.aspect_ratio_auto());
     let justify_self = style.justify_self();
     let align_self = style.align_self();
 
@@ -92,24 +162,86 @@ pub(super) fn align_and_position_item(
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
 
-    let inherent_size = style
-        .size()
+    let size_style = style.size();
+    let min_size_style = style.min_size();
+    let max_size_style = style.max_size();
+    let inherent_size = size_style
         .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
-    let min_size = style
-        .min_size()
+    let min_size = min_size_style
         .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-        .maybe_add(box_sizing_adjustment)
-        .or(padding_border_size.map(Some))
-        .maybe_max(padding_border_size)
-        .maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style
-        .max_size()
+        .maybe_add(box_sizing_adjustment);
+    let max_size = max_size_style
         .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
 
+    // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
+    // resolve against the WIDTH of the grid area.
+    let margin =
+        style.margin().map(|margin| margin.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
+
+    drop(style);
+
+    let grid_area_minus_item_margins_size = Size {
+        width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right)
+            - baseline_shim.horizontal_axis_sum(),
+        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom)
+            - baseline_shim.vertical_axis_sum(),
+    };
+
+    // Resolve any stretch sizes against the grid area minus the item's margins (and its insets if it is absolutely
+    // positioned, in which case the grid area is its containing block)
+    let stretch_size = if position.is_absolutely_positioned() {
+        Size {
+            width: grid_area_minus_item_margins_size.width
+                - inset_horizontal.start.unwrap_or(0.0)
+                - inset_horizontal.end.unwrap_or(0.0),
+            height: grid_area_minus_item_margins_size.height
+                - inset_vertical.start.unwrap_or(0.0)
+                - inset_vertical.end.unwrap_or(0.0),
+        }
+    } else {
+        grid_area_minus_item_margins_size
+    };
+    let inherent_size = resolve_stretch_sizes(size_style, inherent_size, stretch_size.map(Some));
+    let min_size = resolve_stretch_sizes(min_size_style, min_size, stretch_size.map(Some));
+    let max_size = resolve_stretch_sizes(max_size_style, max_size, stretch_size.map(Some));
+
+    // Resolve any min-content, max-content or fit-content sizes by measuring the item's content
+    let parent_size = grid_area_size.map(Some);
+    let inherent_size = resolve_child_intrinsic_sizing_keywords(
+        tree,
+        node,
+        size_style,
+        inherent_size,
+        None,
+        parent_size,
+        box_sizing_adjustment,
+    );
+    let min_size = resolve_child_intrinsic_sizing_keywords(
+        tree,
+        node,
+        min_size_style,
+        min_size,
+        inherent_size.width,
+        parent_size,
+        box_sizing_adjustment,
+    )
+    .or(padding_border_size.map(Some))
+    .maybe_max(padding_border_size)
+    .maybe_apply_aspect_ratio(aspect_ratio);
+    let max_size = resolve_child_intrinsic_sizing_keywords(
+        tree,
+        node,
+        max_size_style,
+        max_size,
+        inherent_size.width,
+        parent_size,
+        box_sizing_adjustment,
+    );
+
     // Resolve default alignment styles if they are set on neither the parent or the node itself
     // Note: if the child has a preferred aspect ratio but neither width or height are set, then the width is stretched
     // and the then height is calculated from the width according the aspect ratio
@@ -123,7 +255,7 @@ pub(super) fn align_and_position_item(
             }
         }),
         vertical: align_self.or(container_alignment_styles.vertical).unwrap_or_else(|| {
-            if inherent_size.height.is_some() || aspect_ratio.is_some() {
+            if inherent_size.height.is_some() || has_aspect_ratio {
                 AlignSelf::Start
             } else {
                 AlignSelf::Stretch
@@ -131,22 +263,12 @@ pub(super) fn align_and_position_item(
         }),
     };
 
-    // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
-    // resolve against the WIDTH of the grid area.
-    let margin =
-        style.margin().map(|margin| margin.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
-
-    let grid_area_minus_item_margins_size = Size {
-        width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right),
-        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - baseline_shim,
-    };
-
     // If node is absolutely positioned and width is not set explicitly, then deduce it
     // from left, right and container_content_box if both are set.
     let width = inherent_size.width.or_else(|| {
         // Apply width derived from both the left and right properties of an absolutely
         // positioned element being set
-        if position == Position::Absolute {
+        if position.is_absolutely_positioned() {
             if let (Some(left), Some(right)) = (inset_horizontal.start, inset_horizontal.end) {
                 return Some(f32_max(grid_area_minus_item_margins_size.width - left - right, 0.0));
             }
@@ -159,7 +281,7 @@ pub(super) fn align_and_position_item(
         if margin.left.is_some()
             && margin.right.is_some()
             && alignment_styles.horizontal == AlignSelf::Stretch
-            && position != Position::Absolute
+            && !position.is_absolutely_positioned()
         {
             return Some(grid_area_minus_item_margins_size.width);
         }
@@ -171,7 +293,7 @@ pub(super) fn align_and_position_item(
     let Size { width, height } = Size { width, height: inherent_size.height }.maybe_apply_aspect_ratio(aspect_ratio);
 
     let height = height.or_else(|| {
-        if position == Position::Absolute {
+        if position.is_absolutely_positioned() {
             if let (Some(top), Some(bottom)) = (inset_vertical.start, inset_vertical.end) {
                 return Some(f32_max(grid_area_minus_item_margins_size.height - top - bottom, 0.0));
             }
@@ -184,7 +306,7 @@ pub(super) fn align_and_position_item(
         if margin.top.is_some()
             && margin.bottom.is_some()
             && alignment_styles.vertical == AlignSelf::Stretch
-            && position != Position::Absolute
+            && !position.is_absolutely_positioned()
         {
             return Some(grid_area_minus_item_margins_size.height);
         }
@@ -198,7 +320,6 @@ pub(super) fn align_and_position_item(
     let Size { width, height } = Size { width, height }.maybe_clamp(min_size, max_size);
 
     // Layout node
-    drop(style);
     let layout_output = tree.perform_child_layout(
         node,
         Size { width, height },
@@ -211,29 +332,55 @@ pub(super) fn align_and_position_item(
     // Resolve final size
     let Size { width, height } = Size { width, height }.unwrap_or(layout_output.size).maybe_clamp(min_size, max_size);
 
+    // Safe alignment falls back to start alignment if the item overflows its grid area
+    let free_space = Size {
+        width: grid_area.right
+            - grid_area.left
+            - width
+            - margin.left.unwrap_or(0.0)
+            - margin.right.unwrap_or(0.0)
+            - baseline_shim.horizontal_axis_sum(),
+        height: grid_area.bottom
+            - grid_area.top
+            - height
+            - margin.top.unwrap_or(0.0)
+            - margin.bottom.unwrap_or(0.0)
+            - baseline_shim.vertical_axis_sum(),
+    };
+    let justify_self =
+        apply_self_alignment_fallback(free_space.width, justify_self.unwrap_or(alignment_styles.horizontal));
+    let align_self = apply_self_alignment_fallback(free_space.height, align_self.unwrap_or(alignment_styles.vertical));
+
+    // In right-to-left containers the inline-start edge is on the right, and the `right` inset takes precedence over
+    // the `left` inset if both are set
+    let (justify_self, inset_horizontal) = match direction {
+        Direction::Ltr => (justify_self, inset_horizontal),
+        Direction::Rtl => (
+            justify_self.swap_start_end(),
+            Line { start: inset_horizontal.start.filter(|_| inset_horizontal.end.is_none()), ..inset_horizontal },
+        ),
+    };
     let (x, x_margin) = align_item_within_area(
         Line { start: grid_area.left, end: grid_area.right },
-        justify_self.unwrap_or(alignment_styles.horizontal),
+        justify_self,
         width,
         position,
         inset_horizontal,
         margin.horizontal_components(),
-        0.0,
+        baseline_shim.horizontal_components(),
     );
     let (y, y_margin) = align_item_within_area(
         Line { start: grid_area.top, end: grid_area.bottom },
-        align_self.unwrap_or(alignment_styles.vertical),
+        align_self,
         height,
         position,
         inset_vertical,
         margin.vertical_components(),
-        baseline_shim,
+        baseline_shim.vertical_components(),
     );
 
-    let scrollbar_size = Size {
-        width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
-        height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
-    };
+    let scrollbar_size =
+        child_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, layout_output.scrollbar_size);
 
     let resolved_margin = Rect { left: x_margin.start, right: x_margin.end, top: y_margin.start, bottom: y_margin.end };
 
@@ -246,6 +393,7 @@ pub(super) fn align_and_position_item(
             #[cfg(feature = "content_size")]
             content_size: layout_output.content_size,
             scrollbar_size,
+            sticky_offset: Point::ZERO,
             padding,
             border,
             margin: resolved_margin,
@@ -269,10 +417,13 @@ pub(super) fn align_item_within_area(
     position: Position,
     inset: Line<Option<f32>>,
     margin: Line<Option<f32>>,
-    baseline_shim: f32,
+    baseline_shim: Line<f32>,
 ) -> (f32, Line<f32>) {
     // Calculate grid area dimension in the axis
-    let non_auto_margin = Line { start: margin.start.unwrap_or(0.0) + baseline_shim, end: margin.end.unwrap_or(0.0) };
+    let non_auto_margin = Line {
+        start: margin.start.unwrap_or(0.0) + baseline_shim.start,
+        end: margin.end.unwrap_or(0.0) + baseline_shim.end,
+    };
     let grid_area_size = f32_max(grid_area.end - grid_area.start, 0.0);
     let free_space = f32_max(grid_area_size - resolved_size - non_auto_margin.sum(), 0.0);
 
@@ -280,21 +431,29 @@ pub(super) fn align_item_within_area(
     let auto_margin_count = margin.start.is_none() as u8 + margin.end.is_none() as u8;
     let auto_margin_size = if auto_margin_count > 0 { free_space / auto_margin_count as f32 } else { 0.0 };
     let resolved_margin = Line {
-        start: margin.start.unwrap_or(auto_margin_size) + baseline_shim,
-        end: margin.end.unwrap_or(auto_margin_size),
+        start: margin.start.unwrap_or(auto_margin_size) + baseline_shim.start,
+        end: margin.end.unwrap_or(auto_margin_size) + baseline_shim.end,
     };
 
     // Compute offset in the axis
     let alignment_based_offset = match alignment_style {
-        AlignSelf::Start | AlignSelf::FlexStart => resolved_margin.start,
-        AlignSelf::End | AlignSelf::FlexEnd => grid_area_size - resolved_size - resolved_margin.end,
-        AlignSelf::Center => (grid_area_size - resolved_size + resolved_margin.start - resolved_margin.end) / 2.0,
-        // TODO: Add support for baseline alignment. For now we treat it as "start".
+        AlignSelf::Start | AlignSelf::FlexStart | AlignSelf::SafeStart | AlignSelf::SafeFlexStart => {
+            resolved_margin.start
+        }
+        AlignSelf::End | AlignSelf::FlexEnd | AlignSelf::SafeEnd | AlignSelf::SafeFlexEnd => {
+            grid_area_size - resolved_size - resolved_margin.end
+        }
+        AlignSelf::Center | AlignSelf::SafeCenter => {
+            (grid_area_size - resolved_size + resolved_margin.start - resolved_margin.end) / 2.0
+        }
+        // Baseline alignment is implemented by the baseline shim, which is included in the start margin
         AlignSelf::Baseline => resolved_margin.start,
+        // Last baseline alignment is implemented by the last baseline shim, which is included in the end margin
+        AlignSelf::LastBaseline => grid_area_size - resolved_size - resolved_margin.end,
         AlignSelf::Stretch => resolved_margin.start,
     };
 
-    let offset_within_area = if position == Position::Absolute {
+    let offset_within_area = if position.is_absolutely_positioned() {
         if let Some(start) = inset.start {
             start + non_auto_margin.start
         } else if let Some(end) = inset.end {
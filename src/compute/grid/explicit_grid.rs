@@ -26,7 +26,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
     // and we default to no explicit tracks
     let template_has_repetitions_with_zero_tracks = template.iter().any(|track_def| match track_def {
         TrackSizingFunction::Single(_) => false,
-        TrackSizingFunction::Repeat(_, tracks) => tracks.is_empty(),
+        TrackSizingFunction::Repeat(_, tracks, _) => tracks.is_empty(),
     });
     if template_has_repetitions_with_zero_tracks {
         return 0;
@@ -39,8 +39,8 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
             use GridTrackRepetition::{AutoFill, AutoFit, Count};
             match track_def {
                 TrackSizingFunction::Single(_) => 1,
-                TrackSizingFunction::Repeat(Count(count), tracks) => count * tracks.len() as u16,
-                TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0,
+                TrackSizingFunction::Repeat(Count(count), tracks, _) => count * tracks.len() as u16,
+                TrackSizingFunction::Repeat(AutoFit | AutoFill, _, _) => 0,
             }
         })
         .sum::<u16>();
@@ -48,7 +48,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
     let auto_repetition_count = template.iter().filter(|track_def| track_def.is_auto_repetition()).count() as u16;
     let all_track_defs_have_fixed_component = template.iter().all(|track_def| match track_def {
         TrackSizingFunction::Single(sizing_function) => sizing_function.has_fixed_component(),
-        TrackSizingFunction::Repeat(_, tracks) => {
+        TrackSizingFunction::Repeat(_, tracks, _) => {
             tracks.iter().all(|sizing_function| sizing_function.has_fixed_component())
         }
     });
@@ -74,8 +74,8 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
             use GridTrackRepetition::{AutoFill, AutoFit, Count};
             match def {
                 TrackSizingFunction::Single(_) => None,
-                TrackSizingFunction::Repeat(Count(_), _) => None,
-                TrackSizingFunction::Repeat(AutoFit | AutoFill, tracks) => Some(tracks),
+                TrackSizingFunction::Repeat(Count(_), _, _) => None,
+                TrackSizingFunction::Repeat(AutoFit | AutoFill, tracks, _) => Some(tracks),
             }
         })
         .unwrap();
@@ -121,7 +121,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                         TrackSizingFunction::Single(sizing_function) => {
                             track_definite_value(sizing_function, parent_size, &resolve_calc_value)
                         }
-                        TrackSizingFunction::Repeat(Count(count), repeated_tracks) => {
+                        TrackSizingFunction::Repeat(Count(count), repeated_tracks, _) => {
                             let sum = repeated_tracks
                                 .iter()
                                 .map(|sizing_function| {
@@ -130,7 +130,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                                 .sum::<f32>();
                             sum * (*count as f32)
                         }
-                        TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0.0,
+                        TrackSizingFunction::Repeat(AutoFit | AutoFill, _, _) => 0.0,
                     }
                 })
                 .sum();
@@ -177,6 +177,28 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
     non_auto_repeating_track_count + (repetition_track_count * num_repetitions)
 }
 
+/// Extend a track template so that it defines `explicit_track_count` tracks. This is required when the grid's template
+/// areas span more tracks than the template defines, in which case the additional tracks are sized by the auto tracks.
+pub(super) fn extend_track_template(
+    template: &[TrackSizingFunction],
+    template_track_count: u16,
+    auto_tracks: &[NonRepeatedTrackSizingFunction],
+    explicit_track_count: u16,
+) -> Vec<TrackSizingFunction> {
+    // A template that generates no tracks (because it is empty or invalid) is ignored entirely
+    let mut extended_template: Vec<TrackSizingFunction> =
+        if template_track_count > 0 { template.to_vec() } else { Vec::new() };
+    let additional_track_count = (explicit_track_count - template_track_count) as usize;
+    if auto_tracks.is_empty() {
+        let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
+        extended_template.extend(iter.take(additional_track_count).map(TrackSizingFunction::Single));
+    } else {
+        let iter = auto_tracks.iter().copied().cycle();
+        extended_template.extend(iter.take(additional_track_count).map(TrackSizingFunction::Single));
+    }
+    extended_template
+}
+
 /// Resolve the track sizing functions of explicit tracks, automatically created tracks, and gutters
 /// given a set of track counts and all of the relevant styles
 pub(super) fn initialize_grid_tracks(
@@ -222,7 +244,7 @@ pub(super) fn initialize_grid_tracks(
                     tracks.push(GridTrack::gutter(gap));
                     current_track_index += 1;
                 }
-                TrackSizingFunction::Repeat(Count(count), repeated_tracks) => {
+                TrackSizingFunction::Repeat(Count(count), repeated_tracks, _) => {
                     let track_iter = repeated_tracks.iter().cycle().take(repeated_tracks.len() * *count as usize);
                     track_iter.for_each(|sizing_function| {
                         tracks.push(GridTrack::new(
@@ -233,7 +255,7 @@ pub(super) fn initialize_grid_tracks(
                         current_track_index += 1;
                     });
                 }
-                TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), repeated_tracks) => {
+                TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), repeated_tracks, _) => {
                     let auto_repeated_track_count = (counts.explicit - (track_template.len() as u16 - 1)) as usize;
                     let iter = repeated_tracks.iter().copied().cycle();
                     for track_def in iter.take(auto_repeated_track_count) {
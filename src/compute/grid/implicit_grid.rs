@@ -5,6 +5,7 @@ use crate::style::{GenericGridPlacement, GridPlacement};
 use crate::GridItemStyle;
 use core::cmp::{max, min};
 
+use super::line_names::NamedLineResolver;
 use super::types::TrackCounts;
 use super::OriginZeroLine;
 
@@ -17,14 +18,17 @@ use super::OriginZeroLine;
 ///
 /// Note that this function internally mixes use of grid track numbers and grid line numbers
 pub(crate) fn compute_grid_size_estimate<'a, S: GridItemStyle + 'a>(
-    explicit_col_count: u16,
-    explicit_row_count: u16,
+    column_names: &NamedLineResolver,
+    row_names: &NamedLineResolver,
     child_styles_iter: impl Iterator<Item = S>,
 ) -> (TrackCounts, TrackCounts) {
+    let explicit_col_count = column_names.explicit_track_count();
+    let explicit_row_count = row_names.explicit_track_count();
+
     // Iterate over children, producing an estimate of the min and max grid lines (in origin-zero coordinates where)
     // along with the span of each item
     let (col_min, col_max, col_max_span, row_min, row_max, row_max_span) =
-        get_known_child_positions(child_styles_iter, explicit_col_count, explicit_row_count);
+        get_known_child_positions(child_styles_iter, column_names, row_names);
 
     // Compute *track* count estimates for each axis from:
     //   - The explicit track counts
@@ -63,8 +67,8 @@ pub(crate) fn compute_grid_size_estimate<'a, S: GridItemStyle + 'a>(
 /// The span is measured in tracks spanned
 fn get_known_child_positions<'a, S: GridItemStyle + 'a>(
     children_iter: impl Iterator<Item = S>,
-    explicit_col_count: u16,
-    explicit_row_count: u16,
+    column_names: &NamedLineResolver,
+    row_names: &NamedLineResolver,
 ) -> (OriginZeroLine, OriginZeroLine, u16, OriginZeroLine, OriginZeroLine, u16) {
     let (mut col_min, mut col_max, mut col_max_span) = (OriginZeroLine(0), OriginZeroLine(0), 0);
     let (mut row_min, mut row_max, mut row_max_span) = (OriginZeroLine(0), OriginZeroLine(0), 0);
@@ -72,9 +76,9 @@ fn get_known_child_positions<'a, S: GridItemStyle + 'a>(
         // Note: that the children reference the lines in between (and around) the tracks not tracks themselves,
         // and thus we must subtract 1 to get an accurate estimate of the number of tracks
         let (child_col_min, child_col_max, child_col_span) =
-            child_min_line_max_line_span(child_style.grid_column(), explicit_col_count);
+            child_min_line_max_line_span(child_style.grid_column(), column_names);
         let (child_row_min, child_row_max, child_row_span) =
-            child_min_line_max_line_span(child_style.grid_row(), explicit_row_count);
+            child_min_line_max_line_span(child_style.grid_row(), row_names);
         col_min = min(col_min, child_col_min);
         col_max = max(col_max, child_col_max);
         col_max_span = max(col_max_span, child_col_span);
@@ -93,7 +97,7 @@ fn get_known_child_positions<'a, S: GridItemStyle + 'a>(
 #[inline]
 fn child_min_line_max_line_span(
     line: Line<GridPlacement>,
-    explicit_track_count: u16,
+    line_names: &NamedLineResolver,
 ) -> (OriginZeroLine, OriginZeroLine, u16) {
     use GenericGridPlacement::*;
 
@@ -104,7 +108,7 @@ fn child_min_line_max_line_span(
     // D. If the placement contains only a span for a named line, replace it with a span of 1.
 
     // Convert line into origin-zero coordinates before attempting to analyze
-    let oz_line = line.into_origin_zero(explicit_track_count);
+    let oz_line = line_names.resolve(&line);
 
     let min = match (oz_line.start, oz_line.end) {
         // Both tracks specified
@@ -156,8 +160,8 @@ fn child_min_line_max_line_span(
 
     // Calculate span only for indefinitely placed items as we don't need for other items (whose required space will
     // be taken into account by min and max)
-    let span = match (line.start, line.end) {
-        (Auto | Span(_), Auto | Span(_)) => line.indefinite_span(),
+    let span = match (oz_line.start, oz_line.end) {
+        (Auto | Span(_), Auto | Span(_)) => oz_line.indefinite_span(),
         _ => 1,
     };
 
@@ -169,13 +173,17 @@ fn child_min_line_max_line_span(
 mod tests {
     mod test_child_min_max_line {
         use super::super::child_min_line_max_line_span;
+        use super::super::NamedLineResolver;
         use super::super::OriginZeroLine;
         use crate::geometry::Line;
         use crate::style_helpers::*;
 
         #[test]
         fn child_min_max_line_auto() {
-            let (min_col, max_col, span) = child_min_line_max_line_span(Line { start: line(5), end: span(6) }, 6);
+            let (min_col, max_col, span) = child_min_line_max_line_span(
+                Line { start: line(5), end: span(6) },
+                &NamedLineResolver::new(&[], &[], 6),
+            );
             assert_eq!(min_col, OriginZeroLine(4));
             assert_eq!(max_col, OriginZeroLine(10));
             assert_eq!(span, 1);
@@ -183,7 +191,10 @@ mod tests {
 
         #[test]
         fn child_min_max_line_negative_track() {
-            let (min_col, max_col, span) = child_min_line_max_line_span(Line { start: line(-5), end: span(3) }, 6);
+            let (min_col, max_col, span) = child_min_line_max_line_span(
+                Line { start: line(-5), end: span(3) },
+                &NamedLineResolver::new(&[], &[], 6),
+            );
             assert_eq!(min_col, OriginZeroLine(2));
             assert_eq!(max_col, OriginZeroLine(5));
             assert_eq!(span, 1);
@@ -192,6 +203,7 @@ mod tests {
 
     mod test_initial_grid_sizing {
         use super::super::compute_grid_size_estimate;
+        use crate::compute::grid::line_names::NamedLineResolver;
         use crate::compute::grid::util::test_helpers::*;
         use crate::style_helpers::*;
 
@@ -203,8 +215,11 @@ mod tests {
                 (line(1), span(2), line(2), auto()).into_grid_child(),
                 (line(-4), auto(), line(-2), auto()).into_grid_child(),
             ];
-            let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+            let (inline, block) = compute_grid_size_estimate(
+                &NamedLineResolver::new(&[], &[], explicit_col_count),
+                &NamedLineResolver::new(&[], &[], explicit_row_count),
+                child_styles.iter(),
+            );
             assert_eq!(inline.negative_implicit, 0);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
@@ -221,8 +236,11 @@ mod tests {
                 (line(-6), span(2), line(-8), auto()).into_grid_child(),
                 (line(4), auto(), line(3), auto()).into_grid_child(),
             ];
-            let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+            let (inline, block) = compute_grid_size_estimate(
+                &NamedLineResolver::new(&[], &[], explicit_col_count),
+                &NamedLineResolver::new(&[], &[], explicit_row_count),
+                child_styles.iter(),
+            );
             assert_eq!(inline.negative_implicit, 1);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
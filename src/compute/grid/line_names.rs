@@ -0,0 +1,217 @@
+//! Resolution of named grid lines into the lines of the grid
+//! <https://www.w3.org/TR/css-grid-1/#line-placement>
+use super::OriginZeroLine;
+use crate::geometry::{AbsoluteAxis, Line};
+use crate::style::{
+    GridPlacement, GridTemplateArea, GridTrackRepetition, OriginZeroGridPlacement, TrackSizingFunction,
+};
+use crate::util::sys::{GridTrackVec, String, Vec};
+
+/// The named lines of the explicit grid in a single axis. Used to resolve [`GridPlacement`]s (which may refer to
+/// lines by name) into origin-zero coordinates.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NamedLineResolver {
+    /// The number of tracks in the explicit grid
+    explicit_track_count: u16,
+    /// Each name along with the (origin-zero) line that it names, in line order
+    names: Vec<(String, i16)>,
+}
+
+impl NamedLineResolver {
+    /// Create from a grid template and the names of the lines between its entries
+    pub(crate) fn new(
+        template: &[TrackSizingFunction],
+        line_names: &[GridTrackVec<String>],
+        explicit_track_count: u16,
+    ) -> Self {
+        let mut resolver = Self { explicit_track_count, names: Vec::new() };
+
+        // An invalid template generates no explicit tracks, in which case its line names are ignored
+        if explicit_track_count == 0 || (line_names.is_empty() && !template.iter().any(has_repeated_line_names)) {
+            return resolver;
+        }
+
+        use GridTrackRepetition::{AutoFill, AutoFit, Count};
+        let non_auto_repeating_track_count: u16 = template
+            .iter()
+            .map(|track_def| match track_def {
+                TrackSizingFunction::Single(_) => 1,
+                TrackSizingFunction::Repeat(Count(count), tracks, _) => count * tracks.len() as u16,
+                TrackSizingFunction::Repeat(AutoFit | AutoFill, _, _) => 0,
+            })
+            .sum();
+
+        let mut line = 0;
+        for (index, track_def) in template.iter().enumerate() {
+            resolver.add_names(line_names.get(index), line);
+            match track_def {
+                TrackSizingFunction::Single(_) => line += 1,
+                TrackSizingFunction::Repeat(repetition, tracks, repeated_line_names) => {
+                    let repetition_count = match repetition {
+                        Count(count) => *count,
+                        AutoFit | AutoFill => {
+                            (explicit_track_count - non_auto_repeating_track_count) / tracks.len() as u16
+                        }
+                    };
+                    for _ in 0..repetition_count {
+                        for track_index in 0..tracks.len() {
+                            resolver.add_names(repeated_line_names.get(track_index), line);
+                            line += 1;
+                        }
+                        resolver.add_names(repeated_line_names.get(tracks.len()), line);
+                    }
+                }
+            }
+        }
+        resolver.add_names(line_names.get(template.len()), line);
+
+        resolver
+    }
+
+    /// Create from the names of each line of the explicit grid. Used for subgrids, whose explicit tracks are
+    /// inherited from the parent grid.
+    pub(crate) fn from_line_names(line_names: &[GridTrackVec<String>], explicit_track_count: u16) -> Self {
+        let mut resolver = Self { explicit_track_count, names: Vec::new() };
+        for (line, names) in line_names.iter().take(explicit_track_count as usize + 1).enumerate() {
+            resolver.add_names(Some(names), line as i16);
+        }
+        resolver
+    }
+
+    /// Add the implicit `<name>-start` and `<name>-end` line names of the named grid areas. The explicit grid is
+    /// extended if required to contain all of the areas.
+    pub(crate) fn add_areas(&mut self, areas: &[GridTemplateArea], axis: AbsoluteAxis) {
+        if areas.is_empty() {
+            return;
+        }
+
+        for area in areas {
+            let lines = area.lines(axis);
+            let mut start_name = area.name.clone();
+            start_name.push_str("-start");
+            let mut end_name = area.name.clone();
+            end_name.push_str("-end");
+            self.names.push((start_name, lines.start as i16 - 1));
+            self.names.push((end_name, lines.end as i16 - 1));
+            self.explicit_track_count = self.explicit_track_count.max(lines.end - 1);
+        }
+
+        // Keep the names in line order
+        self.names.sort_by_key(|(_, line)| *line);
+    }
+
+    /// Add names to the specified line
+    fn add_names(&mut self, names: Option<&GridTrackVec<String>>, line: i16) {
+        if let Some(names) = names {
+            self.names.extend(names.iter().map(|name| (name.clone(), line)));
+        }
+    }
+
+    /// The number of tracks in the explicit grid
+    pub(crate) fn explicit_track_count(&self) -> u16 {
+        self.explicit_track_count
+    }
+
+    /// Resolve a grid placement into origin-zero coordinates, replacing named lines with the lines that they refer to
+    pub(crate) fn resolve(&self, placement: &Line<GridPlacement>) -> Line<OriginZeroGridPlacement> {
+        let start = self.resolve_placement(&placement.start, "-start");
+        let end = self.resolve_placement(&placement.end, "-end");
+
+        // A span to a named line counts from the line at the other end of the placement. If that end is not a line
+        // either then the span is treated as a span of one.
+        match (&placement.start, &placement.end, start, end) {
+            (GridPlacement::NamedSpan(name, span), _, _, OriginZeroGridPlacement::Line(end)) => Line {
+                start: OriginZeroGridPlacement::Line(self.find_line_before(name, *span, end)),
+                end: OriginZeroGridPlacement::Line(end),
+            },
+            (_, GridPlacement::NamedSpan(name, span), OriginZeroGridPlacement::Line(start), _) => Line {
+                start: OriginZeroGridPlacement::Line(start),
+                end: OriginZeroGridPlacement::Line(self.find_line_after(name, *span, start)),
+            },
+            _ => Line { start, end },
+        }
+    }
+
+    /// Resolve a single grid placement. Spans to named lines are resolved as a span of one.
+    fn resolve_placement(&self, placement: &GridPlacement, area_edge_suffix: &str) -> OriginZeroGridPlacement {
+        match placement {
+            GridPlacement::Auto => OriginZeroGridPlacement::Auto,
+            GridPlacement::Span(span) => OriginZeroGridPlacement::Span(*span),
+            GridPlacement::NamedSpan(..) => OriginZeroGridPlacement::Span(1),
+            // Grid line zero is an invalid index, so it gets treated as Auto
+            // See: https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start#values
+            GridPlacement::Line(line) => match line.as_i16() {
+                0 => OriginZeroGridPlacement::Auto,
+                _ => OriginZeroGridPlacement::Line(line.into_origin_zero_line(self.explicit_track_count)),
+            },
+            GridPlacement::NamedLine(name, index) => {
+                OriginZeroGridPlacement::Line(self.find_named_line(name, *index, area_edge_suffix))
+            }
+        }
+    }
+
+    /// The distinct lines with the specified name, in line order
+    fn lines_named(&self, name: &str) -> Vec<i16> {
+        let mut lines: Vec<i16> =
+            self.names.iter().filter(|(line_name, _)| line_name == name).map(|(_, line)| *line).collect();
+        lines.dedup();
+        lines
+    }
+
+    /// Find the nth line with the specified name. If there are not enough lines with that name, then all implicit
+    /// lines are assumed to have the name.
+    fn find_named_line(&self, name: &str, mut index: i16, area_edge_suffix: &str) -> OriginZeroLine {
+        // Without an index, the name first refers to the edge of the grid area with that name
+        if index == 0 {
+            let area_edge = self.names.iter().find(|(line_name, _)| {
+                line_name.len() == name.len() + area_edge_suffix.len()
+                    && line_name.starts_with(name)
+                    && line_name.ends_with(area_edge_suffix)
+            });
+            if let Some((_, line)) = area_edge {
+                return OriginZeroLine(*line);
+            }
+            index = 1;
+        }
+
+        let lines = self.lines_named(name);
+        let line_count = lines.len() as i16;
+        if index > 0 {
+            match lines.get(index as usize - 1) {
+                Some(line) => OriginZeroLine(*line),
+                None => OriginZeroLine(self.explicit_track_count as i16 + (index - line_count)),
+            }
+        } else if -index <= line_count {
+            OriginZeroLine(lines[(line_count + index) as usize])
+        } else {
+            OriginZeroLine(-(-index - line_count))
+        }
+    }
+
+    /// Find the nth line with the specified name before the `end` line. If there are not enough lines with that name,
+    /// then all of the implicit lines before the explicit grid are assumed to have the name.
+    fn find_line_before(&self, name: &str, span: u16, end: OriginZeroLine) -> OriginZeroLine {
+        let lines: Vec<i16> = self.lines_named(name).into_iter().filter(|&line| line < end.0).collect();
+        let span = span.max(1) as usize;
+        match lines.len().checked_sub(span) {
+            Some(index) => OriginZeroLine(lines[index]),
+            None => OriginZeroLine(end.0.min(0) - (span - lines.len()) as i16),
+        }
+    }
+
+    /// Find the nth line with the specified name after the `start` line. If there are not enough lines with that name,
+    /// then all of the implicit lines after the explicit grid are assumed to have the name.
+    fn find_line_after(&self, name: &str, span: u16, start: OriginZeroLine) -> OriginZeroLine {
+        let lines: Vec<i16> = self.lines_named(name).into_iter().filter(|&line| line > start.0).collect();
+        let span = span.max(1) as usize;
+        match lines.get(span - 1) {
+            Some(line) => OriginZeroLine(*line),
+            None => OriginZeroLine(start.0.max(self.explicit_track_count as i16) + (span - lines.len()) as i16),
+        }
+    }
+}
+
+/// Whether a track definition is a repetition that includes line names
+fn has_repeated_line_names(track_def: &TrackSizingFunction) -> bool {
+    matches!(track_def, TrackSizingFunction::Repeat(_, _, line_names) if !line_names.is_empty())
+}
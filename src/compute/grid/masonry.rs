@@ -0,0 +1,554 @@
+//! Masonry layout: grids with a masonry axis, in which items are packed into the tracks of the other ("grid") axis
+//! rather than being placed into cells.
+//! <https://drafts.csswg.org/css-grid-3/#masonry-layout>
+use super::alignment::{align_and_position_item, align_tracks, resolve_column_position, resolve_column_range};
+use super::explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
+use super::line_names::NamedLineResolver;
+use super::track_sizing::{
+    determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
+};
+use super::types::{GridItem, GridTrack, TrackCounts};
+use super::OriginZeroLine;
+use crate::compute::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
+use crate::compute::common::scrollbars::{resolve_scrollbar_gutter_inset, resolve_scrollbar_size};
+use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis, Line, Point, Rect, Size};
+use crate::style::{AlignContent, AlignItems, AvailableSpace, Direction, Position};
+use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutPartialTreeExt, NodeId, RunMode, SizingMode};
+use crate::util::sys::{f32_max, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::{
+    style_helpers::*, BoxGenerationMode, BoxSizing, CoreStyle, GridContainerStyle, GridItemStyle, LayoutGridContainer,
+    LengthPercentage, TrackSizingFunction,
+};
+use core::borrow::Borrow;
+use core::cmp::max;
+
+/// The position of an item that has been placed into a masonry grid
+struct MasonryPlacement {
+    /// The item's placement in the grid axis (with its placement in the masonry axis unused)
+    item: GridItem,
+    /// The offset of the item's margin box from the start of the masonry axis (relative to the content box)
+    offset: f32,
+    /// The size of the item's margin box in the masonry axis
+    size: f32,
+}
+
+/// Masonry layout algorithm
+///
+/// The tracks in the grid axis are sized using the grid track sizing algorithm, with each auto-placed item
+/// contributing to every track that it could be placed in. Items are then placed into the tracks of the grid axis
+/// one at a time, each being stacked after the items already placed in the track(s) it is placed into.
+pub(super) fn compute_masonry_layout<Tree: LayoutGridContainer>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    masonry_axis: AbstractAxis,
+    auto_scrollbars: Point<bool>,
+) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
+    let grid_axis = masonry_axis.other();
+    let abs_grid_axis = grid_axis.as_abs_naive();
+
+    let style = tree.get_grid_container_style(node);
+
+    // Resolve the container's size styles in the same way as a regular grid
+    let aspect_ratio = style.aspect_ratio();
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding_border = padding + border;
+    let padding_border_size = padding_border.sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+
+    let min_size = style
+        .min_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let preferred_size = if inputs.sizing_mode == SizingMode::InherentSize {
+        style
+            .size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+    } else {
+        Size::NONE
+    };
+
+    let scrollbar_size =
+        resolve_scrollbar_size(style.overflow(), style.scrollbar_width(), style.scrollbar_gutter(), auto_scrollbars);
+    let direction = tree.resolve_direction(node);
+    let scrollbar_gutter_inset = resolve_scrollbar_gutter_inset(scrollbar_size, style.scrollbar_gutter(), direction);
+    let content_box_inset = padding_border + scrollbar_gutter_inset;
+
+    let grid_axis_alignment = style.grid_align_content(grid_axis);
+    let masonry_track_alignment = match masonry_axis {
+        AbstractAxis::Block => style.align_tracks(),
+        AbstractAxis::Inline => style.justify_tracks(),
+    }
+    .unwrap_or(AlignContent::Start);
+    let align_items = style.align_items();
+    // The absolutely positioned children of a `Position::Static` container are laid out again against their actual
+    // containing block by `TaffyTree`, so they don't contribute to its content size
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+    let is_containing_block = style.position().is_positioned();
+    let justify_items = style.justify_items();
+    let masonry_auto_flow = style.masonry_auto_flow();
+
+    let constrained_available_space = known_dimensions
+        .or(preferred_size)
+        .map(|size| size.map(AvailableSpace::Definite))
+        .unwrap_or(available_space)
+        .maybe_clamp(min_size, max_size)
+        .maybe_max(padding_border_size);
+    let available_grid_space = Size {
+        width: constrained_available_space
+            .width
+            .map_definite_value(|space| space - content_box_inset.horizontal_axis_sum()),
+        height: constrained_available_space
+            .height
+            .map_definite_value(|space| space - content_box_inset.vertical_axis_sum()),
+    };
+
+    let outer_node_size =
+        known_dimensions.or(preferred_size).maybe_clamp(min_size, max_size).maybe_max(padding_border_size);
+    let mut inner_node_size = Size {
+        width: outer_node_size.width.map(|space| space - content_box_inset.horizontal_axis_sum()),
+        height: outer_node_size.height.map(|space| space - content_box_inset.vertical_axis_sum()),
+    };
+
+    if let (RunMode::ComputeSize, Some(width), Some(height)) = (run_mode, outer_node_size.width, outer_node_size.height)
+    {
+        return LayoutOutput::from_outer_size(Size { width, height });
+    }
+
+    let masonry_gap = style
+        .gap()
+        .get_abs(masonry_axis.as_abs_naive())
+        .resolve_or_zero(inner_node_size.get(masonry_axis), |val, basis| tree.calc(val, basis));
+
+    // 1. Resolve the tracks of the grid axis
+    let auto_fit_container_size = outer_node_size
+        .or(max_size)
+        .or(min_size)
+        .maybe_clamp(min_size, max_size)
+        .maybe_max(padding_border_size)
+        .maybe_sub(content_box_inset.sum_axes());
+    let grid_template_tracks = style.grid_template_tracks(abs_grid_axis);
+    let explicit_track_count = compute_explicit_grid_size_in_axis(
+        &style,
+        grid_template_tracks.borrow(),
+        auto_fit_container_size,
+        |val, basis| tree.calc(val, basis),
+        abs_grid_axis,
+    );
+    let line_names = NamedLineResolver::new(
+        grid_template_tracks.borrow(),
+        style.grid_template_line_names(abs_grid_axis),
+        explicit_track_count,
+    );
+
+    let in_flow_children: Vec<NodeId> = tree
+        .child_ids(node)
+        .filter(|&child| {
+            let child_style = tree.get_grid_child_style(child);
+            child_style.box_generation_mode() != BoxGenerationMode::None
+                && !child_style.position().is_absolutely_positioned()
+        })
+        .collect();
+
+    // Determine the number of implicit tracks from the items with a definite placement in the grid axis, and ensure
+    // that there are enough tracks for the largest span of an auto-placed item
+    let mut track_counts = TrackCounts::from_raw(0, explicit_track_count, 0);
+    let mut max_auto_span = 0;
+    for &child in &in_flow_children {
+        let placement = line_names.resolve(&tree.get_grid_child_style(child).grid_placement(abs_grid_axis));
+        if placement.is_definite() {
+            let lines = placement.resolve_definite_grid_lines();
+            track_counts.negative_implicit =
+                max(track_counts.negative_implicit, lines.start.implied_negative_implicit_tracks());
+            track_counts.positive_implicit =
+                max(track_counts.positive_implicit, lines.end.implied_positive_implicit_tracks(explicit_track_count));
+        } else {
+            max_auto_span = max(max_auto_span, placement.indefinite_span());
+        }
+    }
+    if (track_counts.len() as u16) < max_auto_span {
+        track_counts.positive_implicit = max_auto_span - track_counts.negative_implicit - track_counts.explicit;
+    }
+
+    let mut grid_tracks = Vec::new();
+    initialize_grid_tracks(
+        &mut grid_tracks,
+        track_counts,
+        grid_template_tracks.borrow(),
+        match abs_grid_axis {
+            AbsoluteAxis::Horizontal => style.grid_auto_columns(),
+            AbsoluteAxis::Vertical => style.grid_auto_rows(),
+        }
+        .borrow(),
+        style.gap().get_abs(abs_grid_axis),
+        |_| true,
+    );
+    // The masonry axis is represented by a single auto-sized track which spans the whole axis
+    let mut masonry_tracks = Vec::new();
+    initialize_grid_tracks(
+        &mut masonry_tracks,
+        TrackCounts::from_raw(0, 1, 0),
+        &[TrackSizingFunction::AUTO],
+        &[],
+        LengthPercentage::ZERO,
+        |_| true,
+    );
+
+    drop(grid_template_tracks);
+    drop(style);
+
+    let first_line = OriginZeroLine(-(track_counts.negative_implicit as i16));
+    let last_line = OriginZeroLine((track_counts.explicit + track_counts.positive_implicit) as i16);
+    let masonry_placement = Line { start: OriginZeroLine(0), end: OriginZeroLine(1) };
+    let create_item = |tree: &Tree, child: NodeId, grid_placement: Line<OriginZeroLine>, source_order: usize| {
+        let (column, row) = match grid_axis {
+            AbstractAxis::Inline => (grid_placement, masonry_placement),
+            AbstractAxis::Block => (masonry_placement, grid_placement),
+        };
+        GridItem::new_with_placement_style_and_order(
+            child,
+            column,
+            row,
+            tree.get_grid_child_style(child),
+            align_items.unwrap_or(AlignItems::Stretch),
+            justify_items.unwrap_or(AlignItems::Stretch),
+            source_order as u16,
+        )
+    };
+    let resolve_item_tracks = |items: &mut [GridItem], grid_tracks: &[GridTrack], masonry_tracks: &[GridTrack]| {
+        let (column_counts, row_counts, columns, rows) = match grid_axis {
+            AbstractAxis::Inline => (track_counts, TrackCounts::from_raw(0, 1, 0), grid_tracks, masonry_tracks),
+            AbstractAxis::Block => (TrackCounts::from_raw(0, 1, 0), track_counts, masonry_tracks, grid_tracks),
+        };
+        resolve_item_track_indexes(items, column_counts, row_counts);
+        determine_if_item_crosses_flexible_or_intrinsic_tracks(items, columns, rows);
+    };
+
+    // 2. Size the tracks of the grid axis. Items with a definite placement contribute to the tracks they span, whereas
+    // auto-placed items contribute to every track that they could be placed in.
+    let mut items = Vec::with_capacity(in_flow_children.len());
+    for (index, &child) in in_flow_children.iter().enumerate() {
+        let placement = line_names.resolve(&tree.get_grid_child_style(child).grid_placement(abs_grid_axis));
+        if placement.is_definite() {
+            items.push(create_item(tree, child, placement.resolve_definite_grid_lines(), index));
+        } else {
+            let mut start = first_line;
+            while start + placement.indefinite_span() <= last_line {
+                items.push(create_item(tree, child, placement.resolve_indefinite_grid_tracks(start), index));
+                start += 1;
+            }
+        }
+    }
+    resolve_item_tracks(&mut items, &grid_tracks, &masonry_tracks);
+
+    let (axis_min_size, axis_max_size) = (min_size.get(grid_axis), max_size.get(grid_axis));
+    track_sizing_algorithm(
+        tree,
+        grid_axis,
+        axis_min_size,
+        axis_max_size,
+        grid_axis_alignment,
+        AlignContent::Start,
+        available_grid_space,
+        inner_node_size,
+        &mut grid_tracks,
+        &mut masonry_tracks,
+        &mut items,
+        |track: &GridTrack, parent_size: Option<f32>, tree: &Tree| {
+            track.max_track_sizing_function.definite_value(parent_size, |val, basis| tree.calc(val, basis))
+        },
+        false,
+    );
+    let grid_axis_track_sum = grid_tracks.iter().map(|track| track.base_size).sum::<f32>();
+    inner_node_size.set(grid_axis, inner_node_size.get(grid_axis).or(Some(grid_axis_track_sum)));
+
+    // 3. Place the items into the tracks of the grid axis, stacking them in the masonry axis
+    // Items are placed in order-modified document order (with definite items first, unless the auto flow is ordered)
+    let mut order: Vec<usize> = (0..in_flow_children.len()).collect();
+    order.sort_by_key(|&index| tree.get_grid_child_style(in_flow_children[index]).order());
+    let is_definite = |child: NodeId| tree.get_grid_child_style(child).grid_placement(abs_grid_axis).is_definite();
+    if !masonry_auto_flow.is_ordered() {
+        order.sort_by_key(|&index| !is_definite(in_flow_children[index]));
+    }
+
+    // The running position of each track of the grid axis in the masonry axis
+    let mut running_positions: Vec<f32> = core::iter::repeat(0.0).take(track_counts.len()).collect();
+    let mut next_track = 0;
+    let mut placements: Vec<MasonryPlacement> = Vec::with_capacity(in_flow_children.len());
+    for index in order {
+        let child = in_flow_children[index];
+        let placement = line_names.resolve(&tree.get_grid_child_style(child).grid_placement(abs_grid_axis));
+        let lines = if placement.is_definite() {
+            placement.resolve_definite_grid_lines()
+        } else {
+            let span = placement.indefinite_span() as usize;
+            let start_track = if masonry_auto_flow.is_pack() {
+                (0..=(track_counts.len() - span))
+                    .min_by(|&a, &b| {
+                        let a_position = running_positions[a..a + span].iter().copied().fold(0.0, f32_max);
+                        let b_position = running_positions[b..b + span].iter().copied().fold(0.0, f32_max);
+                        a_position.total_cmp(&b_position)
+                    })
+                    .unwrap_or(0)
+            } else if next_track + span <= track_counts.len() {
+                next_track
+            } else {
+                0
+            };
+            next_track = start_track + span;
+            placement.resolve_indefinite_grid_tracks(first_line + start_track as u16)
+        };
+
+        let tracks = (lines.start.0 - first_line.0) as usize..(lines.end.0 - first_line.0) as usize;
+        let offset = running_positions[tracks.clone()].iter().copied().fold(0.0, f32_max);
+
+        let mut item = create_item(tree, child, lines, index);
+        resolve_item_tracks(core::slice::from_mut(&mut item), &grid_tracks, &masonry_tracks);
+
+        // Measure the item in the masonry axis given the size of the tracks it spans in the grid axis
+        let grid_area_size: f32 =
+            grid_tracks[item.track_range_excluding_lines(grid_axis)].iter().map(|track| track.base_size).sum();
+        let mut item_available_space = Size::NONE;
+        item_available_space.set(grid_axis, Some(grid_area_size));
+        let size = item.max_content_contribution(masonry_axis, tree, item_available_space, inner_node_size)
+            + item.margins_axis_sums_with_baseline_shims(inner_node_size.width, tree).get(masonry_axis);
+
+        for position in &mut running_positions[tracks] {
+            *position = offset + size + masonry_gap;
+        }
+        placements.push(MasonryPlacement { item, offset, size });
+    }
+
+    // 4. Compute the container's size
+    let masonry_content_size = running_positions.iter().copied().fold(0.0, f32_max);
+    let masonry_content_size =
+        if placements.is_empty() { 0.0 } else { f32_max(0.0, masonry_content_size - masonry_gap) };
+    let mut content_size = Size::ZERO;
+    content_size.set(grid_axis, grid_axis_track_sum);
+    content_size.set(masonry_axis, masonry_content_size);
+
+    let resolved_style_size = known_dimensions.or(preferred_size);
+    let container_border_box = Size {
+        width: resolved_style_size
+            .width
+            .unwrap_or_else(|| content_size.width + content_box_inset.horizontal_axis_sum())
+            .maybe_clamp(min_size.width, max_size.width)
+            .max(padding_border_size.width),
+        height: resolved_style_size
+            .height
+            .unwrap_or_else(|| content_size.height + content_box_inset.vertical_axis_sum())
+            .maybe_clamp(min_size.height, max_size.height)
+            .max(padding_border_size.height),
+    };
+    let container_content_box = Size {
+        width: f32_max(0.0, container_border_box.width - content_box_inset.horizontal_axis_sum()),
+        height: f32_max(0.0, container_border_box.height - content_box_inset.vertical_axis_sum()),
+    };
+
+    if run_mode == RunMode::ComputeSize {
+        return LayoutOutput::from_outer_size(container_border_box);
+    }
+
+    // 5. Align the tracks of the grid axis, and the items within each track in the masonry axis
+    // (the scrollbar gutters lie between the border and the padding)
+    let (grid_axis_padding, grid_axis_border, masonry_axis_origin) = match grid_axis {
+        AbstractAxis::Inline => (
+            Line { start: padding.left, end: padding.right },
+            Line { start: border.left + scrollbar_gutter_inset.left, end: border.right + scrollbar_gutter_inset.right },
+            content_box_inset.top,
+        ),
+        AbstractAxis::Block => (
+            Line { start: padding.top, end: padding.bottom },
+            Line { start: border.top, end: border.bottom + scrollbar_gutter_inset.bottom },
+            content_box_inset.left,
+        ),
+    };
+    align_tracks(
+        container_content_box.get(grid_axis),
+        grid_axis_padding,
+        grid_axis_border,
+        &mut grid_tracks,
+        grid_axis_alignment,
+    );
+
+    // Items that span multiple tracks are not moved by track alignment
+    let masonry_axis_size = container_content_box.get(masonry_axis);
+    for (track, &running_position) in running_positions.iter().enumerate() {
+        let mut track_items: Vec<&mut MasonryPlacement> = placements
+            .iter_mut()
+            .filter(|placement| {
+                placement.item.track_range_excluding_lines(grid_axis).len() == 1 && {
+                    let start = placement.item.placement(grid_axis).start.0 - first_line.0;
+                    start as usize == track
+                }
+            })
+            .collect();
+        if track_items.is_empty() {
+            continue;
+        }
+        track_items.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        let used_size = running_position - masonry_gap;
+        let free_space = masonry_axis_size - used_size;
+        let item_count = track_items.len();
+        let alignment = apply_alignment_fallback(free_space, item_count, masonry_track_alignment);
+        let mut total_offset = 0.0;
+        for (index, placement) in track_items.into_iter().enumerate() {
+            total_offset += compute_alignment_offset(free_space, item_count, 0.0, alignment, false, index == 0);
+            placement.offset += total_offset;
+        }
+    }
+
+    // 6. Position the in-flow children
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut item_content_size_contribution = Size::ZERO;
+    let container_alignment_styles = InBothAbsAxis { horizontal: justify_items, vertical: align_items };
+    placements.sort_by_key(|placement| placement.item.source_order);
+
+    // The left and right edges of the content box as the items were laid out (from left to right)
+    let content_box_edges =
+        Line { start: content_box_inset.left, end: container_border_box.width - content_box_inset.right };
+
+    let mut first_baseline = None;
+    let mut last_baseline = None;
+    for (order, placement) in placements.iter().enumerate() {
+        let track_indexes = placement.item.placement_indexes(grid_axis);
+        let grid_axis_area = Line {
+            start: grid_tracks[track_indexes.start as usize + 1].offset,
+            end: grid_tracks[track_indexes.end as usize].offset,
+        };
+        let masonry_axis_area = Line {
+            start: masonry_axis_origin + placement.offset,
+            end: masonry_axis_origin + placement.offset + placement.size,
+        };
+        let (horizontal, vertical) = match grid_axis {
+            AbstractAxis::Inline => (grid_axis_area, masonry_axis_area),
+            AbstractAxis::Block => (masonry_axis_area, grid_axis_area),
+        };
+        let horizontal = resolve_column_range(horizontal, content_box_edges, direction);
+        let grid_area =
+            Rect { left: horizontal.start, right: horizontal.end, top: vertical.start, bottom: vertical.end };
+
+        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+        let (content_size_contribution, y_position, height) = align_and_position_item(
+            tree,
+            placement.item.node,
+            order as u32,
+            grid_area,
+            container_alignment_styles,
+            Rect::ZERO,
+            direction,
+        );
+        if first_baseline.is_none() {
+            first_baseline = Some(y_position + height);
+        }
+        last_baseline = Some(y_position + height);
+
+        #[cfg(feature = "content_size")]
+        {
+            item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
+        }
+    }
+
+    // 7. Position hidden and absolutely positioned children. Absolutely positioned children may be placed into the
+    // tracks of the grid axis, but their grid area always spans the whole masonry axis.
+    let mut order = placements.len() as u32;
+    for index in 0..tree.child_count(node) {
+        let child = tree.get_child_id(node, index);
+        let child_style = tree.get_grid_child_style(child);
+
+        if child_style.box_generation_mode() == BoxGenerationMode::None {
+            drop(child_style);
+            tree.set_unrounded_layout(child, &Layout::with_order(order));
+            tree.perform_child_layout(
+                child,
+                Size::NONE,
+                Size::NONE,
+                Size::MAX_CONTENT,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+            order += 1;
+            continue;
+        }
+
+        if child_style.position().is_absolutely_positioned() {
+            // Fixed items are positioned relative to the viewport, so don't contribute to the content size of the grid
+            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+            let contributes_to_content_size = is_containing_block && child_style.position() != Position::Fixed;
+            let maybe_track_indexes = line_names
+                .resolve(&child_style.grid_placement(abs_grid_axis))
+                .resolve_absolutely_positioned_grid_tracks()
+                .map(|maybe_grid_line| {
+                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(track_counts))
+                });
+            drop(child_style);
+
+            let grid_area = match abs_grid_axis {
+                AbsoluteAxis::Horizontal => {
+                    // Grid lines are mirrored in right-to-left containers, whereas auto lines always resolve to the
+                    // physical edges of the padding box
+                    let column_line =
+                        |index: usize| resolve_column_position(grid_tracks[index].offset, content_box_edges, direction);
+                    let (left_index, right_index) = match direction {
+                        Direction::Ltr => (maybe_track_indexes.start, maybe_track_indexes.end),
+                        Direction::Rtl => (maybe_track_indexes.end, maybe_track_indexes.start),
+                    };
+                    Rect {
+                        left: left_index.map(column_line).unwrap_or(border.left + scrollbar_gutter_inset.left),
+                        right: right_index
+                            .map(column_line)
+                            .unwrap_or(container_border_box.width - border.right - scrollbar_gutter_inset.right),
+                        top: border.top,
+                        bottom: container_border_box.height - border.bottom - scrollbar_gutter_inset.bottom,
+                    }
+                }
+                AbsoluteAxis::Vertical => Rect {
+                    left: border.left + scrollbar_gutter_inset.left,
+                    right: container_border_box.width - border.right - scrollbar_gutter_inset.right,
+                    top: maybe_track_indexes.start.map(|index| grid_tracks[index].offset).unwrap_or(border.top),
+                    bottom: maybe_track_indexes
+                        .end
+                        .map(|index| grid_tracks[index].offset)
+                        .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter_inset.bottom),
+                },
+            };
+
+            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+            let (content_size_contribution, _, _) = align_and_position_item(
+                tree,
+                child,
+                order,
+                grid_area,
+                container_alignment_styles,
+                Rect::ZERO,
+                direction,
+            );
+            #[cfg(feature = "content_size")]
+            if contributes_to_content_size {
+                item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
+            }
+
+            order += 1;
+        }
+    }
+
+    LayoutOutput::from_sizes_and_first_and_last_baselines(
+        container_border_box,
+        item_content_size_contribution,
+        Point { x: None, y: first_baseline },
+        Point { x: None, y: last_baseline },
+    )
+}
@@ -2,9 +2,13 @@
 //! <https://www.w3.org/TR/css-grid-1>
 use core::borrow::Borrow;
 
+use crate::compute::common::scrollbars::{
+    compute_with_auto_scrollbars, resolve_scrollbar_gutter_inset, resolve_scrollbar_size,
+};
+use crate::compute::common::writing_mode::compute_in_writing_mode;
 use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AlignItems, AlignSelf, AvailableSpace, Overflow, Position};
+use crate::style::{AlignItems, AlignSelf, AvailableSpace, Direction, Position};
 use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutPartialTreeExt, NodeId, RunMode, SizingMode};
 use crate::util::debug::debug_log;
 use crate::util::sys::{f32_max, GridTrackVec, Vec};
@@ -12,26 +16,36 @@ use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{
     style_helpers::*, AlignContent, BoxGenerationMode, BoxSizing, CoreStyle, GridContainerStyle, GridItemStyle,
-    JustifyContent, LayoutGridContainer,
+    JustifyContent, LayoutGridContainer, LengthPercentage,
+};
+use alignment::{
+    absolutely_positioned_item_baseline_shim, align_and_position_item, align_tracks, resolve_column_position,
+    resolve_column_range,
 };
-use alignment::{align_and_position_item, align_tracks};
-use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
+use explicit_grid::{compute_explicit_grid_size_in_axis, extend_track_template, initialize_grid_tracks};
 use implicit_grid::compute_grid_size_estimate;
-use placement::place_grid_items;
+use line_names::NamedLineResolver;
+use masonry::compute_masonry_layout;
+use placement::{in_flow_children_in_order, place_grid_items};
+use subgrid::{clamp_placement_to_subgrid, generate_subgrid_child_items, set_subgrid_tracks};
 use track_sizing::{
     determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
 };
-use types::{CellOccupancyMatrix, GridTrack};
+use types::{CellOccupancyMatrix, GridTrack, TrackCounts};
 
 #[cfg(feature = "detailed_layout_info")]
-use types::{GridItem, GridTrackKind, TrackCounts};
+use types::{GridItem, GridTrackKind};
 
+pub use subgrid::{SubgridAxisTracks, SubgridTracks};
 pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine};
 
 mod alignment;
 mod explicit_grid;
 mod implicit_grid;
+mod line_names;
+mod masonry;
 mod placement;
+mod subgrid;
 mod track_sizing;
 mod types;
 mod util;
@@ -46,11 +60,37 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     tree: &mut Tree,
     node: NodeId,
     inputs: LayoutInput,
+) -> LayoutOutput {
+    let writing_mode = tree.resolve_writing_mode(node);
+    if writing_mode.is_vertical() {
+        return compute_in_writing_mode(tree, writing_mode, inputs, |tree, inputs| {
+            compute_with_auto_scrollbars(tree, node, inputs, compute_logical_layout)
+        });
+    }
+    compute_with_auto_scrollbars(tree, node, inputs, compute_logical_layout)
+}
+
+/// Computes the layout of a CSS Grid container in the logical coordinate space of the container
+fn compute_logical_layout<Tree: LayoutGridContainer>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    auto_scrollbars: Point<bool>,
 ) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
     let style = tree.get_grid_container_style(node);
 
+    // Grids with a masonry axis are laid out using a separate algorithm
+    if let Some(masonry_axis) = style.masonry_axis() {
+        drop(style);
+        let masonry_axis = match masonry_axis {
+            AbsoluteAxis::Horizontal => AbstractAxis::Inline,
+            AbsoluteAxis::Vertical => AbstractAxis::Block,
+        };
+        return compute_masonry_layout(tree, node, inputs, masonry_axis, auto_scrollbars);
+    }
+
     // 1. Compute "available grid space"
     // https://www.w3.org/TR/css-grid-1/#available-grid-space
     let aspect_ratio = style.aspect_ratio();
@@ -81,21 +121,21 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         Size::NONE
     };
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`, or to
+    // `Overflow::Auto` and the content was found to overflow the node, unless overridden by `scrollbar_gutter`.
+    let scrollbar_size =
+        resolve_scrollbar_size(style.overflow(), style.scrollbar_width(), style.scrollbar_gutter(), auto_scrollbars);
+    let direction = tree.resolve_direction(node);
+    let scrollbar_gutter_inset = resolve_scrollbar_gutter_inset(scrollbar_size, style.scrollbar_gutter(), direction);
+    let content_box_inset = padding_border + scrollbar_gutter_inset;
 
     let align_content = style.align_content().unwrap_or(AlignContent::Stretch);
     let justify_content = style.justify_content().unwrap_or(JustifyContent::Stretch);
     let align_items = style.align_items();
+    // The absolutely positioned children of a `Position::Static` container are laid out again against their actual
+    // containing block by `TaffyTree`, so they don't contribute to its content size
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+    let is_containing_block = style.position().is_positioned();
     let justify_items = style.justify_items();
 
     // Note: we avoid accessing the grid rows/columns methods more than once as this can
@@ -105,6 +145,15 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     let grid_auto_columms = style.grid_auto_columns();
     let grid_auto_rows = style.grid_auto_rows();
 
+    // A subgrid uses the tracks inherited from its parent grid in place of its own tracks in the subgridded axes
+    let subgrid_tracks = tree.get_subgrid_tracks(node);
+    let inherited_columns = subgrid_tracks.columns.as_ref().filter(|_| style.subgrid_columns());
+    let inherited_rows = subgrid_tracks.rows.as_ref().filter(|_| style.subgrid_rows());
+    let inherited_column_template = inherited_columns.map(SubgridAxisTracks::track_template);
+    let inherited_row_template = inherited_rows.map(SubgridAxisTracks::track_template);
+    let column_template = inherited_column_template.as_deref().unwrap_or(grid_template_columms.borrow());
+    let row_template = inherited_row_template.as_deref().unwrap_or(grid_template_rows.borrow());
+
     let constrained_available_space = known_dimensions
         .or(preferred_size)
         .map(|size| size.map(AvailableSpace::Definite))
@@ -155,49 +204,89 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     // Exactly compute the number of rows and columns in the explicit grid.
     let explicit_col_count = compute_explicit_grid_size_in_axis(
         &style,
-        grid_template_columms.borrow(),
+        column_template,
         auto_fit_container_size,
         |val, basis| tree.calc(val, basis),
         AbsoluteAxis::Horizontal,
     );
     let explicit_row_count = compute_explicit_grid_size_in_axis(
         &style,
-        grid_template_rows.borrow(),
+        row_template,
         auto_fit_container_size,
         |val, basis| tree.calc(val, basis),
         AbsoluteAxis::Vertical,
     );
 
+    // Resolve the names of the lines of the explicit grid, which grid items may be placed relative to
+    let mut column_names =
+        NamedLineResolver::new(column_template, style.grid_template_column_names(), explicit_col_count);
+    let mut row_names = NamedLineResolver::new(row_template, style.grid_template_row_names(), explicit_row_count);
+
+    // Named grid areas implicitly name the lines at their edges, and extend the explicit grid if they span more tracks
+    // than the template defines (the template areas are ignored in axes inherited from a parent grid)
+    if inherited_columns.is_none() {
+        column_names.add_areas(style.grid_template_areas(), AbsoluteAxis::Horizontal);
+    }
+    if inherited_rows.is_none() {
+        row_names.add_areas(style.grid_template_areas(), AbsoluteAxis::Vertical);
+    }
+    let extended_column_template = (column_names.explicit_track_count() > explicit_col_count).then(|| {
+        extend_track_template(
+            column_template,
+            explicit_col_count,
+            grid_auto_columms.borrow(),
+            column_names.explicit_track_count(),
+        )
+    });
+    let extended_row_template = (row_names.explicit_track_count() > explicit_row_count).then(|| {
+        extend_track_template(
+            row_template,
+            explicit_row_count,
+            grid_auto_rows.borrow(),
+            row_names.explicit_track_count(),
+        )
+    });
+    let column_template = extended_column_template.as_deref().unwrap_or(column_template);
+    let row_template = extended_row_template.as_deref().unwrap_or(row_template);
+
     // 3. Implicit Grid: Estimate Track Counts
     // Estimate the number of rows and columns in the implicit grid (= the entire grid)
     // This is necessary as part of placement. Doing it early here is a perf optimisation to reduce allocations.
-    let (est_col_counts, est_row_counts) =
-        compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+    let (est_col_counts, est_row_counts) = compute_grid_size_estimate(&column_names, &row_names, child_styles_iter);
 
     // 4. Grid Item Placement
     // Match items (children) to a definite grid position (row start/end and column start/end position)
     let mut items = Vec::with_capacity(tree.child_count(node));
     let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
+    let in_flow_children = in_flow_children_in_order(tree, node);
     let in_flow_children_iter = || {
-        tree.child_ids(node)
-            .enumerate()
-            .map(|(index, child_node)| (index, child_node, tree.get_grid_child_style(child_node)))
-            .filter(|(_, _, style)| {
-                style.box_generation_mode() != BoxGenerationMode::None && style.position() != Position::Absolute
-            })
+        in_flow_children.iter().map(|&(index, child_node)| (index, child_node, tree.get_grid_child_style(child_node)))
     };
     place_grid_items(
         &mut cell_occupancy_matrix,
         &mut items,
         in_flow_children_iter,
+        InBothAbsAxis { horizontal: &column_names, vertical: &row_names },
         style.grid_auto_flow(),
         align_items.unwrap_or(AlignItems::Stretch),
         justify_items.unwrap_or(AlignItems::Stretch),
     );
 
     // Extract track counts from previous step (auto-placement can expand the number of tracks)
-    let final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
-    let final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+    let mut final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
+    let mut final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+
+    // A subgrid has no implicit tracks in its subgridded axes
+    if let Some(inherited_columns) = inherited_columns {
+        let track_count = inherited_columns.sizes.len() as u16;
+        items.iter_mut().for_each(|item| item.column = clamp_placement_to_subgrid(item.column, track_count));
+        final_col_counts = TrackCounts::from_raw(0, track_count, 0);
+    }
+    if let Some(inherited_rows) = inherited_rows {
+        let track_count = inherited_rows.sizes.len() as u16;
+        items.iter_mut().for_each(|item| item.row = clamp_placement_to_subgrid(item.row, track_count));
+        final_row_counts = TrackCounts::from_raw(0, track_count, 0);
+    }
 
     // 5. Initialize Tracks
     // Initialize (explicit and implicit) grid tracks (and gutters)
@@ -207,19 +296,25 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     initialize_grid_tracks(
         &mut columns,
         final_col_counts,
-        grid_template_columms.borrow(),
+        column_template,
         grid_auto_columms.borrow(),
-        style.gap().width,
+        if inherited_columns.is_some() { LengthPercentage::ZERO } else { style.gap().width },
         |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
     );
     initialize_grid_tracks(
         &mut rows,
         final_row_counts,
-        grid_template_rows.borrow(),
+        row_template,
         grid_auto_rows.borrow(),
-        style.gap().height,
+        if inherited_rows.is_some() { LengthPercentage::ZERO } else { style.gap().height },
         |row_index| cell_occupancy_matrix.row_is_occupied(row_index),
     );
+    if let Some(inherited_columns) = inherited_columns {
+        inherited_columns.apply_gutters(&mut columns, final_col_counts);
+    }
+    if let Some(inherited_rows) = inherited_rows {
+        inherited_rows.apply_gutters(&mut rows, final_row_counts);
+    }
 
     drop(grid_template_rows);
     drop(grid_template_columms);
@@ -229,6 +324,11 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // 6. Track Sizing
 
+    // Generate items standing in for the children of any subgrids, which contribute to the sizing of this grid's tracks
+    // in place of the subgrids themselves. Until this grid's tracks have been sized, subgrids use their own tracks.
+    generate_subgrid_child_items(tree, &mut items, inner_node_size.width);
+    set_subgrid_tracks(tree, &items, &columns, &rows, inner_node_size.width, Size { width: false, height: false });
+
     // Convert grid placements in origin-zero coordinates to indexes into the GridTrack (rows and columns) vectors
     // This computation is relatively trivial, but it requires the final number of negative (implicit) tracks in
     // each axis, and doing it up-front here means we don't have to keep repeating that calculation
@@ -238,8 +338,11 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_or_intrinsic_tracks(&mut items, &columns, &rows);
 
-    // Determine if the grid has any baseline aligned items
-    let has_baseline_aligned_item = items.iter().any(|item| item.align_self == AlignSelf::Baseline);
+    // Determine if the grid has any baseline aligned items in each axis
+    let has_baseline_aligned_item = Size {
+        width: items.iter().any(|item| item.is_baseline_aligned(AbstractAxis::Inline)),
+        height: items.iter().any(|item| item.is_baseline_aligned(AbstractAxis::Block)),
+    };
 
     // Run track sizing algorithm for Inline axis
     track_sizing_algorithm(
@@ -257,11 +360,14 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         |track: &GridTrack, parent_size: Option<f32>, tree: &Tree| {
             track.max_track_sizing_function.definite_value(parent_size, |val, basis| tree.calc(val, basis))
         },
-        has_baseline_aligned_item,
+        has_baseline_aligned_item.get(AbstractAxis::Inline),
     );
     let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
     inner_node_size.width = inner_node_size.width.or_else(|| initial_column_sum.into());
 
+    // Subgrids inherit the sized columns so that they can be measured when sizing the rows
+    set_subgrid_tracks(tree, &items, &columns, &rows, inner_node_size.width, Size { width: true, height: false });
+
     items.iter_mut().for_each(|item| item.available_space_cache = None);
 
     // Run track sizing algorithm for Block axis
@@ -278,7 +384,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         &mut columns,
         &mut items,
         |track: &GridTrack, _, _| Some(track.base_size),
-        false, // TODO: Support baseline alignment in the vertical axis
+        has_baseline_aligned_item.get(AbstractAxis::Block),
     );
     let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>();
     inner_node_size.height = inner_node_size.height.or_else(|| initial_row_sum.into());
@@ -349,8 +455,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     rerun_column_sizing = parent_width_indefinite && has_percentage_column;
 
     if !rerun_column_sizing {
-        let min_content_contribution_changed =
-            items.iter_mut().filter(|item| item.crosses_intrinsic_column).any(|item| {
+        let min_content_contribution_changed = items
+            .iter_mut()
+            .filter(|item| item.crosses_intrinsic_column && item.contributes_to_track_sizing(AbstractAxis::Inline))
+            .any(|item| {
                 let available_space = item.available_space(
                     AbstractAxis::Inline,
                     &rows,
@@ -395,8 +503,9 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             &mut rows,
             &mut items,
             |track: &GridTrack, _, _| Some(track.base_size),
-            has_baseline_aligned_item,
+            has_baseline_aligned_item.get(AbstractAxis::Inline),
         );
+        set_subgrid_tracks(tree, &items, &columns, &rows, inner_node_size.width, Size { width: true, height: false });
 
         // Row sizing must be re-run (once) if:
         //   - The grid container's height was initially indefinite and there are any rows with percentage track sizing functions
@@ -409,8 +518,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         rerun_row_sizing = parent_height_indefinite && has_percentage_row;
 
         if !rerun_row_sizing {
-            let min_content_contribution_changed =
-                items.iter_mut().filter(|item| item.crosses_intrinsic_column).any(|item| {
+            let min_content_contribution_changed = items
+                .iter_mut()
+                .filter(|item| item.crosses_intrinsic_column && item.contributes_to_track_sizing(AbstractAxis::Block))
+                .any(|item| {
                     let available_space = item.available_space(
                         AbstractAxis::Block,
                         &columns,
@@ -455,18 +566,18 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
                 &mut columns,
                 &mut items,
                 |track: &GridTrack, _, _| Some(track.base_size),
-                false, // TODO: Support baseline alignment in the vertical axis
+                has_baseline_aligned_item.get(AbstractAxis::Block),
             );
         }
     }
 
     // 8. Track Alignment
 
-    // Align columns
+    // Align columns (the scrollbar gutters lie between the border and the padding)
     align_tracks(
         container_content_box.get(AbstractAxis::Inline),
         Line { start: padding.left, end: padding.right },
-        Line { start: border.left, end: border.right },
+        Line { start: border.left + scrollbar_gutter_inset.left, end: border.right + scrollbar_gutter_inset.right },
         &mut columns,
         justify_content,
     );
@@ -479,6 +590,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         align_content,
     );
 
+    // Remove the items standing in for the children of subgrids, and pass the final tracks to the subgrids
+    items.retain(|item| item.subgrid_child_axes.is_none());
+    set_subgrid_tracks(tree, &items, &columns, &rows, inner_node_size.width, Size { width: true, height: true });
+
     // 9. Size, Align, and Position Grid Items
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
@@ -489,13 +604,25 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     let container_alignment_styles = InBothAbsAxis { horizontal: justify_items, vertical: align_items };
 
+    // The left and right edges of the content box as the columns were laid out (from left to right)
+    let content_box_edges =
+        Line { start: content_box_inset.left, end: container_border_box.width - content_box_inset.right };
+
     // Position in-flow children (stored in items vector)
     for (index, item) in items.iter_mut().enumerate() {
+        let column_range = resolve_column_range(
+            Line {
+                start: columns[item.column_indexes.start as usize + 1].offset,
+                end: columns[item.column_indexes.end as usize].offset,
+            },
+            content_box_edges,
+            direction,
+        );
         let grid_area = Rect {
             top: rows[item.row_indexes.start as usize + 1].offset,
             bottom: rows[item.row_indexes.end as usize].offset,
-            left: columns[item.column_indexes.start as usize + 1].offset,
-            right: columns[item.column_indexes.end as usize].offset,
+            left: column_range.start,
+            right: column_range.end,
         };
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
         let (content_size_contribution, y_position, height) = align_and_position_item(
@@ -504,7 +631,13 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             index as u32,
             grid_area,
             container_alignment_styles,
-            item.baseline_shim,
+            Rect {
+                left: item.baseline_shim.x,
+                right: item.last_baseline_shim.x,
+                top: item.baseline_shim.y,
+                bottom: item.last_baseline_shim.y,
+            },
+            direction,
         );
         item.y_position = y_position;
         item.height = height;
@@ -538,46 +671,80 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         }
 
         // Position absolutely positioned child
-        if child_style.position() == Position::Absolute {
+        if child_style.position().is_absolutely_positioned() {
+            // Fixed items are positioned relative to the viewport, so don't contribute to the content size of the grid
+            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+            let contributes_to_content_size = is_containing_block && child_style.position() != Position::Fixed;
             // Convert grid-col-{start/end} into Option's of indexes into the columns vector
             // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_col_indexes = child_style
-                .grid_column()
-                .into_origin_zero(final_col_counts.explicit)
+            let maybe_col_indexes = column_names
+                .resolve(&child_style.grid_column())
                 .resolve_absolutely_positioned_grid_tracks()
                 .map(|maybe_grid_line| {
                     maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_col_counts))
                 });
             // Convert grid-row-{start/end} into Option's of indexes into the row vector
             // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_row_indexes = child_style
-                .grid_row()
-                .into_origin_zero(final_row_counts.explicit)
+            let maybe_row_indexes = row_names
+                .resolve(&child_style.grid_row())
                 .resolve_absolutely_positioned_grid_tracks()
                 .map(|maybe_grid_line| {
                     maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
                 });
 
+            // Grid lines are mirrored in right-to-left containers, whereas auto lines always resolve to the physical
+            // edges of the padding box
+            let column_line =
+                |index: usize| resolve_column_position(columns[index].offset, content_box_edges, direction);
+            let (left_index, right_index) = match direction {
+                Direction::Ltr => (maybe_col_indexes.start, maybe_col_indexes.end),
+                Direction::Rtl => (maybe_col_indexes.end, maybe_col_indexes.start),
+            };
             let grid_area = Rect {
                 top: maybe_row_indexes.start.map(|index| rows[index].offset).unwrap_or(border.top),
                 bottom: maybe_row_indexes
                     .end
                     .map(|index| rows[index].offset)
-                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.y),
-                left: maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(border.left),
-                right: maybe_col_indexes
-                    .end
-                    .map(|index| columns[index].offset)
-                    .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.x),
+                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter_inset.bottom),
+                left: left_index.map(column_line).unwrap_or(border.left + scrollbar_gutter_inset.left),
+                right: right_index
+                    .map(column_line)
+                    .unwrap_or(container_border_box.width - border.right - scrollbar_gutter_inset.right),
+            };
+
+            // Absolutely positioned items don't participate in baseline-sharing groups, but baseline aligned items
+            // are aligned with the shared baseline of the tracks at the (physical) start of their grid area, if any
+            let is_baseline_aligned = |alignment: Option<AlignSelf>| alignment == Some(AlignSelf::Baseline);
+            let left_column_index = match direction {
+                Direction::Ltr => maybe_col_indexes.start.map(|index| index + 1),
+                Direction::Rtl => maybe_col_indexes.end.and_then(|index| index.checked_sub(1)),
+            };
+            let shared_baselines = Point {
+                x: left_column_index
+                    .and_then(|index| columns.get(index))
+                    .and_then(|track| track.baseline)
+                    .filter(|_| is_baseline_aligned(child_style.justify_self().or(justify_items))),
+                y: maybe_row_indexes
+                    .start
+                    .and_then(|index| rows.get(index + 1))
+                    .and_then(|track| track.baseline)
+                    .filter(|_| is_baseline_aligned(child_style.align_self().or(align_items))),
             };
             drop(child_style);
+            let baseline_shim = absolutely_positioned_item_baseline_shim(tree, child, grid_area, shared_baselines);
 
-            // TODO: Baseline alignment support for absolutely positioned items (should check if is actuallty specified)
             #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-            let (content_size_contribution, _, _) =
-                align_and_position_item(tree, child, order, grid_area, container_alignment_styles, 0.0);
+            let (content_size_contribution, _, _) = align_and_position_item(
+                tree,
+                child,
+                order,
+                grid_area,
+                container_alignment_styles,
+                baseline_shim,
+                direction,
+            );
             #[cfg(feature = "content_size")]
-            {
+            if contributes_to_content_size {
                 item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
             }
 
@@ -601,7 +768,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         return LayoutOutput::from_outer_size(container_border_box);
     }
 
-    // Determine the grid container baseline(s) (currently we only compute the first baseline)
+    // Determine the grid container's first and last baselines
     let grid_container_baseline: f32 = {
         // Sort items by row start position so that we can iterate items in groups which are in the same row
         items.sort_by_key(|item| item.row_indexes.start);
@@ -621,13 +788,27 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             &first_row_items[0]
         };
 
-        item.y_position + item.baseline.unwrap_or(item.height)
+        item.y_position + item.baselines.y.unwrap_or(item.height)
+    };
+    let grid_container_last_baseline: f32 = {
+        // Items are still sorted by row start position, so the items which start in the last row are at the end
+        let last_row = items[items.len() - 1].row_indexes.start;
+        let last_row_items = &items[0..].rsplit(|item| item.row_indexes.start != last_row).next().unwrap();
+
+        let item = last_row_items
+            .iter()
+            .rev()
+            .find(|item| item.align_self == AlignSelf::LastBaseline)
+            .unwrap_or(&last_row_items[last_row_items.len() - 1]);
+
+        item.y_position + item.last_baselines.y.unwrap_or(item.height)
     };
 
-    LayoutOutput::from_sizes_and_baselines(
+    LayoutOutput::from_sizes_and_first_and_last_baselines(
         container_border_box,
         item_content_size_contribution,
         Point { x: None, y: Some(grid_container_baseline) },
+        Point { x: None, y: Some(grid_container_last_baseline) },
     )
 }
 
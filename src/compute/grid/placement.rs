@@ -1,5 +1,6 @@
 //! Implements placing items in the grid and resolving the implicit grid.
 //! <https://www.w3.org/TR/css-grid-1/#placement>
+use super::line_names::NamedLineResolver;
 use super::types::{CellOccupancyMatrix, CellOccupancyState, GridItem};
 use super::OriginZeroLine;
 use crate::geometry::Line;
@@ -7,7 +8,26 @@ use crate::geometry::{AbsoluteAxis, InBothAbsAxis};
 use crate::style::{AlignItems, GridAutoFlow, OriginZeroGridPlacement};
 use crate::tree::NodeId;
 use crate::util::sys::Vec;
-use crate::GridItemStyle;
+use crate::{BoxGenerationMode, CoreStyle, GridItemStyle, LayoutGridContainer};
+
+/// The in-flow children of a grid container (along with their index amongst all of the container's children) in
+/// "order-modified document order", which is the order in which they are placed into the grid.
+///
+/// [Specification](https://www.w3.org/TR/css-display-3/#order-modified-document-order)
+pub(super) fn in_flow_children_in_order(tree: &impl LayoutGridContainer, node: NodeId) -> Vec<(usize, NodeId)> {
+    let mut children: Vec<(usize, NodeId)> = tree
+        .child_ids(node)
+        .enumerate()
+        .filter(|&(_, child)| {
+            let style = tree.get_grid_child_style(child);
+            style.box_generation_mode() != BoxGenerationMode::None && !style.position().is_absolutely_positioned()
+        })
+        .collect();
+
+    // The sort is stable, so children with the same order remain in tree order
+    children.sort_by_key(|&(_, child)| tree.get_grid_child_style(child).order());
+    children
+}
 
 /// 8.5. Grid Item Placement Algorithm
 /// Place items into the grid, generating new rows/column into the implicit grid as required
@@ -17,6 +37,7 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
     items: &mut Vec<GridItem>,
     children_iter: impl Fn() -> ChildIter,
+    line_names: InBothAbsAxis<&NamedLineResolver>,
     grid_auto_flow: GridAutoFlow,
     align_items: AlignItems,
     justify_items: AlignItems,
@@ -27,18 +48,12 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
     let primary_axis = grid_auto_flow.primary_axis();
     let secondary_axis = primary_axis.other_axis();
 
-    let map_child_style_to_origin_zero_placement = {
-        let explicit_col_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal).explicit;
-        let explicit_row_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical).explicit;
-        move |(index, node, style): (usize, NodeId, S)| -> (_, _, _, S) {
-            let origin_zero_placement = InBothAbsAxis {
-                horizontal: style
-                    .grid_column()
-                    .map(|placement| placement.into_origin_zero_placement(explicit_col_count)),
-                vertical: style.grid_row().map(|placement| placement.into_origin_zero_placement(explicit_row_count)),
-            };
-            (index, node, origin_zero_placement, style)
-        }
+    let map_child_style_to_origin_zero_placement = |(index, node, style): (usize, NodeId, S)| -> (_, _, _, S) {
+        let origin_zero_placement = InBothAbsAxis {
+            horizontal: line_names.horizontal.resolve(&style.grid_column()),
+            vertical: line_names.vertical.resolve(&style.grid_row()),
+        };
+        (index, node, origin_zero_placement, style)
     };
 
     // 1. Place children with definite positions
@@ -353,9 +368,11 @@ mod tests {
 
     mod test_placement_algorithm {
         use crate::compute::grid::implicit_grid::compute_grid_size_estimate;
+        use crate::compute::grid::line_names::NamedLineResolver;
         use crate::compute::grid::types::TrackCounts;
         use crate::compute::grid::util::*;
         use crate::compute::grid::CellOccupancyMatrix;
+        use crate::geometry::InBothAbsAxis;
         use crate::prelude::*;
         use crate::style::GridAutoFlow;
 
@@ -374,7 +391,9 @@ mod tests {
             // Setup test
             let children_iter = || children.iter().map(|(index, style, _)| (*index, NodeId::from(*index), style));
             let child_styles_iter = children.iter().map(|(_, style, _)| style);
-            let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+            let column_names = NamedLineResolver::new(&[], &[], explicit_col_count);
+            let row_names = NamedLineResolver::new(&[], &[], explicit_row_count);
+            let estimated_sizes = compute_grid_size_estimate(&column_names, &row_names, child_styles_iter);
             let mut items = Vec::new();
             let mut cell_occupancy_matrix =
                 CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
@@ -384,6 +403,7 @@ mod tests {
                 &mut cell_occupancy_matrix,
                 &mut items,
                 children_iter,
+                InBothAbsAxis { horizontal: &column_names, vertical: &row_names },
                 flow,
                 AlignSelf::Start,
                 AlignSelf::Start,
@@ -0,0 +1,245 @@
+//! Support for subgrids: grid items whose own grid adopts the tracks of the parent grid in one or both axes.
+//! <https://www.w3.org/TR/css-grid-2/#subgrids>
+use super::explicit_grid::compute_explicit_grid_size_in_axis;
+use super::implicit_grid::compute_grid_size_estimate;
+use super::line_names::NamedLineResolver;
+use super::placement::{in_flow_children_in_order, place_grid_items};
+use super::types::{CellOccupancyMatrix, GridItem, GridTrack, TrackCounts};
+use super::OriginZeroLine;
+use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis, Line, Rect, Size};
+use crate::style::{AlignItems, LengthPercentage, TrackSizingFunction};
+use crate::style_helpers::length;
+use crate::tree::{LayoutPartialTreeExt, NodeId};
+use crate::util::sys::{f32_max, Vec};
+use crate::util::ResolveOrZero;
+use crate::{GridContainerStyle, LayoutGridContainer};
+use core::borrow::Borrow;
+use core::cmp::{max, min};
+
+/// The tracks that a subgrid inherits from its parent grid in a single axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubgridAxisTracks {
+    /// The sizes of the inherited tracks
+    ///
+    /// The outermost tracks are reduced by the subgrid's own margin, border and padding so that the lines
+    /// of the subgrid coincide with the lines of the parent grid.
+    pub sizes: Vec<f32>,
+    /// The sizes of the gutters between the inherited tracks
+    pub gutters: Vec<f32>,
+}
+
+impl SubgridAxisTracks {
+    /// Create from the parent grid's tracks spanned by the subgrid (excluding the lines that bound it), where
+    /// `edges` is the sum of the subgrid's margin, border and padding at each end of the axis.
+    pub(super) fn from_parent_tracks(tracks: &[GridTrack], edges: Line<f32>) -> Self {
+        let mut sizes: Vec<f32> = tracks.iter().step_by(2).map(|track| track.base_size).collect();
+        // Once the tracks have been aligned, the distance between adjacent tracks also includes any space
+        // distributed by content alignment. Before that, the track offsets are all zero.
+        let gutters = tracks
+            .iter()
+            .enumerate()
+            .skip(1)
+            .step_by(2)
+            .map(|(index, gutter)| {
+                let (previous, next) = (&tracks[index - 1], &tracks[index + 1]);
+                f32_max(gutter.base_size, next.offset - previous.offset - previous.base_size)
+            })
+            .collect();
+
+        if let Some(first) = sizes.first_mut() {
+            *first = f32_max(0.0, *first - edges.start);
+        }
+        if let Some(last) = sizes.last_mut() {
+            *last = f32_max(0.0, *last - edges.end);
+        }
+
+        SubgridAxisTracks { sizes, gutters }
+    }
+
+    /// Fixed track sizing functions equivalent to the inherited tracks
+    pub(super) fn track_template(&self) -> Vec<TrackSizingFunction> {
+        self.sizes.iter().map(|&size| length(size)).collect()
+    }
+
+    /// Replace the gutters between the (explicit) tracks with the inherited gutters
+    pub(super) fn apply_gutters(&self, tracks: &mut [GridTrack], counts: TrackCounts) {
+        let first_gutter_index = 2 * counts.negative_implicit as usize + 2;
+        for (gutter, &size) in tracks[first_gutter_index..].iter_mut().step_by(2).zip(self.gutters.iter()) {
+            *gutter = GridTrack::gutter(LengthPercentage::length(size));
+        }
+    }
+}
+
+/// The tracks that a subgrid inherits from its parent grid
+///
+/// Each axis is `None` unless the node is a subgrid in that axis and has been placed into a parent grid.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SubgridTracks {
+    /// The inherited columns
+    pub columns: Option<SubgridAxisTracks>,
+    /// The inherited rows
+    pub rows: Option<SubgridAxisTracks>,
+}
+
+impl SubgridTracks {
+    /// No inherited tracks in either axis
+    pub const NONE: Self = Self { columns: None, rows: None };
+}
+
+/// Clamp the placement of a subgrid's child to the subgrid's explicit tracks. In a subgridded axis, the subgrid
+/// has no implicit tracks so children that would be placed outside of its explicit grid are placed at its edges.
+pub(super) fn clamp_placement_to_subgrid(placement: Line<OriginZeroLine>, track_count: u16) -> Line<OriginZeroLine> {
+    let track_count = max(track_count as i16, 1);
+    let start = min(max(placement.start.0, 0), track_count - 1);
+    let end = min(max(placement.end.0, start + 1), track_count);
+    Line { start: OriginZeroLine(start), end: OriginZeroLine(end) }
+}
+
+/// Pass the tracks that each subgrid in `items` inherits from the grid's `columns` and `rows` to the subgrid.
+/// `axes` limits the axes in which tracks are inherited, as they may be passed before the tracks in both axes have been sized.
+pub(super) fn set_subgrid_tracks(
+    tree: &mut impl LayoutGridContainer,
+    items: &[GridItem],
+    columns: &[GridTrack],
+    rows: &[GridTrack],
+    inner_node_width: Option<f32>,
+    axes: Size<bool>,
+) {
+    let subgrids = items
+        .iter()
+        .filter(|item| item.subgrid_child_axes.is_none() && (item.is_subgrid.width || item.is_subgrid.height));
+    for item in subgrids {
+        let edges = subgrid_edges(tree, item, inner_node_width);
+        let inherit_tracks = |axis: AbstractAxis, tracks: &[GridTrack]| {
+            (item.is_subgrid.get(axis) && axes.get(axis)).then(|| {
+                let edges = match axis {
+                    AbstractAxis::Inline => Line { start: edges.left, end: edges.right },
+                    AbstractAxis::Block => Line { start: edges.top, end: edges.bottom },
+                };
+                SubgridAxisTracks::from_parent_tracks(&tracks[item.track_range_excluding_lines(axis)], edges)
+            })
+        };
+        let tracks = SubgridTracks {
+            columns: inherit_tracks(AbstractAxis::Inline, columns),
+            rows: inherit_tracks(AbstractAxis::Block, rows),
+        };
+        tree.set_subgrid_tracks(item.node, tracks);
+    }
+}
+
+/// The sum of a subgrid's margin, border and padding on each edge
+fn subgrid_edges(tree: &impl LayoutGridContainer, item: &GridItem, inner_node_width: Option<f32>) -> Rect<f32> {
+    let margin = item.margin.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis));
+    let padding = item.padding.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis));
+    let border = item.border.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis));
+    margin + padding + border
+}
+
+/// Generate grid items that stand in for the children of each subgrid in `items` during track sizing.
+///
+/// The children of a subgrid are placed into the subgrid's own grid, and their placement is then translated into the
+/// grid's coordinates using the lines that the subgrid spans. They contribute to the sizing of the grid's tracks in
+/// the subgridded axes, with the subgrid's own margin, border and padding added to the contributions of the children
+/// that are adjacent to its edges.
+pub(super) fn generate_subgrid_child_items<Tree: LayoutGridContainer>(
+    tree: &Tree,
+    items: &mut Vec<GridItem>,
+    inner_node_width: Option<f32>,
+) {
+    let mut source_order = items.len() as u16;
+    for subgrid_index in 0..items.len() {
+        let subgrid = &items[subgrid_index];
+        if !subgrid.is_subgrid.width && !subgrid.is_subgrid.height {
+            continue;
+        }
+
+        let child_items = place_subgrid_children(tree, subgrid.node, subgrid.is_subgrid, subgrid.column, subgrid.row);
+        let edges = subgrid_edges(tree, subgrid, inner_node_width);
+        let (subgrid_column, subgrid_row, is_subgrid) = (subgrid.column, subgrid.row, subgrid.is_subgrid);
+
+        for mut child in child_items {
+            child.source_order = source_order;
+            child.subgrid_child_axes = Some(is_subgrid);
+            if is_subgrid.width {
+                child.column = clamp_placement_to_subgrid(child.column, subgrid_column.span())
+                    .map(|line| subgrid_column.start + line);
+                if child.column.start == subgrid_column.start {
+                    child.subgrid_edge_offsets.left += edges.left;
+                }
+                if child.column.end == subgrid_column.end {
+                    child.subgrid_edge_offsets.right += edges.right;
+                }
+            } else {
+                child.column = subgrid_column;
+            }
+            if is_subgrid.height {
+                child.row =
+                    clamp_placement_to_subgrid(child.row, subgrid_row.span()).map(|line| subgrid_row.start + line);
+                if child.row.start == subgrid_row.start {
+                    child.subgrid_edge_offsets.top += edges.top;
+                }
+                if child.row.end == subgrid_row.end {
+                    child.subgrid_edge_offsets.bottom += edges.bottom;
+                }
+            } else {
+                child.row = subgrid_row;
+            }
+            items.push(child);
+            source_order += 1;
+        }
+    }
+}
+
+/// Run the placement algorithm for the children of a subgrid, as they would be placed within the subgrid's own grid
+fn place_subgrid_children<Tree: LayoutGridContainer>(
+    tree: &Tree,
+    node: NodeId,
+    is_subgrid: Size<bool>,
+    column: Line<OriginZeroLine>,
+    row: Line<OriginZeroLine>,
+) -> Vec<GridItem> {
+    let style = tree.get_grid_container_style(node);
+    let line_names = |axis: AbsoluteAxis, span: u16, is_subgrid: bool| {
+        // In a subgridded axis the explicit tracks are those spanned in the parent grid, and the subgrid's line
+        // names apply directly to the lines between them
+        if is_subgrid {
+            NamedLineResolver::from_line_names(style.grid_template_line_names(axis), span)
+        } else {
+            let template = style.grid_template_tracks(axis);
+            let explicit_track_count = compute_explicit_grid_size_in_axis(
+                &style,
+                template.borrow(),
+                Size::NONE,
+                |val, basis| tree.calc(val, basis),
+                axis,
+            );
+            let mut resolver =
+                NamedLineResolver::new(template.borrow(), style.grid_template_line_names(axis), explicit_track_count);
+            resolver.add_areas(style.grid_template_areas(), axis);
+            resolver
+        }
+    };
+    let column_names = line_names(AbsoluteAxis::Horizontal, column.span(), is_subgrid.width);
+    let row_names = line_names(AbsoluteAxis::Vertical, row.span(), is_subgrid.height);
+
+    let child_styles_iter = tree.child_ids(node).map(|child_node: NodeId| tree.get_grid_child_style(child_node));
+    let (est_col_counts, est_row_counts) = compute_grid_size_estimate(&column_names, &row_names, child_styles_iter);
+
+    let mut items = Vec::with_capacity(tree.child_count(node));
+    let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
+    let in_flow_children = in_flow_children_in_order(tree, node);
+    let in_flow_children_iter = || {
+        in_flow_children.iter().map(|&(index, child_node)| (index, child_node, tree.get_grid_child_style(child_node)))
+    };
+    place_grid_items(
+        &mut cell_occupancy_matrix,
+        &mut items,
+        in_flow_children_iter,
+        InBothAbsAxis { horizontal: &column_names, vertical: &row_names },
+        style.grid_auto_flow(),
+        style.align_items().unwrap_or(AlignItems::Stretch),
+        style.justify_items().unwrap_or(AlignItems::Stretch),
+    );
+
+    items
+}
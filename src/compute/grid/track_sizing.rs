@@ -3,7 +3,6 @@
 use super::types::{GridItem, GridTrack, TrackCounts};
 use crate::geometry::{AbstractAxis, Line, Size};
 use crate::style::{AlignContent, AlignSelf, AvailableSpace};
-use crate::style_helpers::TaffyMinContent;
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, SizingMode};
 use crate::util::sys::{f32_max, f32_min, Vec};
 use crate::util::{MaybeMath, ResolveOrZero};
@@ -191,11 +190,11 @@ pub(super) fn compute_alignment_gutter_adjustment(
     // As items never cross the outermost gutters in a grid, we can simplify our calculations by treating
     // AlignContent::Start and AlignContent::End the same
     let outer_gutter_weight = match alignment {
-        AlignContent::Start => 1,
-        AlignContent::FlexStart => 1,
-        AlignContent::End => 1,
-        AlignContent::FlexEnd => 1,
-        AlignContent::Center => 1,
+        AlignContent::Start | AlignContent::SafeStart => 1,
+        AlignContent::FlexStart | AlignContent::SafeFlexStart => 1,
+        AlignContent::End | AlignContent::SafeEnd => 1,
+        AlignContent::FlexEnd | AlignContent::SafeFlexEnd => 1,
+        AlignContent::Center | AlignContent::SafeCenter => 1,
         AlignContent::Stretch => 0,
         AlignContent::SpaceBetween => 0,
         AlignContent::SpaceAround => 1,
@@ -203,11 +202,11 @@ pub(super) fn compute_alignment_gutter_adjustment(
     };
 
     let inner_gutter_weight = match alignment {
-        AlignContent::FlexStart => 0,
-        AlignContent::Start => 0,
-        AlignContent::FlexEnd => 0,
-        AlignContent::End => 0,
-        AlignContent::Center => 0,
+        AlignContent::FlexStart | AlignContent::SafeFlexStart => 0,
+        AlignContent::Start | AlignContent::SafeStart => 0,
+        AlignContent::FlexEnd | AlignContent::SafeFlexEnd => 0,
+        AlignContent::End | AlignContent::SafeEnd => 0,
+        AlignContent::Center | AlignContent::SafeCenter => 0,
         AlignContent::Stretch => 0,
         AlignContent::SpaceBetween => 1,
         AlignContent::SpaceAround => 2,
@@ -247,7 +246,7 @@ pub(super) fn resolve_item_track_indexes(items: &mut [GridItem], column_counts:
 /// Determine (in each axis) whether the item crosses any flexible tracks
 #[inline(always)]
 pub(super) fn determine_if_item_crosses_flexible_or_intrinsic_tracks(
-    items: &mut Vec<GridItem>,
+    items: &mut [GridItem],
     columns: &[GridTrack],
     rows: &[GridTrack],
 ) {
@@ -281,13 +280,31 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     get_track_size_estimate: fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
     has_baseline_aligned_item: bool,
 ) {
+    // Subgrids don't contribute to the sizing of tracks in their subgridded axes (their children contribute instead),
+    // so move any items that don't contribute in this axis to the end of the list and exclude them
+    let items = if items.iter().all(|item| item.contributes_to_track_sizing(axis)) {
+        items
+    } else {
+        items.sort_by_key(|item| !item.contributes_to_track_sizing(axis));
+        let contributing_item_count = items.iter().take_while(|item| item.contributes_to_track_sizing(axis)).count();
+        &mut items[..contributing_item_count]
+    };
+
     // 11.4 Initialise Track sizes
     // Initialize each track’s base size and growth limit.
     initialize_track_sizes(tree, axis_tracks, inner_node_size.get(axis));
 
     // 11.5.1 Shim item baselines
     if has_baseline_aligned_item {
-        resolve_item_baselines(tree, axis, items, inner_node_size);
+        resolve_item_baselines(
+            tree,
+            axis,
+            axis_tracks,
+            other_axis_tracks,
+            items,
+            inner_node_size,
+            get_track_size_estimate,
+        );
     }
 
     // If all tracks have base_size = growth_limit, then skip the rest of this function.
@@ -443,76 +460,116 @@ fn initialize_track_sizes(
 }
 
 /// 11.5.1 Shim baseline-aligned items so their intrinsic size contributions reflect their baseline alignment.
-fn resolve_item_baselines(
-    tree: &mut impl LayoutPartialTree,
+///
+/// The items that start in the same track (of the axis being sized) and whose self-alignment in that axis is
+/// `baseline` form a baseline-sharing group. Each item in a group is shimmed so that its baseline lines up with the
+/// largest baseline in the group, which is also recorded as the shared baseline of the track.
+///
+/// Likewise, the items whose self-alignment is `last baseline` form a separate last-baseline-sharing group. These items
+/// are shimmed at their end edge so that their last baselines line up when they are aligned to the end of the track.
+fn resolve_item_baselines<Tree: LayoutPartialTree>(
+    tree: &mut Tree,
     axis: AbstractAxis,
+    axis_tracks: &mut [GridTrack],
+    other_axis_tracks: &[GridTrack],
     items: &mut [GridItem],
     inner_node_size: Size<Option<f32>>,
+    get_track_size_estimate: fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
 ) {
-    // Sort items by track in the other axis (row) start position so that we can iterate items in groups which
-    // are in the same track in the other axis (row)
-    let other_axis = axis.other();
-    items.sort_by_key(|item| item.placement(other_axis).start);
+    axis_tracks.iter_mut().for_each(|track| track.baseline = None);
 
-    // Iterate over grid rows
+    // Sort items by their start track in the axis being sized so that we can iterate items in groups which
+    // start in the same track
+    items.sort_by_key(|item| item.placement(axis).start);
+
+    // Iterate over the tracks in the axis
     let mut remaining_items = &mut items[0..];
     while !remaining_items.is_empty() {
-        // Get the row index of the current row
-        let current_row = remaining_items[0].placement(other_axis).start;
+        // Get the start line of the current track
+        let current_track = remaining_items[0].placement(axis).start;
 
-        // Find the item index of the first item that is in a different row (or None if we've reached the end of the list)
-        let next_row_first_item =
-            remaining_items.iter().position(|item| item.placement(other_axis).start != current_row);
+        // Find the item index of the first item that is in a different track (or None if we've reached the end of the list)
+        let next_track_first_item = remaining_items.iter().position(|item| item.placement(axis).start != current_track);
 
         // Use this index to split the `remaining_items` slice in two slices:
-        //    - A `row_items` slice containing the items (that start) in the current row
+        //    - A `track_items` slice containing the items (that start) in the current track
         //    - A new `remaining_items` consisting of the remainder of the `remaining_items` slice
-        //      that hasn't been split off into `row_items
-        let row_items = if let Some(index) = next_row_first_item {
-            let (row_items, tail) = remaining_items.split_at_mut(index);
+        //      that hasn't been split off into `track_items`
+        let track_items = if let Some(index) = next_track_first_item {
+            let (track_items, tail) = remaining_items.split_at_mut(index);
             remaining_items = tail;
-            row_items
+            track_items
         } else {
-            let row_items = remaining_items;
+            let track_items = remaining_items;
             remaining_items = &mut [];
-            row_items
+            track_items
         };
 
-        // Count how many items in *this row* are baseline aligned
-        // If a row has one or zero items participating in baseline alignment then baseline alignment is a no-op
-        // for those items and we skip further computations for that row
-        let row_baseline_item_count = row_items.iter().filter(|item| item.align_self == AlignSelf::Baseline).count();
-        if row_baseline_item_count <= 1 {
+        // Only items that are baseline aligned in this axis participate in the track's baseline-sharing groups
+        track_items.iter_mut().for_each(|item| {
+            item.baseline_shim.set(axis, 0.0);
+            item.last_baseline_shim.set(axis, 0.0);
+        });
+        if !track_items.iter().any(|item| item.is_baseline_aligned(axis)) {
             continue;
         }
 
-        // Compute the baselines of all items in the row
-        for item in row_items.iter_mut() {
+        // Compute the baselines of all items in the groups. For items in the last-baseline-sharing group we also
+        // record the distance between the item's last baseline and its end margin edge.
+        let mut last_baseline_descents = Vec::new();
+        for item in track_items.iter_mut().filter(|item| item.is_baseline_aligned(axis)) {
+            let available_space = item
+                .available_space(axis, other_axis_tracks, inner_node_size.get(axis.other()), |track, basis| {
+                    get_track_size_estimate(track, basis, tree)
+                })
+                .map(|size| size.map(AvailableSpace::Definite).unwrap_or(AvailableSpace::MinContent));
             let measured_size_and_baselines = tree.perform_child_layout(
                 item.node,
                 Size::NONE,
                 inner_node_size,
-                Size::MIN_CONTENT,
+                available_space,
                 SizingMode::InherentSize,
                 Line::FALSE,
             );
 
-            let baseline = measured_size_and_baselines.first_baselines.y;
-            let height = measured_size_and_baselines.size.height;
+            let size = measured_size_and_baselines.size.get(axis);
+            let margin = match axis {
+                AbstractAxis::Inline => item.margin.horizontal_components(),
+                AbstractAxis::Block => item.margin.vertical_components(),
+            }
+            .map(|margin| margin.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(val, basis)));
 
-            item.baseline = Some(
-                baseline.unwrap_or(height)
-                    + item.margin.top.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(val, basis)),
-            );
+            if item.self_alignment(axis) == AlignSelf::Baseline {
+                let baseline = measured_size_and_baselines.first_baselines.get(axis);
+                item.baselines.set(axis, Some(baseline.unwrap_or(size) + margin.start));
+            } else {
+                let last_baseline = measured_size_and_baselines.last_baselines.get(axis).unwrap_or(size) + margin.start;
+                item.last_baselines.set(axis, Some(last_baseline));
+                last_baseline_descents.push(margin.start + size + margin.end - last_baseline);
+            }
         }
 
-        // Compute the max baseline of all items in the row
-        let row_max_baseline =
-            row_items.iter().map(|item| item.baseline.unwrap_or(0.0)).max_by(|a, b| a.total_cmp(b)).unwrap();
+        // Compute the max baseline of all items in the first-baseline-sharing group
+        let group_max_baseline = track_items
+            .iter()
+            .filter(|item| item.self_alignment(axis) == AlignSelf::Baseline)
+            .map(|item| item.baselines.get(axis).unwrap_or(0.0))
+            .max_by(|a, b| a.total_cmp(b));
+        if let Some(group_max_baseline) = group_max_baseline {
+            axis_tracks[track_items[0].placement_indexes(axis).start as usize + 1].baseline = Some(group_max_baseline);
+
+            // Compute the baseline shim for each item in the group
+            for item in track_items.iter_mut().filter(|item| item.self_alignment(axis) == AlignSelf::Baseline) {
+                item.baseline_shim.set(axis, group_max_baseline - item.baselines.get(axis).unwrap_or(0.0));
+            }
+        }
 
-        // Compute the baseline shim for each item in the row
-        for item in row_items.iter_mut() {
-            item.baseline_shim = row_max_baseline - item.baseline.unwrap_or(0.0);
+        // Compute the last baseline shim for each item in the last-baseline-sharing group
+        let group_max_descent = last_baseline_descents.iter().copied().fold(0.0, f32::max);
+        let last_baseline_items =
+            track_items.iter_mut().filter(|item| item.self_alignment(axis) == AlignSelf::LastBaseline);
+        for (item, descent) in last_baseline_items.zip(last_baseline_descents) {
+            item.last_baseline_shim.set(axis, group_max_descent - descent);
         }
     }
 }
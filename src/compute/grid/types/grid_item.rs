@@ -1,7 +1,8 @@
 //! Contains GridItem used to represent a single grid item during layout
 use super::GridTrack;
+use crate::compute::common::sizing_keywords::{resolve_child_intrinsic_sizing_keywords, resolve_stretch_sizes};
 use crate::compute::grid::OriginZeroLine;
-use crate::geometry::AbstractAxis;
+use crate::geometry::{AbsoluteAxis, AbstractAxis};
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{AlignItems, AlignSelf, AvailableSpace, Dimension, LengthPercentageAuto, Overflow};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
@@ -53,11 +54,25 @@ pub(in super::super) struct GridItem {
     pub align_self: AlignSelf,
     /// The item's justify_self property, or the parent's justify_items property is not set
     pub justify_self: AlignSelf,
-    /// The items first baseline (horizontal)
-    pub baseline: Option<f32>,
-    /// Shim for baseline alignment that acts like an extra top margin
-    /// TODO: Support last baseline and vertical text baselines
-    pub baseline_shim: f32,
+    /// The item's first baselines (including the item's start margin). Only computed in an axis in which the
+    /// item participates in baseline alignment.
+    pub baselines: Point<Option<f32>>,
+    /// The item's last baselines (including the item's start margin). Only computed in an axis in which the
+    /// item participates in last baseline alignment.
+    pub last_baselines: Point<Option<f32>>,
+    /// Shim for baseline alignment that acts like an extra left (x) and top (y) margin
+    pub baseline_shim: Point<f32>,
+    /// Shim for last baseline alignment that acts like an extra right (x) and bottom (y) margin
+    pub last_baseline_shim: Point<f32>,
+
+    /// Whether the item is itself a grid whose columns (`width`) or rows (`height`) are a subgrid of this grid.
+    /// A subgrid doesn't contribute to the sizing of the tracks in a subgridded axis: its children do so instead.
+    pub is_subgrid: Size<bool>,
+    /// If the item stands in for a child of a subgrid then the axes in which it contributes to track sizing
+    /// (the axes in which the subgrid is subgridded). Such items are not laid out by the grid.
+    pub subgrid_child_axes: Option<Size<bool>>,
+    /// The margin, border and padding of the subgrid(s) whose edges the item is adjacent to. Acts like an extra margin.
+    pub subgrid_edge_offsets: Rect<f32>,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
     /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
@@ -113,14 +128,24 @@ impl GridItem {
             size: style.size(),
             min_size: style.min_size(),
             max_size: style.max_size(),
-            aspect_ratio: style.aspect_ratio(),
+            // An `auto <ratio>` aspect ratio may be overridden by the natural aspect ratio of the item's content, so
+            // it is left to the item's own layout to apply it
+            aspect_ratio: style.aspect_ratio().filter(|_| !style.aspect_ratio_auto()),
             padding: style.padding(),
             border: style.border(),
             margin: style.margin(),
             align_self: style.align_self().unwrap_or(parent_align_items),
             justify_self: style.justify_self().unwrap_or(parent_justify_items),
-            baseline: None,
-            baseline_shim: 0.0,
+            baselines: Point::NONE,
+            last_baselines: Point::NONE,
+            baseline_shim: Point::ZERO,
+            last_baseline_shim: Point::ZERO,
+            is_subgrid: Size {
+                width: style.is_subgrid(AbsoluteAxis::Horizontal),
+                height: style.is_subgrid(AbsoluteAxis::Vertical),
+            },
+            subgrid_child_axes: None,
+            subgrid_edge_offsets: Rect::ZERO,
             row_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             column_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             crosses_flexible_row: false,            // Properly initialised later
@@ -144,6 +169,20 @@ impl GridItem {
         }
     }
 
+    /// The item's self-alignment in the specified axis (`justify_self` in the inline axis, and `align_self` in
+    /// the block axis)
+    pub fn self_alignment(&self, axis: AbstractAxis) -> AlignSelf {
+        match axis {
+            AbstractAxis::Block => self.align_self,
+            AbstractAxis::Inline => self.justify_self,
+        }
+    }
+
+    /// Whether the item participates in first or last baseline alignment in the specified axis
+    pub fn is_baseline_aligned(&self, axis: AbstractAxis) -> bool {
+        matches!(self.self_alignment(axis), AlignSelf::Baseline | AlignSelf::LastBaseline)
+    }
+
     /// This item's placement in the specified axis as GridTrackVec indices
     pub fn placement_indexes(&self, axis: AbstractAxis) -> Line<u16> {
         match axis {
@@ -168,6 +207,14 @@ impl GridItem {
         }
     }
 
+    /// Whether the item contributes to the sizing of the tracks in the specified axis
+    pub fn contributes_to_track_sizing(&self, axis: AbstractAxis) -> bool {
+        match self.subgrid_child_axes {
+            Some(axes) => axes.get(axis),
+            None => !self.is_subgrid.get(axis),
+        }
+    }
+
     /// Returns the pre-computed value indicating whether the grid item crosses a flexible track in
     /// the specified axis
     pub fn crosses_flexible_track(&self, axis: AbstractAxis) -> bool {
@@ -273,6 +320,40 @@ impl GridItem {
 
         let grid_area_minus_item_margins_size = grid_area_size.maybe_sub(margins);
 
+        // Resolve any stretch sizes against the grid area minus the item's margins
+        let inherent_size = resolve_stretch_sizes(self.size, inherent_size, grid_area_minus_item_margins_size);
+        let min_size = resolve_stretch_sizes(self.min_size, min_size, grid_area_minus_item_margins_size);
+        let max_size = resolve_stretch_sizes(self.max_size, max_size, grid_area_minus_item_margins_size);
+
+        // Resolve any min-content, max-content or fit-content sizes by measuring the item's content
+        let inherent_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            self.node,
+            self.size,
+            inherent_size,
+            None,
+            grid_area_size,
+            box_sizing_adjustment,
+        );
+        let min_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            self.node,
+            self.min_size,
+            min_size,
+            inherent_size.width,
+            grid_area_size,
+            box_sizing_adjustment,
+        );
+        let max_size = resolve_child_intrinsic_sizing_keywords(
+            tree,
+            self.node,
+            self.max_size,
+            max_size,
+            inherent_size.width,
+            grid_area_size,
+            box_sizing_adjustment,
+        );
+
         // If node is absolutely positioned and width is not set explicitly, then deduce it
         // from left, right and container_content_box if both are set.
         let width = inherent_size.width.or_else(|| {
@@ -360,14 +441,17 @@ impl GridItem {
         inner_node_width: Option<f32>,
         tree: &impl LayoutPartialTree,
     ) -> Size<f32> {
-        Rect {
-            left: self.margin.left.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
-            right: self.margin.right.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
+        (Rect {
+            left: self.margin.left.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis))
+                + self.baseline_shim.x,
+            right: self.margin.right.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis))
+                + self.last_baseline_shim.x,
             top: self.margin.top.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis))
-                + self.baseline_shim,
-            bottom: self.margin.bottom.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis)),
-        }
-        .sum_axes()
+                + self.baseline_shim.y,
+            bottom: self.margin.bottom.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis))
+                + self.last_baseline_shim.y,
+        } + self.subgrid_edge_offsets)
+            .sum_axes()
     }
 
     /// Compute the item's min content contribution from the provided parameters
@@ -40,6 +40,10 @@ pub(in super::super) struct GridTrack {
     /// The size (width/height as applicable) of the track
     pub base_size: f32,
 
+    /// The shared baseline of the items that start in this track and participate in baseline alignment (measured
+    /// from the start of the track), if there are any such items
+    pub baseline: Option<f32>,
+
     /// A temporary scratch value when sizing tracks
     /// Note: can be infinity
     pub growth_limit: f32,
@@ -73,6 +77,7 @@ impl GridTrack {
             max_track_sizing_function,
             offset: 0.0,
             base_size: 0.0,
+            baseline: None,
             growth_limit: 0.0,
             content_alignment_adjustment: 0.0,
             item_incurred_increase: 0.0,
@@ -1,25 +1,50 @@
 //! Computes size using styles and measure functions
 
+use crate::compute::common::scrollbars::{
+    overflowing_auto_axes, resolve_scrollbar_gutter_inset, resolve_scrollbar_size,
+};
 use crate::geometry::{Point, Size};
-use crate::style::{AvailableSpace, Overflow, Position};
+use crate::style::{AvailableSpace, CompactLength, Dimension, Direction};
 use crate::tree::{CollapsibleMarginSet, RunMode};
 use crate::tree::{LayoutInput, LayoutOutput, SizingMode};
 use crate::util::debug::debug_log;
-use crate::util::sys::f32_max;
+use crate::util::sys::{f32_max, f32_min};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxSizing, CoreStyle};
 use core::unreachable;
 
+/// The result of a leaf node's measure function
+///
+/// Measure functions passed to [`compute_leaf_layout`] may return either a plain `Size<f32>` or a `LeafMeasureOutput`
+/// if they need to report a natural aspect ratio.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LeafMeasureOutput {
+    /// The size of the node's content box
+    pub size: Size<f32>,
+    /// The natural aspect ratio (width divided by height) of the node's content, if it has one (e.g. an image or video)
+    ///
+    /// This is used if the node's `aspect_ratio` style is unset or is combined with `auto`, and applies to the
+    /// node's content box.
+    pub natural_aspect_ratio: Option<f32>,
+}
+
+impl From<Size<f32>> for LeafMeasureOutput {
+    fn from(size: Size<f32>) -> Self {
+        Self { size, natural_aspect_ratio: None }
+    }
+}
+
 /// Compute the size of a leaf node (node with no children)
-pub fn compute_leaf_layout<MeasureFunction>(
+pub fn compute_leaf_layout<MeasureFunction, MeasureOutput>(
     inputs: LayoutInput,
     style: &impl CoreStyle,
     resolve_calc_value: impl Fn(*const (), f32) -> f32,
     measure_function: MeasureFunction,
 ) -> LayoutOutput
 where
-    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
+    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     let LayoutInput { known_dimensions, parent_size, available_space, sizing_mode, run_mode, .. } = inputs;
 
@@ -42,7 +67,9 @@ where
             (node_size, node_min_size, node_max_size, None)
         }
         SizingMode::InherentSize => {
-            let aspect_ratio = style.aspect_ratio();
+            // With `aspect-ratio: auto <ratio>` the ratio is only applied once we know whether the measure function
+            // reports a natural aspect ratio that takes precedence over it
+            let aspect_ratio = if style.aspect_ratio_auto() { None } else { style.aspect_ratio() };
             let style_size = style
                 .size()
                 .maybe_resolve(parent_size, &resolve_calc_value)
@@ -61,22 +88,22 @@ where
         }
     };
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`, or as specified by
+    // `scrollbar_gutter`. As a leaf's content is only measured once, `Overflow::Auto` scrollbars are added after
+    // measuring without reserving space for them.
+    let overflow = style.overflow();
+    let scrollbar_width = style.scrollbar_width();
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let scrollbar_size =
+        resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, Point { x: false, y: false });
+    // Leaf nodes have no children, so the side on which the gutter is placed does not affect their layout
+    let content_box_inset =
+        padding_border + resolve_scrollbar_gutter_inset(scrollbar_size, scrollbar_gutter, Direction::Ltr);
 
     let has_styles_preventing_being_collapsed_through = !style.is_block()
         || style.overflow().x.is_scroll_container()
         || style.overflow().y.is_scroll_container()
-        || style.position() == Position::Absolute
+        || style.position().is_absolutely_positioned()
         || padding.top > 0.0
         || padding.bottom > 0.0
         || border.top > 0.0
@@ -99,7 +126,9 @@ where
                 size,
                 #[cfg(feature = "content_size")]
                 content_size: Size::ZERO,
+                scrollbar_size,
                 first_baselines: Point::NONE,
+                last_baselines: Point::NONE,
                 top_margin: CollapsibleMarginSet::ZERO,
                 bottom_margin: CollapsibleMarginSet::ZERO,
                 margins_can_collapse_through: false,
@@ -107,6 +136,13 @@ where
         };
     }
 
+    // Intrinsic sizing keywords (min-content, max-content and fit-content) are resolved by constraining the space that
+    // is made available to the measure function. They are ignored in ContentSize mode, and in axes with a known size.
+    let (size_style, min_size_style, max_size_style) = match sizing_mode {
+        SizingMode::ContentSize => (Size::auto(), Size::auto(), Size::auto()),
+        SizingMode::InherentSize => (style.size(), style.min_size(), style.max_size()),
+    };
+
     // Compute available space
     let available_space = Size {
         width: known_dimensions
@@ -114,6 +150,13 @@ where
             .map(AvailableSpace::from)
             .unwrap_or(available_space.width)
             .maybe_sub(margin.horizontal_axis_sum())
+            .apply_intrinsic_sizing_keywords(
+                size_style.width,
+                min_size_style.width,
+                max_size_style.width,
+                parent_size.width,
+                box_sizing_adjustment.width,
+            )
             .maybe_set(known_dimensions.width)
             .maybe_set(node_size.width)
             .map_definite_value(|size| {
@@ -124,6 +167,13 @@ where
             .map(AvailableSpace::from)
             .unwrap_or(available_space.height)
             .maybe_sub(margin.vertical_axis_sum())
+            .apply_intrinsic_sizing_keywords(
+                size_style.height,
+                min_size_style.height,
+                max_size_style.height,
+                parent_size.height,
+                box_sizing_adjustment.height,
+            )
             .maybe_set(known_dimensions.height)
             .maybe_set(node_size.height)
             .map_definite_value(|size| {
@@ -132,29 +182,68 @@ where
     };
 
     // Measure node
-    let measured_size = measure_function(
+    let measure_output: LeafMeasureOutput = measure_function(
         match run_mode {
             RunMode::ComputeSize => known_dimensions,
             RunMode::PerformLayout => Size::NONE,
             RunMode::PerformHiddenLayout => unreachable!(),
         },
         available_space,
-    );
-    let clamped_size = known_dimensions
-        .or(node_size)
-        .unwrap_or(measured_size + content_box_inset.sum_axes())
-        .maybe_clamp(node_min_size, node_max_size);
-    let size = Size {
-        width: clamped_size.width,
-        height: f32_max(clamped_size.height, aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0)),
+    )
+    .into();
+    let measured_size = measure_output.size;
+
+    // A natural aspect ratio is used unless an aspect ratio was specified without `auto`, and applies to the content
+    // box. An `auto <ratio>` aspect ratio that was deferred above applies to the box specified by `box_sizing`.
+    let natural_aspect_ratio =
+        measure_output.natural_aspect_ratio.filter(|_| style.aspect_ratio_auto() || style.aspect_ratio().is_none());
+    let deferred_aspect_ratio = match (natural_aspect_ratio, sizing_mode) {
+        (Some(ratio), _) => Some((ratio, pb_sum)),
+        (None, SizingMode::InherentSize) if style.aspect_ratio_auto() => {
+            style.aspect_ratio().map(|ratio| (ratio, box_sizing_adjustment))
+        }
+        (None, _) => None,
+    };
+
+    let (node_size, node_min_size) = match deferred_aspect_ratio {
+        Some((ratio, adjustment)) => {
+            let apply_aspect_ratio = |size: Size<Option<f32>>| {
+                size.maybe_sub(adjustment).maybe_apply_aspect_ratio(Some(ratio)).maybe_add(adjustment)
+            };
+            (apply_aspect_ratio(known_dimensions.or(node_size)), apply_aspect_ratio(node_min_size))
+        }
+        None => (known_dimensions.or(node_size), node_min_size),
+    };
+
+    let clamped_size =
+        node_size.unwrap_or(measured_size + content_box_inset.sum_axes()).maybe_clamp(node_min_size, node_max_size);
+    let min_height_from_aspect_ratio = match deferred_aspect_ratio {
+        Some((ratio, adjustment)) => (clamped_size.width - adjustment.width) / ratio + adjustment.height,
+        None => aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0),
     };
+    let size = Size { width: clamped_size.width, height: f32_max(clamped_size.height, min_height_from_aspect_ratio) };
     let size = size.maybe_max(padding_border.sum_axes().map(Some));
 
+    // `Overflow::Auto` scrollbars are shown if the measured content overflows the node. Showing one scrollbar
+    // reduces the space available to the content in the other axis, so this is checked a second time.
+    let mut auto_scrollbars = Point { x: false, y: false };
+    if run_mode == RunMode::PerformLayout {
+        for _ in 0..2 {
+            let scrollbar_size = resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, auto_scrollbars);
+            let overflowing =
+                overflowing_auto_axes(overflow, scrollbar_size, border, size, measured_size + padding.sum_axes());
+            auto_scrollbars = Point { x: auto_scrollbars.x || overflowing.x, y: auto_scrollbars.y || overflowing.y };
+        }
+    }
+    let scrollbar_size = resolve_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, auto_scrollbars);
+
     LayoutOutput {
         size,
         #[cfg(feature = "content_size")]
         content_size: measured_size + padding.sum_axes(),
+        scrollbar_size,
         first_baselines: Point::NONE,
+        last_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: !has_styles_preventing_being_collapsed_through
@@ -162,3 +251,70 @@ where
             && measured_size.height == 0.0,
     }
 }
+
+/// Extension trait for applying intrinsic sizing keywords to the available space passed to a leaf's measure function
+trait ApplyIntrinsicSizingKeywords {
+    /// Constrain the available space according to any min-content, max-content or fit-content values in the
+    /// size, min-size and max-size styles
+    fn apply_intrinsic_sizing_keywords(
+        self,
+        size: Dimension,
+        min_size: Dimension,
+        max_size: Dimension,
+        parent_size: Option<f32>,
+        box_sizing_adjustment: f32,
+    ) -> Self;
+}
+
+impl ApplyIntrinsicSizingKeywords for AvailableSpace {
+    fn apply_intrinsic_sizing_keywords(
+        self,
+        size: Dimension,
+        min_size: Dimension,
+        max_size: Dimension,
+        parent_size: Option<f32>,
+        box_sizing_adjustment: f32,
+    ) -> Self {
+        // Converts a keyword into the available space under which the leaf's content takes the keyword's size.
+        // An unresolvable fit-content percentage behaves as max-content.
+        let keyword_space = |dimension: Dimension| match dimension.tag() {
+            CompactLength::MIN_CONTENT_TAG => Some(AvailableSpace::MinContent),
+            CompactLength::MAX_CONTENT_TAG => Some(AvailableSpace::MaxContent),
+            CompactLength::FIT_CONTENT_PX_TAG => {
+                Some(AvailableSpace::Definite(dimension.value() + box_sizing_adjustment))
+            }
+            CompactLength::FIT_CONTENT_PERCENT_TAG => Some(
+                parent_size
+                    .map(|size| AvailableSpace::Definite(size * dimension.value() + box_sizing_adjustment))
+                    .unwrap_or(AvailableSpace::MaxContent),
+            ),
+            _ => None,
+        };
+
+        // Orders available space constraints from most to least constraining
+        let rank = |space: AvailableSpace| match space {
+            AvailableSpace::MinContent => 0,
+            AvailableSpace::Definite(_) => 1,
+            AvailableSpace::MaxContent => 2,
+        };
+        let tighter = |a: AvailableSpace, b: AvailableSpace| match (a, b) {
+            (AvailableSpace::Definite(a), AvailableSpace::Definite(b)) => AvailableSpace::Definite(f32_min(a, b)),
+            _ if rank(b) < rank(a) => b,
+            _ => a,
+        };
+        let looser = |a: AvailableSpace, b: AvailableSpace| match (a, b) {
+            (AvailableSpace::Definite(a), AvailableSpace::Definite(b)) => AvailableSpace::Definite(f32_max(a, b)),
+            _ if rank(b) > rank(a) => b,
+            _ => a,
+        };
+
+        let mut space = keyword_space(size).unwrap_or(self);
+        if let Some(max_space) = keyword_space(max_size) {
+            space = tighter(space, max_space);
+        }
+        if let Some(min_space) = keyword_space(min_size) {
+            space = looser(space, min_space);
+        }
+        space
+    }
+}
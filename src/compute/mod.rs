@@ -9,6 +9,7 @@
 //! | [`compute_flexbox_layout`]        | Layout a Flexbox container and it's direct children                                                                                                                                                |
 //! | [`compute_grid_layout`]           | Layout a CSS Grid container and it's direct children                                                                                                                                               |
 //! | [`compute_block_layout`]          | Layout a Block container and it's direct children                                                                                                                                                  |
+//! | [`compute_table_layout`]          | Layout a CSS Table container and it's row groups, rows and cells                                                                                                                                   |
 //! | [`compute_leaf_layout`]           | Applies common properties like padding/border/aspect-ratio to a node before deferring to a passed closure to determine it's size. Can be applied to nodes like text or image nodes.                |
 //! | [`compute_root_layout`]           | Layout the root node of a tree (regardless of it's layout mode). This function is typically called once to begin a layout run.                                                                     |                                                                      |
 //! | [`compute_hidden_layout`]         | Mark a node as hidden during layout (like `Display::None`)                                                                                                                                         |
@@ -33,19 +34,28 @@ pub(crate) mod flexbox;
 #[cfg(feature = "grid")]
 pub(crate) mod grid;
 
-pub use leaf::compute_leaf_layout;
+#[cfg(feature = "table")]
+pub(crate) mod table;
+
+pub use leaf::{compute_leaf_layout, LeafMeasureOutput};
 
 #[cfg(feature = "block_layout")]
-pub use self::block::compute_block_layout;
+pub use self::block::{compute_block_layout, PlacedFloat};
+#[cfg(feature = "inline_layout")]
+pub use self::block::{InlineFragment, LineBox, ShapedTextRun, TextSegment};
 
 #[cfg(feature = "flexbox")]
 pub use self::flexbox::compute_flexbox_layout;
 
 #[cfg(feature = "grid")]
-pub use self::grid::compute_grid_layout;
+pub use self::grid::{compute_grid_layout, SubgridAxisTracks, SubgridTracks};
+
+#[cfg(feature = "table")]
+pub use self::table::compute_table_layout;
 
+use crate::compute::common::scrollbars::child_scrollbar_size;
 use crate::geometry::{Line, Point, Size};
-use crate::style::{AvailableSpace, CoreStyle, Overflow};
+use crate::style::{AvailableSpace, CoreStyle};
 use crate::tree::{
     Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode,
 };
@@ -55,6 +65,10 @@ use crate::util::ResolveOrZero;
 use crate::{BoxSizing, CacheTree, MaybeMath, MaybeResolve};
 
 /// Compute layout for the root node in the tree
+///
+/// This does not perform the tree-wide pass that [`TaffyTree`](crate::TaffyTree) runs once the rest of the tree has
+/// been laid out, so `Position::Fixed` nodes are laid out against their parent rather than the viewport, and the
+/// absolutely positioned children of `Position::Static` nodes are laid out against the static node itself.
 pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, available_space: Size<AvailableSpace>) {
     let mut known_dimensions = Size::NONE;
 
@@ -129,10 +143,12 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
         style.border().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
     let margin =
         style.margin().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
-    let scrollbar_size = Size {
-        width: if style.overflow().y == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
-        height: if style.overflow().x == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
-    };
+    let scrollbar_size = child_scrollbar_size(
+        style.overflow(),
+        style.scrollbar_width(),
+        style.scrollbar_gutter(),
+        output.scrollbar_size,
+    );
     drop(style);
 
     tree.set_unrounded_layout(
@@ -144,6 +160,7 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
             #[cfg(feature = "content_size")]
             content_size: output.content_size,
             scrollbar_size,
+            sticky_offset: Point::ZERO,
             padding,
             border,
             // TODO: support auto margins for root node?
@@ -219,6 +236,8 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
         layout.size.height = round(cumulative_y + unrounded_layout.size.height) - round(cumulative_y);
         layout.scrollbar_size.width = round(unrounded_layout.scrollbar_size.width);
         layout.scrollbar_size.height = round(unrounded_layout.scrollbar_size.height);
+        layout.sticky_offset.x = round(unrounded_layout.sticky_offset.x);
+        layout.sticky_offset.y = round(unrounded_layout.sticky_offset.y);
         layout.border.left = round(cumulative_x + unrounded_layout.border.left) - round(cumulative_x);
         layout.border.right = round(cumulative_x + unrounded_layout.size.width)
             - round(cumulative_x + unrounded_layout.size.width - unrounded_layout.border.right);
@@ -278,6 +297,8 @@ pub fn compute_hidden_layout(tree: &mut (impl LayoutPartialTree + CacheTree), no
 /// A module for unified re-exports of detailed layout info structs, used by low level API
 #[cfg(feature = "detailed_layout_info")]
 pub mod detailed_info {
+    #[cfg(feature = "inline_layout")]
+    pub use super::block::DetailedInlineInfo;
     #[cfg(feature = "grid")]
     pub use super::grid::DetailedGridInfo;
 }
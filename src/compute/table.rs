@@ -0,0 +1,1047 @@
+//! Computes the CSS table layout algorithm in the case that the layout mode of the container is `Display::Table`
+//!
+//! This implements the [separated borders model](https://www.w3.org/TR/CSS22/tables.html#separated-borders) of CSS tables
+//! along with both the [automatic](https://www.w3.org/TR/CSS22/tables.html#auto-table-layout) and the
+//! [fixed](https://www.w3.org/TR/CSS22/tables.html#fixed-table-layout) column width algorithms.
+//!
+//! Differences from web browsers:
+//!   - Cells are stretched to the height of the row(s) they span, and their contents are top-aligned
+//!   - Column and caption boxes are not supported
+//!   - Margins on row groups, rows and cells are ignored
+use core::ops::Range;
+
+use crate::compute::common::scrollbars::{
+    child_scrollbar_size, compute_with_auto_scrollbars, resolve_scrollbar_gutter_inset, resolve_scrollbar_size,
+};
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+use crate::style::{
+    AvailableSpace, BoxGenerationMode, BoxSizing, CoreStyle, Direction, Position, TableContainerStyle, TableItemStyle,
+    TableLayout, TableRole,
+};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
+use crate::tree::{LayoutPartialTreeExt, LayoutTableContainer, NodeId};
+use crate::util::debug::debug_log;
+use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+
+#[cfg(feature = "content_size")]
+use super::common::content_size::compute_content_size_contribution;
+
+/// The node that a row, cell or out-of-flow child is a direct child of. Used to convert the table-relative
+/// positions computed by the algorithm into parent-relative locations.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum TableParent {
+    /// The table container itself
+    Table,
+    /// The row group with the specified index
+    RowGroup(usize),
+    /// The (non-anonymous) row with the specified index
+    Row(usize),
+}
+
+/// A row group (header, body or footer) of the table
+struct TableRowGroup {
+    /// The identifier for the associated node
+    node_id: NodeId,
+    /// The index of the row group within the table's children
+    order: u32,
+    /// The range of indexes into the table's list of rows which belong to this group
+    rows: Range<usize>,
+    /// The computed location of the row group relative to the table's border box
+    location: Point<f32>,
+}
+
+/// A row of the table. Consecutive cells which are not contained within a row are wrapped in an anonymous row.
+struct TableRow {
+    /// The identifier for the associated node. `None` for anonymous rows.
+    node_id: Option<NodeId>,
+    /// The index of the row within its parent's children
+    order: u32,
+    /// The node that the row is a child of
+    parent: TableParent,
+    /// The index of the first row that cells in this row cannot span into (the end of the row group)
+    span_limit: usize,
+    /// The height of the row as specified by it's styles
+    style_height: Option<f32>,
+    /// The computed height of the row
+    height: f32,
+    /// The computed y offset of the row relative to the table's border box
+    offset: f32,
+}
+
+/// A cell of the table
+struct TableCell {
+    /// The identifier for the associated node
+    node_id: NodeId,
+    /// The index of the cell within its parent's children
+    order: u32,
+    /// The node that the cell is a child of
+    parent: TableParent,
+    /// The index of the first row the cell occupies
+    row: usize,
+    /// The index of the first column the cell occupies
+    column: usize,
+    /// The number of rows the cell occupies
+    row_span: usize,
+    /// The number of columns the cell occupies
+    column_span: usize,
+    /// The min-content width of the cell
+    min_content_width: f32,
+    /// The max-content width of the cell
+    max_content_width: f32,
+    /// The computed width of the cell
+    width: f32,
+    /// The computed height of the cell's contents
+    content_height: f32,
+}
+
+/// A child of the table, a row group or a row which does not take part in the table's structure
+/// (because it is absolutely positioned or `display: none`)
+struct OutOfFlowChild {
+    /// The identifier for the associated node
+    node_id: NodeId,
+    /// The index of the node within its parent's children
+    order: u32,
+    /// The node that the child is a child of
+    parent: TableParent,
+}
+
+/// The rows, row groups and cells of a table along with the grid of slots they occupy
+struct TableStructure {
+    /// The row groups of the table in the order in which they are laid out
+    row_groups: Vec<TableRowGroup>,
+    /// The rows of the table in the order in which they are laid out
+    rows: Vec<TableRow>,
+    /// The cells of the table, ordered by row
+    cells: Vec<TableCell>,
+    /// Children that do not take part in the table's structure
+    out_of_flow: Vec<OutOfFlowChild>,
+    /// The number of columns in the table
+    column_count: usize,
+    /// The x offset of the first column relative to the table's border box
+    first_column_offset: f32,
+}
+
+/// Computes the layout of [`LayoutTableContainer`] according to the CSS table layout algorithm
+pub fn compute_table_layout(tree: &mut impl LayoutTableContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    compute_with_auto_scrollbars(tree, node, inputs, compute_inner)
+}
+
+/// Computes the layout of [`LayoutTableContainer`], given the axes in which `Overflow::Auto` scrollbars are shown
+fn compute_inner(
+    tree: &mut impl LayoutTableContainer,
+    node: NodeId,
+    inputs: LayoutInput,
+    auto_scrollbars: Point<bool>,
+) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, sizing_mode, .. } = inputs;
+
+    let style = tree.get_table_container_style(node);
+
+    // Resolve the table's padding, border and size styles
+    let aspect_ratio = style.aspect_ratio();
+    let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding_border = padding + border;
+    let padding_border_size = padding_border.sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+
+    let min_size = style
+        .min_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let clamped_style_size = if sizing_mode == SizingMode::InherentSize {
+        style
+            .size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size)
+    } else {
+        Size::NONE
+    };
+
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`, or to
+    // `Overflow::Auto` and the content was found to overflow the node, unless overridden by `scrollbar_gutter`.
+    let direction = tree.resolve_direction(node);
+    let scrollbar_gutter = {
+        let scrollbar_size = resolve_scrollbar_size(
+            style.overflow(),
+            style.scrollbar_width(),
+            style.scrollbar_gutter(),
+            auto_scrollbars,
+        );
+        resolve_scrollbar_gutter_inset(scrollbar_size, style.scrollbar_gutter(), direction)
+    };
+    let content_box_inset = padding_border + scrollbar_gutter;
+
+    let table_layout = style.table_layout();
+    let border_spacing = style.border_spacing();
+
+    drop(style);
+
+    // Sizes imposed by the table's own styles act as a minimum: the table grows to fit its contents.
+    // Sizes imposed by the parent (known_dimensions) are always respected.
+    let styled_size = clamped_style_size.maybe_max(padding_border_size);
+    let outer_size = known_dimensions.or(styled_size);
+
+    // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
+    // is ComputeSize (and thus the container's size is all that we're interested in)
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+            return LayoutOutput::from_outer_size(Size { width, height });
+        }
+    }
+
+    debug_log!("TABLE");
+
+    let inner_size = outer_size.maybe_sub(content_box_inset.sum_axes()).maybe_max(Size::ZERO.map(Some));
+    let border_spacing = Size {
+        width: border_spacing.width.resolve_or_zero(inner_size.width, |val, basis| tree.calc(val, basis)),
+        height: border_spacing.height.resolve_or_zero(inner_size.height, |val, basis| tree.calc(val, basis)),
+    };
+
+    // 1. Generate the row groups, rows and cells of the table and assign each cell to a slot in the table's grid
+    let mut structure = generate_table_structure(tree, node, inner_size);
+    assign_cell_slots(&mut structure);
+    let column_count = structure.column_count;
+    let row_count = structure.rows.len();
+    let column_spacing_sum = if column_count > 0 { border_spacing.width * (column_count + 1) as f32 } else { 0.0 };
+    let row_spacing_sum = if row_count > 0 { border_spacing.height * (row_count + 1) as f32 } else { 0.0 };
+
+    debug_log!("column_count", dbg:column_count);
+    debug_log!("row_count", dbg:row_count);
+
+    // 2. Determine the widths of the table's columns and the width of the table itself
+    let fixed_layout_width = if table_layout == TableLayout::Fixed { inner_size.width } else { None };
+    let (column_widths, container_outer_width) = if let Some(styled_inner_width) = fixed_layout_width {
+        let column_widths = compute_fixed_column_widths(
+            tree,
+            &structure,
+            styled_inner_width,
+            styled_inner_width - column_spacing_sum,
+            border_spacing.width,
+        );
+        let content_width = column_widths.iter().sum::<f32>() + column_spacing_sum;
+        let outer_width = known_dimensions
+            .width
+            .unwrap_or_else(|| f32_max(styled_inner_width, content_width) + content_box_inset.horizontal_axis_sum());
+        (column_widths, outer_width)
+    } else {
+        let (column_min_widths, column_max_widths) =
+            compute_column_intrinsic_widths(tree, &mut structure, inner_size, border_spacing.width);
+        let min_table_width = column_min_widths.iter().sum::<f32>() + column_spacing_sum;
+        let max_table_width = column_max_widths.iter().sum::<f32>() + column_spacing_sum;
+
+        let outer_width = known_dimensions.width.unwrap_or_else(|| {
+            let content_width = match inner_size.width {
+                Some(styled_inner_width) => styled_inner_width,
+                None => {
+                    let available_width = available_space
+                        .width
+                        .maybe_sub(margin.horizontal_axis_sum() + content_box_inset.horizontal_axis_sum());
+                    let shrink_to_fit_width = match available_width {
+                        AvailableSpace::Definite(width) => f32_max(min_table_width, f32_min(max_table_width, width)),
+                        AvailableSpace::MinContent => min_table_width,
+                        AvailableSpace::MaxContent => max_table_width,
+                    };
+                    (shrink_to_fit_width + content_box_inset.horizontal_axis_sum())
+                        .maybe_clamp(min_size.width, max_size.width)
+                        - content_box_inset.horizontal_axis_sum()
+                }
+            };
+            // A table is never narrower than the min-content width of its columns
+            f32_max(content_width, min_table_width) + content_box_inset.horizontal_axis_sum()
+        });
+        let outer_width = f32_max(outer_width, padding_border_size.width);
+        let available_column_width = outer_width - content_box_inset.horizontal_axis_sum() - column_spacing_sum;
+        let column_widths = distribute_width_to_columns(&column_min_widths, &column_max_widths, available_column_width);
+        (column_widths, outer_width)
+    };
+
+    // Compute the offset of each column relative to the table's border box
+    let mut column_offsets = Vec::with_capacity(column_count);
+    let mut x_offset = content_box_inset.left + border_spacing.width;
+    for width in column_widths.iter() {
+        column_offsets.push(x_offset);
+        x_offset += width + border_spacing.width;
+    }
+    let columns_width = column_widths.iter().sum::<f32>() + column_spacing_sum - 2.0 * border_spacing.width;
+    let columns_width = f32_max(columns_width, 0.0);
+
+    // 3. Determine the heights of the table's rows and the height of the table itself
+    let table_content_width = container_outer_width - content_box_inset.horizontal_axis_sum();
+    let cell_parent_size = Size { width: Some(table_content_width), height: inner_size.height };
+    for cell in structure.cells.iter_mut() {
+        let last_column = cell.column + cell.column_span - 1;
+        cell.width = column_offsets[last_column] + column_widths[last_column] - column_offsets[cell.column];
+        cell.content_height = tree.measure_child_size(
+            cell.node_id,
+            Size { width: Some(cell.width), height: None },
+            cell_parent_size,
+            Size { width: AvailableSpace::Definite(cell.width), height: AvailableSpace::MaxContent },
+            SizingMode::InherentSize,
+            AbsoluteAxis::Vertical,
+            Line::FALSE,
+        );
+    }
+    compute_row_heights(&mut structure, border_spacing.height);
+
+    let content_height = structure.rows.iter().map(|row| row.height).sum::<f32>() + row_spacing_sum;
+    let container_outer_height = known_dimensions.height.unwrap_or_else(|| {
+        let intrinsic_outer_height = content_height + content_box_inset.vertical_axis_sum();
+        let outer_height = match styled_size.height {
+            Some(height) => f32_max(height, intrinsic_outer_height),
+            None => intrinsic_outer_height.maybe_clamp(min_size.height, max_size.height),
+        };
+        f32_max(outer_height, padding_border_size.height)
+    });
+    let final_outer_size = Size { width: container_outer_width, height: container_outer_height };
+
+    // Short-circuit if computing size
+    if run_mode == RunMode::ComputeSize {
+        return LayoutOutput::from_outer_size(final_outer_size);
+    }
+
+    // Distribute any extra height to the rows
+    let extra_height = container_outer_height - content_box_inset.vertical_axis_sum() - content_height;
+    if extra_height > 0.0 && row_count > 0 {
+        let total_height: f32 = structure.rows.iter().map(|row| row.height).sum();
+        for row in structure.rows.iter_mut() {
+            row.height += if total_height > 0.0 {
+                extra_height * row.height / total_height
+            } else {
+                extra_height / row_count as f32
+            };
+        }
+    }
+
+    // Compute the offset of each row relative to the table's border box
+    let mut y_offset = content_box_inset.top + border_spacing.height;
+    for row in structure.rows.iter_mut() {
+        row.offset = y_offset;
+        y_offset += row.height + border_spacing.height;
+    }
+    let first_column_offset = content_box_inset.left + border_spacing.width;
+    structure.first_column_offset = first_column_offset;
+
+    // 4. Position row groups and rows
+    for group in structure.row_groups.iter_mut() {
+        let (y, height) = match (structure.rows.get(group.rows.start), group.rows.is_empty()) {
+            (Some(first_row), false) => {
+                let last_row = &structure.rows[group.rows.end - 1];
+                (first_row.offset, last_row.offset + last_row.height - first_row.offset)
+            }
+            (Some(next_row), true) => (next_row.offset, 0.0),
+            (None, _) => (y_offset, 0.0),
+        };
+        group.location = Point { x: first_column_offset, y };
+        tree.set_unrounded_layout(
+            group.node_id,
+            &Layout {
+                location: group.location,
+                size: Size { width: columns_width, height },
+                ..Layout::with_order(group.order)
+            },
+        );
+    }
+    for index in 0..row_count {
+        let row = &structure.rows[index];
+        if let Some(node_id) = row.node_id {
+            let location = Point { x: first_column_offset, y: row.offset } - parent_offset(&structure, row.parent);
+            tree.set_unrounded_layout(
+                node_id,
+                &Layout {
+                    location,
+                    size: Size { width: columns_width, height: row.height },
+                    ..Layout::with_order(row.order)
+                },
+            );
+        }
+    }
+
+    // 5. Perform final layout on each cell
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut inflow_content_size = Size::ZERO;
+    let mut first_baseline = None;
+    let mut last_baseline = None;
+    for cell in structure.cells.iter() {
+        let first_row = &structure.rows[cell.row];
+        let last_row = &structure.rows[cell.row + cell.row_span - 1];
+        let cell_size = Size { width: cell.width, height: last_row.offset + last_row.height - first_row.offset };
+
+        let cell_style = tree.get_table_child_style(cell.node_id);
+        let overflow = cell_style.overflow();
+        let scrollbar_width = cell_style.scrollbar_width();
+        let scrollbar_gutter = cell_style.scrollbar_gutter();
+        let cell_padding =
+            cell_style.padding().resolve_or_zero(Some(table_content_width), |val, basis| tree.calc(val, basis));
+        let cell_border =
+            cell_style.border().resolve_or_zero(Some(table_content_width), |val, basis| tree.calc(val, basis));
+        drop(cell_style);
+
+        let layout_output = tree.perform_child_layout(
+            cell.node_id,
+            cell_size.map(Some),
+            cell_parent_size,
+            cell_size.map(AvailableSpace::Definite),
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+
+        // Columns are ordered from right to left in right-to-left tables
+        let x = match direction {
+            Direction::Ltr => column_offsets[cell.column],
+            Direction::Rtl => {
+                content_box_inset.left + container_outer_width
+                    - content_box_inset.right
+                    - column_offsets[cell.column]
+                    - cell.width
+            }
+        };
+        let table_relative_location = Point { x, y: first_row.offset };
+        if cell.row == 0 && first_baseline.is_none() {
+            first_baseline = layout_output.first_baselines.y.map(|baseline| table_relative_location.y + baseline);
+        }
+        // The last baseline of a table is the baseline of its last row
+        if cell.row + 1 == structure.rows.len() && last_baseline.is_none() {
+            last_baseline = layout_output.first_baselines.y.map(|baseline| table_relative_location.y + baseline);
+        }
+
+        let scrollbar_size =
+            child_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, layout_output.scrollbar_size);
+
+        tree.set_unrounded_layout(
+            cell.node_id,
+            &Layout {
+                order: cell.order,
+                location: table_relative_location - parent_offset(&structure, cell.parent),
+                size: cell_size,
+                #[cfg(feature = "content_size")]
+                content_size: layout_output.content_size,
+                scrollbar_size,
+                sticky_offset: Point::ZERO,
+                padding: cell_padding,
+                border: cell_border,
+                margin: Rect::zero(),
+            },
+        );
+
+        #[cfg(feature = "content_size")]
+        {
+            inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
+                table_relative_location,
+                cell_size,
+                layout_output.content_size,
+                overflow,
+            ));
+        }
+    }
+
+    // 6. Perform hidden layout on hidden children
+    for child in structure.out_of_flow.iter() {
+        if tree.get_table_child_style(child.node_id).box_generation_mode() == BoxGenerationMode::None {
+            tree.set_unrounded_layout(child.node_id, &Layout::with_order(child.order));
+            tree.perform_child_layout(
+                child.node_id,
+                Size::NONE,
+                Size::NONE,
+                Size::MAX_CONTENT,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+        }
+    }
+
+    // 7. Layout absolutely positioned children. The containing block is the table's padding box.
+    let absolute_position_inset = border + scrollbar_gutter;
+    let absolute_position_area = final_outer_size - absolute_position_inset.sum_axes();
+    let absolute_position_offset = Point { x: absolute_position_inset.left, y: absolute_position_inset.top };
+    let static_position = Point { x: content_box_inset.left, y: content_box_inset.top };
+    let mut absolute_content_size = perform_absolute_layout_on_absolute_children(
+        tree,
+        &structure,
+        absolute_position_area,
+        absolute_position_offset,
+        static_position,
+    );
+    // The absolutely positioned children of a `Position::Static` table are laid out again against their actual
+    // containing block by `TaffyTree`, so they don't contribute to its content size
+    if !tree.get_table_container_style(node).position().is_positioned() {
+        absolute_content_size = Size::ZERO;
+    }
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+    let content_size = inflow_content_size.f32_max(absolute_content_size);
+
+    LayoutOutput::from_sizes_and_first_and_last_baselines(
+        final_outer_size,
+        content_size,
+        Point { x: None, y: first_baseline },
+        Point { x: None, y: last_baseline },
+    )
+}
+
+/// Whether a child of the table (or of a row group or row) is excluded from the table's structure
+#[inline]
+fn is_out_of_flow(style: &impl CoreStyle) -> bool {
+    style.box_generation_mode() == BoxGenerationMode::None || style.position().is_absolutely_positioned()
+}
+
+/// The children of a table, row group or row, with any `display: contents` children replaced by their own children
+fn table_child_ids(tree: &impl LayoutTableContainer, node: NodeId) -> Vec<NodeId> {
+    fn push_child_ids(tree: &impl LayoutTableContainer, node: NodeId, child_ids: &mut Vec<NodeId>) {
+        for child in tree.child_ids(node) {
+            if tree.get_table_child_style(child).box_generation_mode() == BoxGenerationMode::Contents {
+                push_child_ids(tree, child, child_ids);
+            } else {
+                child_ids.push(child);
+            }
+        }
+    }
+
+    let mut child_ids = Vec::new();
+    push_child_ids(tree, node, &mut child_ids);
+    child_ids
+}
+
+/// Generate the row groups, rows and cells of the table from the table's descendants.
+///
+/// The first header group is moved to the top of the table and the first footer group is moved to the bottom.
+/// Consecutive children which are not rows or row groups are wrapped in anonymous rows.
+fn generate_table_structure(
+    tree: &impl LayoutTableContainer,
+    node: NodeId,
+    inner_size: Size<Option<f32>>,
+) -> TableStructure {
+    let mut structure = TableStructure {
+        row_groups: Vec::new(),
+        rows: Vec::new(),
+        cells: Vec::new(),
+        out_of_flow: Vec::new(),
+        column_count: 0,
+        first_column_offset: 0.0,
+    };
+
+    let mut children: Vec<(NodeId, u32, TableRole)> = Vec::new();
+    for (order, child) in table_child_ids(tree, node).into_iter().enumerate() {
+        let child_style = tree.get_table_child_style(child);
+        if is_out_of_flow(&child_style) {
+            structure.out_of_flow.push(OutOfFlowChild {
+                node_id: child,
+                order: order as u32,
+                parent: TableParent::Table,
+            });
+        } else {
+            children.push((child, order as u32, child_style.table_role()));
+        }
+    }
+    if let Some(index) = children.iter().position(|(_, _, role)| *role == TableRole::HeaderGroup) {
+        let header = children.remove(index);
+        children.insert(0, header);
+    }
+    if let Some(index) = children.iter().position(|(_, _, role)| *role == TableRole::FooterGroup) {
+        let footer = children.remove(index);
+        children.push(footer);
+    }
+
+    let mut anonymous_row = None;
+    for (child, order, role) in children {
+        if role.is_row_group() {
+            anonymous_row = None;
+            let group_index = structure.row_groups.len();
+            let rows_start = structure.rows.len();
+            structure.row_groups.push(TableRowGroup {
+                node_id: child,
+                order,
+                rows: rows_start..rows_start,
+                location: Point::ZERO,
+            });
+            let mut group_anonymous_row = None;
+            for (order, grandchild) in table_child_ids(tree, child).into_iter().enumerate() {
+                push_table_child(
+                    tree,
+                    &mut structure,
+                    grandchild,
+                    order as u32,
+                    TableParent::RowGroup(group_index),
+                    &mut group_anonymous_row,
+                    inner_size,
+                );
+            }
+            let rows_end = structure.rows.len();
+            structure.row_groups[group_index].rows.end = rows_end;
+            for row in &mut structure.rows[rows_start..rows_end] {
+                row.span_limit = rows_end;
+            }
+        } else {
+            push_table_child(tree, &mut structure, child, order, TableParent::Table, &mut anonymous_row, inner_size);
+        }
+    }
+
+    // Rows which are direct children of the table are treated as if they were wrapped in an anonymous row group
+    let row_count = structure.rows.len();
+    let mut index = 0;
+    while index < row_count {
+        if structure.rows[index].parent == TableParent::Table {
+            let run_end =
+                (index..row_count).find(|&i| structure.rows[i].parent != TableParent::Table).unwrap_or(row_count);
+            for row in &mut structure.rows[index..run_end] {
+                row.span_limit = run_end;
+            }
+            index = run_end;
+        } else {
+            index += 1;
+        }
+    }
+
+    structure
+}
+
+/// Add a child of the table or of a row group to the table's structure
+fn push_table_child(
+    tree: &impl LayoutTableContainer,
+    structure: &mut TableStructure,
+    node: NodeId,
+    order: u32,
+    parent: TableParent,
+    anonymous_row: &mut Option<usize>,
+    inner_size: Size<Option<f32>>,
+) {
+    let style = tree.get_table_child_style(node);
+    if is_out_of_flow(&style) {
+        structure.out_of_flow.push(OutOfFlowChild { node_id: node, order, parent });
+        return;
+    }
+
+    if style.table_role() == TableRole::Row {
+        *anonymous_row = None;
+        let row_index = structure.rows.len();
+        structure.rows.push(TableRow {
+            node_id: Some(node),
+            order,
+            parent,
+            span_limit: row_index + 1,
+            style_height: style.size().height.maybe_resolve(inner_size.height, |val, basis| tree.calc(val, basis)),
+            height: 0.0,
+            offset: 0.0,
+        });
+        drop(style);
+
+        for (order, cell) in table_child_ids(tree, node).into_iter().enumerate() {
+            let cell_style = tree.get_table_child_style(cell);
+            if is_out_of_flow(&cell_style) {
+                structure.out_of_flow.push(OutOfFlowChild {
+                    node_id: cell,
+                    order: order as u32,
+                    parent: TableParent::Row(row_index),
+                });
+            } else {
+                structure.cells.push(new_cell(&cell_style, cell, order as u32, TableParent::Row(row_index), row_index));
+            }
+        }
+    } else {
+        let row_index = *anonymous_row.get_or_insert_with(|| {
+            let row_index = structure.rows.len();
+            structure.rows.push(TableRow {
+                node_id: None,
+                order: 0,
+                parent,
+                span_limit: row_index + 1,
+                style_height: None,
+                height: 0.0,
+                offset: 0.0,
+            });
+            row_index
+        });
+        structure.cells.push(new_cell(&style, node, order, parent, row_index));
+    }
+}
+
+/// Create a new cell in the specified row. The cell is assigned to a column later.
+#[inline]
+fn new_cell(style: &impl TableItemStyle, node_id: NodeId, order: u32, parent: TableParent, row: usize) -> TableCell {
+    TableCell {
+        node_id,
+        order,
+        parent,
+        row,
+        column: 0,
+        row_span: style.rowspan() as usize,
+        column_span: (style.colspan() as usize).max(1),
+        min_content_width: 0.0,
+        max_content_width: 0.0,
+        width: 0.0,
+        content_height: 0.0,
+    }
+}
+
+/// Assign each cell to the first column in its row that is not occupied by a cell spanning down from an earlier row,
+/// and resolve each cell's row span against the end of its row group.
+fn assign_cell_slots(structure: &mut TableStructure) {
+    // For each column, the index of the first row which is not occupied by a previously placed cell
+    let mut occupied_until: Vec<usize> = Vec::new();
+    let mut current_row = usize::MAX;
+    let mut column = 0;
+    for cell in structure.cells.iter_mut() {
+        if cell.row != current_row {
+            current_row = cell.row;
+            column = 0;
+        }
+        while matches!(occupied_until.get(column), Some(&until) if until > cell.row) {
+            column += 1;
+        }
+
+        let max_row_span = structure.rows[cell.row].span_limit - cell.row;
+        cell.row_span = if cell.row_span == 0 { max_row_span } else { cell.row_span.min(max_row_span) };
+        cell.column = column;
+
+        let column_end = column + cell.column_span;
+        if occupied_until.len() < column_end {
+            occupied_until.resize(column_end, 0);
+        }
+        for until in &mut occupied_until[column..column_end] {
+            *until = cell.row + cell.row_span;
+        }
+        column = column_end;
+    }
+    structure.column_count = occupied_until.len();
+}
+
+/// Determine the min-content and max-content width of each column using the automatic table layout algorithm
+fn compute_column_intrinsic_widths(
+    tree: &mut impl LayoutTableContainer,
+    structure: &mut TableStructure,
+    inner_size: Size<Option<f32>>,
+    column_spacing: f32,
+) -> (Vec<f32>, Vec<f32>) {
+    let parent_size = Size { width: inner_size.width, height: None };
+    for cell in structure.cells.iter_mut() {
+        cell.min_content_width = tree.measure_child_size(
+            cell.node_id,
+            Size::NONE,
+            parent_size,
+            Size { width: AvailableSpace::MinContent, height: AvailableSpace::MinContent },
+            SizingMode::InherentSize,
+            AbsoluteAxis::Horizontal,
+            Line::FALSE,
+        );
+        cell.max_content_width = tree.measure_child_size(
+            cell.node_id,
+            Size::NONE,
+            parent_size,
+            Size { width: AvailableSpace::MaxContent, height: AvailableSpace::MaxContent },
+            SizingMode::InherentSize,
+            AbsoluteAxis::Horizontal,
+            Line::FALSE,
+        );
+        cell.max_content_width = f32_max(cell.max_content_width, cell.min_content_width);
+    }
+
+    let mut min_widths = Vec::new();
+    let mut max_widths = Vec::new();
+    min_widths.resize(structure.column_count, 0.0);
+    max_widths.resize(structure.column_count, 0.0);
+
+    // Cells which span a single column contribute directly to the width of that column
+    for cell in structure.cells.iter().filter(|cell| cell.column_span == 1) {
+        min_widths[cell.column] = f32_max(min_widths[cell.column], cell.min_content_width);
+        max_widths[cell.column] = f32_max(max_widths[cell.column], cell.max_content_width);
+    }
+
+    // Cells which span multiple columns distribute any width that the spanned columns don't already provide
+    // between those columns (in order of increasing span)
+    let mut spanning_cells: Vec<&TableCell> = structure.cells.iter().filter(|cell| cell.column_span > 1).collect();
+    spanning_cells.sort_by_key(|cell| cell.column_span);
+    for cell in spanning_cells {
+        let columns = cell.column..(cell.column + cell.column_span);
+        let spacing = column_spacing * (cell.column_span - 1) as f32;
+
+        let weights: Vec<f32> = max_widths[columns.clone()].to_vec();
+        let spanned_min = min_widths[columns.clone()].iter().sum::<f32>() + spacing;
+        distribute_excess(&mut min_widths[columns.clone()], &weights, cell.min_content_width - spanned_min);
+        let spanned_max = max_widths[columns.clone()].iter().sum::<f32>() + spacing;
+        distribute_excess(&mut max_widths[columns], &weights, cell.max_content_width - spanned_max);
+    }
+
+    for (max, min) in max_widths.iter_mut().zip(min_widths.iter()) {
+        *max = f32_max(*max, *min);
+    }
+
+    (min_widths, max_widths)
+}
+
+/// Determine the width of each column using the fixed table layout algorithm. Only the cells in the first row
+/// are taken into account. Columns without a definite width share the remaining space equally.
+fn compute_fixed_column_widths(
+    tree: &impl LayoutTableContainer,
+    structure: &TableStructure,
+    table_inner_width: f32,
+    available_width: f32,
+    column_spacing: f32,
+) -> Vec<f32> {
+    let mut widths: Vec<Option<f32>> = Vec::new();
+    widths.resize(structure.column_count, None);
+
+    for cell in structure.cells.iter().filter(|cell| cell.row == 0) {
+        let style = tree.get_table_child_style(cell.node_id);
+        let padding_border_size =
+            (style.padding().resolve_or_zero(Some(table_inner_width), |val, basis| tree.calc(val, basis))
+                + style.border().resolve_or_zero(Some(table_inner_width), |val, basis| tree.calc(val, basis)))
+            .horizontal_axis_sum();
+        let box_sizing_adjustment = if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { 0.0 };
+        let cell_width = style
+            .size()
+            .width
+            .maybe_resolve(Some(table_inner_width), |val, basis| tree.calc(val, basis))
+            .map(|width| f32_max(width + box_sizing_adjustment, padding_border_size));
+
+        if let Some(cell_width) = cell_width {
+            let spacing = column_spacing * (cell.column_span - 1) as f32;
+            let column_width = f32_max(0.0, cell_width - spacing) / cell.column_span as f32;
+            for width in &mut widths[cell.column..(cell.column + cell.column_span)] {
+                *width = Some(column_width);
+            }
+        }
+    }
+
+    let specified_sum: f32 = widths.iter().flatten().sum();
+    let auto_column_count = widths.iter().filter(|width| width.is_none()).count();
+    let remaining_width = f32_max(0.0, available_width - specified_sum);
+
+    if auto_column_count > 0 {
+        let auto_width = remaining_width / auto_column_count as f32;
+        widths.iter().map(|width| width.unwrap_or(auto_width)).collect()
+    } else {
+        let mut widths: Vec<f32> = widths.iter().map(|width| width.unwrap_or(0.0)).collect();
+        let weights = widths.clone();
+        distribute_excess(&mut widths, &weights, remaining_width);
+        widths
+    }
+}
+
+/// Determine the final width of each column given the width available to the columns
+fn distribute_width_to_columns(min_widths: &[f32], max_widths: &[f32], available_width: f32) -> Vec<f32> {
+    let min_sum: f32 = min_widths.iter().sum();
+    let max_sum: f32 = max_widths.iter().sum();
+
+    if available_width <= min_sum {
+        min_widths.to_vec()
+    } else if available_width <= max_sum {
+        let ratio = (available_width - min_sum) / (max_sum - min_sum);
+        min_widths.iter().zip(max_widths.iter()).map(|(min, max)| min + (max - min) * ratio).collect()
+    } else {
+        let mut widths = max_widths.to_vec();
+        distribute_excess(&mut widths, max_widths, available_width - max_sum);
+        widths
+    }
+}
+
+/// Distribute a positive amount of space between tracks in proportion to the passed weights,
+/// or equally if all of the weights are zero. Negative amounts are ignored.
+fn distribute_excess(sizes: &mut [f32], weights: &[f32], excess: f32) {
+    if excess <= 0.0 || sizes.is_empty() {
+        return;
+    }
+    let total_weight: f32 = weights.iter().sum();
+    for (size, weight) in sizes.iter_mut().zip(weights.iter()) {
+        *size += if total_weight > 0.0 { excess * weight / total_weight } else { excess / weights.len() as f32 };
+    }
+}
+
+/// Determine the height of each row from the heights of the cells in that row
+fn compute_row_heights(structure: &mut TableStructure, row_spacing: f32) {
+    for row in structure.rows.iter_mut() {
+        row.height = row.style_height.unwrap_or(0.0);
+    }
+
+    // Cells which span a single row contribute directly to the height of that row
+    for cell in structure.cells.iter().filter(|cell| cell.row_span == 1) {
+        let row = &mut structure.rows[cell.row];
+        row.height = f32_max(row.height, cell.content_height);
+    }
+
+    // Cells which span multiple rows distribute any height that the spanned rows don't already provide
+    // between those rows (in order of increasing span)
+    let mut spanning_cells: Vec<&TableCell> = structure.cells.iter().filter(|cell| cell.row_span > 1).collect();
+    spanning_cells.sort_by_key(|cell| cell.row_span);
+    for cell in spanning_cells {
+        let rows = &mut structure.rows[cell.row..(cell.row + cell.row_span)];
+        let spanned_height = rows.iter().map(|row| row.height).sum::<f32>() + row_spacing * (cell.row_span - 1) as f32;
+        let excess = cell.content_height - spanned_height;
+        if excess > 0.0 {
+            let total_height: f32 = rows.iter().map(|row| row.height).sum();
+            let row_count = rows.len() as f32;
+            for row in rows.iter_mut() {
+                row.height += if total_height > 0.0 { excess * row.height / total_height } else { excess / row_count };
+            }
+        }
+    }
+}
+
+/// The location of the specified parent node relative to the table's border box
+#[inline]
+fn parent_offset(structure: &TableStructure, parent: TableParent) -> Point<f32> {
+    match parent {
+        TableParent::Table => Point::ZERO,
+        TableParent::RowGroup(index) => structure.row_groups[index].location,
+        TableParent::Row(index) => Point { x: structure.first_column_offset, y: structure.rows[index].offset },
+    }
+}
+
+/// Perform absolute layout on all absolutely positioned children of the table, its row groups and its rows.
+/// Returns the contribution of the absolutely positioned children to the table's content size.
+#[inline]
+fn perform_absolute_layout_on_absolute_children(
+    tree: &mut impl LayoutTableContainer,
+    structure: &TableStructure,
+    area_size: Size<f32>,
+    area_offset: Point<f32>,
+    static_position: Point<f32>,
+) -> Size<f32> {
+    let area_width = area_size.width;
+    let area_height = area_size.height;
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut absolute_content_size = Size::ZERO;
+
+    for child in structure.out_of_flow.iter() {
+        let child_style = tree.get_table_child_style(child.node_id);
+
+        // Skip items that are display:none (these have already been laid out as hidden)
+        if child_style.box_generation_mode() == BoxGenerationMode::None {
+            continue;
+        }
+
+        let aspect_ratio = child_style.aspect_ratio().filter(|_| !child_style.aspect_ratio_auto());
+        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+        let is_fixed = child_style.position() == Position::Fixed;
+        let overflow = child_style.overflow();
+        let scrollbar_width = child_style.scrollbar_width();
+        let scrollbar_gutter = child_style.scrollbar_gutter();
+        let margin =
+            child_style.margin().map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(val, basis)));
+        let padding = child_style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+        let border = child_style.border().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+        let padding_border_sum = (padding + border).sum_axes();
+        let box_sizing_adjustment =
+            if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+
+        // Resolve inset
+        let left = child_style.inset().left.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
+        let right = child_style.inset().right.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
+        let top = child_style.inset().top.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
+        let bottom = child_style.inset().bottom.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
+
+        // Compute known dimensions from min/max/inherent size styles
+        let style_size = child_style
+            .size()
+            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let min_size = child_style
+            .min_size()
+            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .or(padding_border_sum.map(Some))
+            .maybe_max(padding_border_sum);
+        let max_size = child_style
+            .max_size()
+            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+
+        drop(child_style);
+
+        // Fill in width from left/right and reapply aspect ratio if:
+        //   - Width is not already known
+        //   - Item has both left and right inset properties set
+        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+            let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
+            known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
+            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        }
+
+        // Fill in height from top/bottom and reapply aspect ratio if:
+        //   - Height is not already known
+        //   - Item has both top and bottom inset properties set
+        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+            let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
+            known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
+            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        }
+
+        let layout_output = tree.perform_child_layout(
+            child.node_id,
+            known_dimensions,
+            area_size.map(Some),
+            Size {
+                width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
+                height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
+            },
+            SizingMode::ContentSize,
+            Line::FALSE,
+        );
+        let final_size = known_dimensions.unwrap_or(layout_output.size).maybe_clamp(min_size, max_size);
+
+        // Auto margins resolve to zero
+        let resolved_margin = margin.map(|margin| margin.unwrap_or(0.0));
+
+        let table_relative_location = Point {
+            x: left
+                .map(|left| left + resolved_margin.left)
+                .or(right.map(|right| area_size.width - final_size.width - right - resolved_margin.right))
+                .maybe_add(area_offset.x)
+                .unwrap_or(static_position.x + resolved_margin.left),
+            y: top
+                .map(|top| top + resolved_margin.top)
+                .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
+                .maybe_add(area_offset.y)
+                .unwrap_or(static_position.y + resolved_margin.top),
+        };
+
+        let scrollbar_size =
+            child_scrollbar_size(overflow, scrollbar_width, scrollbar_gutter, layout_output.scrollbar_size);
+
+        tree.set_unrounded_layout(
+            child.node_id,
+            &Layout {
+                order: child.order,
+                size: final_size,
+                #[cfg(feature = "content_size")]
+                content_size: layout_output.content_size,
+                scrollbar_size,
+                sticky_offset: Point::ZERO,
+                location: table_relative_location - parent_offset(structure, child.parent),
+                padding,
+                border,
+                margin: resolved_margin,
+            },
+        );
+
+        // Fixed items are positioned relative to the viewport, so don't contribute to the content size of their parent
+        #[cfg(feature = "content_size")]
+        if !is_fixed {
+            absolute_content_size = absolute_content_size.f32_max(compute_content_size_contribution(
+                table_relative_location,
+                final_size,
+                layout_output.content_size,
+                overflow,
+            ));
+        }
+    }
+
+    absolute_content_size
+}
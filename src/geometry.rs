@@ -2,7 +2,7 @@
 
 use crate::util::sys::f32_max;
 use crate::CompactLength;
-use crate::{style::Dimension, util::sys::f32_min};
+use crate::{style::Dimension, style::WritingMode, util::sys::f32_min};
 use core::ops::{Add, Sub};
 
 #[cfg(feature = "flexbox")]
@@ -71,7 +71,10 @@ impl AbstractAxis {
     }
 
     /// Convert an `AbstractAxis` into an `AbsoluteAxis` naively assuming that the Inline axis is Horizontal
-    /// This is currently always true, but will change if Taffy ever implements the `writing_mode` property
+    ///
+    /// The layout algorithms always operate in the logical coordinate space of the container being laid out (in which
+    /// the inline axis is horizontal) so this conversion is correct within them. Use [`AbstractAxis::as_abs`] to take
+    /// a node's writing mode into account.
     #[inline]
     pub fn as_abs_naive(&self) -> AbsoluteAxis {
         match self {
@@ -79,6 +82,15 @@ impl AbstractAxis {
             AbstractAxis::Block => AbsoluteAxis::Vertical,
         }
     }
+
+    /// Convert an `AbstractAxis` into an `AbsoluteAxis` for a node with the specified writing mode
+    #[inline]
+    pub fn as_abs(&self, writing_mode: WritingMode) -> AbsoluteAxis {
+        match (self, writing_mode.is_vertical()) {
+            (AbstractAxis::Inline, false) | (AbstractAxis::Block, true) => AbsoluteAxis::Horizontal,
+            (AbstractAxis::Block, false) | (AbstractAxis::Inline, true) => AbsoluteAxis::Vertical,
+        }
+    }
 }
 
 /// Container that holds an item in each absolute axis without specifying
@@ -652,6 +664,15 @@ impl<U, T: Add<U>> Add<Point<U>> for Point<T> {
     }
 }
 
+// Generic Sub impl for Point<T> - Point<U> where T - U has an Sub impl
+impl<U, T: Sub<U>> Sub<Point<U>> for Point<T> {
+    type Output = Point<<T as Sub<U>>::Output>;
+
+    fn sub(self, rhs: Point<U>) -> Self::Output {
+        Point { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
 impl<T> Point<T> {
     /// Applies the function `f` to both the x and y
     ///
@@ -92,21 +92,28 @@ mod readme_doctest {
     #![doc = include_str!("../README.md")]
 }
 
-#[cfg(feature = "block_layout")]
-#[doc(inline)]
-pub use crate::compute::compute_block_layout;
 #[cfg(feature = "flexbox")]
 #[doc(inline)]
 pub use crate::compute::compute_flexbox_layout;
-#[cfg(feature = "grid")]
+#[cfg(feature = "table")]
 #[doc(inline)]
-pub use crate::compute::compute_grid_layout;
+pub use crate::compute::compute_table_layout;
 #[cfg(feature = "detailed_layout_info")]
 pub use crate::compute::detailed_info::*;
+#[cfg(feature = "block_layout")]
+#[doc(inline)]
+pub use crate::compute::{compute_block_layout, PlacedFloat};
 #[doc(inline)]
 pub use crate::compute::{
     compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    LeafMeasureOutput,
 };
+#[cfg(feature = "grid")]
+#[doc(inline)]
+pub use crate::compute::{compute_grid_layout, SubgridAxisTracks, SubgridTracks};
+#[cfg(feature = "inline_layout")]
+#[doc(inline)]
+pub use crate::compute::{InlineFragment, LineBox, ShapedTextRun, TextSegment};
 #[doc(inline)]
 pub use crate::style::Style;
 #[doc(inline)]
@@ -3,7 +3,7 @@
 pub use crate::{
     geometry::{Line, Rect, Size},
     style::{
-        AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, CompactLength, Dimension, Display,
+        AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, CompactLength, Dimension, Direction, Display,
         JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
     },
     style_helpers::{
@@ -18,13 +18,23 @@ pub use crate::style::{FlexDirection, FlexWrap};
 
 #[cfg(feature = "grid")]
 pub use crate::style::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
-    NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridTemplateArea, GridTrackRepetition, MasonryAutoFlow, MaxTrackSizingFunction,
+    MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 #[cfg(feature = "grid")]
 pub use crate::style_helpers::{
-    evenly_sized_tracks, flex, fr, line, minmax, repeat, span, TaffyGridLine, TaffyGridSpan,
+    evenly_sized_tracks, flex, fr, line, minmax, named_area, named_line, named_span, repeat, repeat_with_line_names,
+    span, TaffyGridLine, TaffyGridSpan,
 };
 
+#[cfg(feature = "block_layout")]
+pub use crate::style::{Clear, ColumnFill, ColumnSpan, Float};
+
+#[cfg(feature = "inline_layout")]
+pub use crate::style::DisplayOutside;
+
+#[cfg(feature = "table")]
+pub use crate::style::{TableLayout, TableRole};
+
 #[cfg(feature = "taffy_tree")]
 pub use crate::TaffyTree;
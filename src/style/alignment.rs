@@ -4,6 +4,10 @@
 /// For Flexbox it controls alignment in the cross axis
 /// For Grid it controls alignment in the block axis
 ///
+/// The `Safe` variants correspond to the CSS `safe` overflow alignment modifier: if the item overflows its alignment
+/// container then it is aligned as if the alignment were `Start`. All other variants use `unsafe` overflow alignment,
+/// which is also the default when no modifier is specified.
+///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/align-items)
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,9 +30,44 @@ pub enum AlignItems {
     Center,
     /// Items are aligned such as their baselines align
     Baseline,
+    /// Items are aligned such that their last baselines align
+    LastBaseline,
     /// Stretch to fill the container
     Stretch,
+    /// Like `Start`, but falls back to `Start` if the item overflows its alignment container
+    SafeStart,
+    /// Like `End`, but falls back to `Start` if the item overflows its alignment container
+    SafeEnd,
+    /// Like `FlexStart`, but falls back to `Start` if the item overflows its alignment container
+    SafeFlexStart,
+    /// Like `FlexEnd`, but falls back to `Start` if the item overflows its alignment container
+    SafeFlexEnd,
+    /// Like `Center`, but falls back to `Start` if the item overflows its alignment container
+    SafeCenter,
+}
+
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+impl AlignItems {
+    /// Swap the `Start` and `End` values, leaving all other values unchanged. This resolves the alignment of items
+    /// in an axis whose start edge is on the right (such as the inline axis of a right-to-left container).
+    #[inline(always)]
+    pub(crate) fn swap_start_end(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            Self::SafeStart => Self::SafeEnd,
+            Self::SafeEnd => Self::SafeStart,
+            other => other,
+        }
+    }
+
+    /// Whether this value uses `safe` overflow alignment
+    #[inline(always)]
+    pub(crate) fn is_safe(self) -> bool {
+        matches!(self, Self::SafeStart | Self::SafeEnd | Self::SafeFlexStart | Self::SafeFlexEnd | Self::SafeCenter)
+    }
 }
+
 /// Used to control how child nodes are aligned.
 /// Does not apply to Flexbox, and will be ignored if specified on a flex container
 /// For Grid it controls alignment in the inline axis
@@ -56,6 +95,10 @@ pub type JustifySelf = AlignItems;
 /// For Flexbox it controls alignment in the cross axis
 /// For Grid it controls alignment in the block axis
 ///
+/// The `Safe` variants correspond to the CSS `safe` overflow alignment modifier: if the items overflow the container
+/// then they are aligned as if the alignment were `Start`. All other variants use `unsafe` overflow alignment, which
+/// is also the default when no modifier is specified.
+///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/align-content)
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -87,6 +130,53 @@ pub enum AlignContent {
     /// The gap between the first and last items is exactly HALF the gap between items.
     /// The gaps are distributed evenly in proportion to these ratios.
     SpaceAround,
+    /// Like `Start`, but falls back to `Start` if the items overflow the container
+    SafeStart,
+    /// Like `End`, but falls back to `Start` if the items overflow the container
+    SafeEnd,
+    /// Like `FlexStart`, but falls back to `Start` if the items overflow the container
+    SafeFlexStart,
+    /// Like `FlexEnd`, but falls back to `Start` if the items overflow the container
+    SafeFlexEnd,
+    /// Like `Center`, but falls back to `Start` if the items overflow the container
+    SafeCenter,
+}
+
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+impl AlignContent {
+    /// Whether this value uses `safe` overflow alignment
+    #[inline(always)]
+    pub(crate) fn is_safe(self) -> bool {
+        matches!(self, Self::SafeStart | Self::SafeEnd | Self::SafeFlexStart | Self::SafeFlexEnd | Self::SafeCenter)
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl AlignContent {
+    /// Swap the `Start` and `End` values, leaving all other values unchanged. This resolves the alignment of content
+    /// in an axis whose start edge is on the right (such as the inline axis of a right-to-left container).
+    #[inline(always)]
+    pub(crate) fn swap_start_end(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            Self::SafeStart => Self::SafeEnd,
+            Self::SafeEnd => Self::SafeStart,
+            other => other,
+        }
+    }
+
+    /// Swap both the `Start` and `End` values and the `FlexStart` and `FlexEnd` values
+    #[inline(always)]
+    pub(crate) fn reversed(self) -> Self {
+        match self {
+            Self::FlexStart => Self::FlexEnd,
+            Self::FlexEnd => Self::FlexStart,
+            Self::SafeFlexStart => Self::SafeFlexEnd,
+            Self::SafeFlexEnd => Self::SafeFlexStart,
+            other => other.swap_start_end(),
+        }
+    }
 }
 
 /// Sets the distribution of space between and around content items
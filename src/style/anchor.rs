@@ -0,0 +1,82 @@
+//! Style types for CSS anchor positioning
+
+/// A position within the border box of an anchor, used by [`LengthPercentageAuto::anchor`](crate::LengthPercentageAuto::anchor)
+/// (the CSS `anchor()` function)
+///
+/// Sides are physical and refer to the axis of the inset that the value is used in: the start side is the left edge
+/// of the anchor for the `left` and `right` insets, and its top edge for the `top` and `bottom` insets.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/anchor>
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnchorSide {
+    /// The left or top edge of the anchor
+    Start,
+    /// The right or bottom edge of the anchor
+    End,
+    /// The center of the anchor
+    Center,
+    /// The edge of the anchor on the same side as the inset (e.g. its left edge for the `left` inset)
+    Inside,
+    /// The edge of the anchor on the opposite side to the inset (e.g. its right edge for the `left` inset)
+    Outside,
+    /// A position between the start (`0.0`) and end (`1.0`) edges of the anchor
+    Percent(f32),
+}
+
+/// The tracks spanned in one axis of a [`PositionArea`]
+///
+/// The edges of the anchor divide each axis of the containing block into three tracks: the start track (before the
+/// anchor), the center track (spanned by the anchor) and the end track (after the anchor). Tracks are physical: the
+/// start track is the left track in the horizontal axis, and the top track in the vertical axis.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PositionAreaSpan {
+    /// The track before the anchor. The node is aligned towards the anchor (to the end of the track).
+    Start,
+    /// The track spanned by the anchor. The node is centered within it.
+    Center,
+    /// The track after the anchor. The node is aligned towards the anchor (to the start of the track).
+    End,
+    /// The start and center tracks. The node is aligned to the end of the anchor.
+    SpanStart,
+    /// The center and end tracks. The node is aligned to the start of the anchor.
+    SpanEnd,
+    /// All three tracks. The node is centered on the anchor as far as the containing block allows.
+    SpanAll,
+}
+
+/// The area of the 3x3 grid formed around a node's anchor that the node is positioned in (the CSS `position-area` property)
+///
+/// The area is used as the node's containing block: its insets are resolved against it (with `auto` insets treated as
+/// zero), and an `auto` size is fitted to its content within it. The node is then aligned towards the anchor.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/position-area>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PositionArea {
+    /// The tracks spanned in the horizontal axis
+    pub x: PositionAreaSpan,
+    /// The tracks spanned in the vertical axis
+    pub y: PositionAreaSpan,
+}
+
+/// An alternative position for an anchor-positioned node, to be tried if the node overflows its containing block (an
+/// entry of the CSS `position-try-fallbacks` property)
+///
+/// Flipping an axis swaps the node's insets and margins in that axis, mirrors the anchor sides of its `anchor()` insets
+/// and flips its [`PositionArea`]. The block axis is the vertical axis unless the node has a vertical writing mode.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/position-try-fallbacks>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PositionTryFallback {
+    /// Flip the node to the other side of its anchor in the block axis (`flip-block`)
+    FlipBlock,
+    /// Flip the node to the other side of its anchor in the inline axis (`flip-inline`)
+    FlipInline,
+    /// Flip the node in both axes (`flip-block flip-inline`)
+    FlipBlockAndInline,
+    /// Position the node in the given area instead of its `position_area`
+    PositionArea(PositionArea),
+}
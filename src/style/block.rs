@@ -1,5 +1,5 @@
 //! Style types for Block layout
-use crate::{CoreStyle, Style};
+use crate::{CoreStyle, Dimension, LengthPercentage, Style};
 
 /// The set of styles required for a Block layout container
 pub trait BlockContainerStyle: CoreStyle {
@@ -8,6 +8,33 @@ pub trait BlockContainerStyle: CoreStyle {
     fn text_align(&self) -> TextAlign {
         Style::DEFAULT.text_align
     }
+
+    /// The number of columns the container's content should be split into. `None` corresponds to `auto`.
+    ///
+    /// The container is a multi-column container if either this or [`BlockContainerStyle::column_width`] is not auto.
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        Style::DEFAULT.column_count
+    }
+
+    /// The ideal width of the container's columns. The actual column width may be wider (to fill the container) or
+    /// narrower (if the container is narrower than a single column).
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        Style::DEFAULT.column_width
+    }
+
+    /// The size of the gap between the columns of a multi-column container
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        Style::DEFAULT.gap.width
+    }
+
+    /// How the content of a multi-column container is distributed between its columns
+    #[inline(always)]
+    fn column_fill(&self) -> ColumnFill {
+        Style::DEFAULT.column_fill
+    }
 }
 
 /// The set of styles required for a Block layout item (child of a Block container)
@@ -17,6 +44,31 @@ pub trait BlockItemStyle: CoreStyle {
     fn is_table(&self) -> bool {
         false
     }
+
+    /// Whether the item is floated to the left or right side of its container
+    #[inline(always)]
+    fn float(&self) -> Float {
+        Style::DEFAULT.float
+    }
+
+    /// Which floats the item must be placed below
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        Style::DEFAULT.clear
+    }
+
+    /// Whether the item spans across all of the columns of its parent multi-column container
+    #[inline(always)]
+    fn column_span(&self) -> ColumnSpan {
+        Style::DEFAULT.column_span
+    }
+
+    /// Whether the item participates in its parent's flow layout as a block-level box or as an inline-level box
+    #[cfg(feature = "inline_layout")]
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        Style::DEFAULT.display_outside
+    }
 }
 
 /// Used by block layout to implement the legacy behaviour of `<center>` and `<div align="left | right | center">`
@@ -33,3 +85,101 @@ pub enum TextAlign {
     /// Corresponds to `-webkit-center` or `-moz-center` in browsers
     LegacyCenter,
 }
+
+/// How the content of a multi-column container is distributed between its columns
+///
+/// Defaults to [`ColumnFill::Balance`]
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/column-fill)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColumnFill {
+    /// Content is distributed so that the columns are as close to the same height as possible
+    #[default]
+    Balance,
+    /// Columns are filled sequentially up to the height of the container. If the container's height is not
+    /// constrained then all of the content is placed into the first column.
+    Auto,
+}
+
+/// Whether a child of a multi-column container spans across all of its columns.
+///
+/// Spanning items (column spanners) split the container's content into separate sets of columns above and below the
+/// spanner. Has no effect on absolutely positioned or floated items.
+///
+/// Defaults to [`ColumnSpan::None`]
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/column-span)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColumnSpan {
+    /// The item is placed within a single column
+    #[default]
+    None,
+    /// The item spans across all columns
+    All,
+}
+
+/// Whether a child of a Block container is floated to one side of the container. Floated items are taken out of flow
+/// and placed as far to the left or right as possible. Subsequent in-flow items (and lines of inline-level content)
+/// are shortened to flow around them.
+///
+/// Floats only affect the other children of the same container, and a Block container's automatic height always
+/// grows to contain its floats. Has no effect on absolutely positioned items or on children of Flexbox and CSS Grid containers.
+///
+/// Defaults to [`Float::None`]
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/float)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Float {
+    /// The item is not floated
+    #[default]
+    None,
+    /// The item is floated to the left side of its container
+    Left,
+    /// The item is floated to the right side of its container
+    Right,
+}
+
+/// Whether a child of a Block container must be moved down below any preceding floats
+///
+/// Defaults to [`Clear::None`]
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/clear)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Clear {
+    /// The item is not moved down to clear floats
+    #[default]
+    None,
+    /// The item is moved down to clear left floats
+    Left,
+    /// The item is moved down to clear right floats
+    Right,
+    /// The item is moved down to clear both left and right floats
+    Both,
+}
+
+/// Controls whether a node participates in its parent's flow layout as a block-level box or as an inline-level box.
+/// This corresponds to the "outer" display type of the CSS `display` property (e.g. the `inline` in `inline-flex`).
+///
+/// Only has an effect on the children of Block containers. The children of Flexbox and CSS Grid containers are always block-level.
+///
+/// Defaults to [`DisplayOutside::Block`]
+///
+/// [Specification](https://www.w3.org/TR/css-display-3/#outer-role)
+#[cfg(feature = "inline_layout")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DisplayOutside {
+    /// The node generates a block-level box, which is stacked vertically with its siblings
+    #[default]
+    Block,
+    /// The node generates an inline-level box, which is laid out into line boxes with its inline-level siblings.
+    ///
+    /// Inline-level leaf nodes are treated as text runs if the tree is able to shape them
+    /// (see [`LayoutBlockContainer::shape_text_run`](crate::LayoutBlockContainer::shape_text_run)). All other
+    /// inline-level nodes are laid out as atomic inline boxes (like `inline-block`, `inline-flex` or an image).
+    Inline,
+}
@@ -1,6 +1,6 @@
 //! A tagged-pointer abstraction that allows size styles in Taffy to be represented
 //! in just 64 bits. Wrapped by types in the `super::dimension` and `super::grid` modules.
-use super::LengthPercentage;
+use super::{AnchorSide, LengthPercentage};
 use crate::style_helpers::{
     FromFr, FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
 };
@@ -228,6 +228,14 @@ impl CompactLength {
     pub const FIT_CONTENT_PX_TAG: usize = 0b00010111;
     /// The tag indicating a fit-content value with percent limit
     pub const FIT_CONTENT_PERCENT_TAG: usize = 0b00011111;
+    /// The tag indicating a stretch value
+    pub const STRETCH_TAG: usize = 0b00100111;
+    /// The tag indicating an `anchor()` value at a fraction of the way between the start and end edges of the anchor
+    pub const ANCHOR_TAG: usize = 0b00101111;
+    /// The tag indicating an `anchor(inside)` value
+    pub const ANCHOR_INSIDE_TAG: usize = 0b00110111;
+    /// The tag indicating an `anchor(outside)` value
+    pub const ANCHOR_OUTSIDE_TAG: usize = 0b00111111;
 }
 
 impl CompactLength {
@@ -315,6 +323,25 @@ impl CompactLength {
         Self(CompactLengthInner::from_val(limit, Self::FIT_CONTENT_PERCENT_TAG))
     }
 
+    /// The size should fill the available space in the containing block (minus the box's margins)
+    #[inline(always)]
+    pub const fn stretch() -> Self {
+        Self(CompactLengthInner::from_tag(Self::STRETCH_TAG))
+    }
+
+    /// A position within the border box of the node's anchor (the CSS `anchor()` function). Only valid for insets.
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        match side {
+            AnchorSide::Start => Self(CompactLengthInner::from_val(0.0, Self::ANCHOR_TAG)),
+            AnchorSide::End => Self(CompactLengthInner::from_val(1.0, Self::ANCHOR_TAG)),
+            AnchorSide::Center => Self(CompactLengthInner::from_val(0.5, Self::ANCHOR_TAG)),
+            AnchorSide::Percent(fraction) => Self(CompactLengthInner::from_val(fraction, Self::ANCHOR_TAG)),
+            AnchorSide::Inside => Self(CompactLengthInner::from_tag(Self::ANCHOR_INSIDE_TAG)),
+            AnchorSide::Outside => Self(CompactLengthInner::from_tag(Self::ANCHOR_OUTSIDE_TAG)),
+        }
+    }
+
     /// Get the primary tag
     #[inline(always)]
     pub fn tag(self) -> usize {
@@ -384,6 +411,18 @@ impl CompactLength {
         matches!(self.tag(), Self::MAX_CONTENT_TAG | Self::FIT_CONTENT_PX_TAG | Self::FIT_CONTENT_PERCENT_TAG)
     }
 
+    /// Returns true if the value is stretch
+    #[inline(always)]
+    pub fn is_stretch(self) -> bool {
+        self.tag() == Self::STRETCH_TAG
+    }
+
+    /// Returns true if the value is an `anchor()` value
+    #[inline(always)]
+    pub fn is_anchor(self) -> bool {
+        matches!(self.tag(), Self::ANCHOR_TAG | Self::ANCHOR_INSIDE_TAG | Self::ANCHOR_OUTSIDE_TAG)
+    }
+
     /// Returns true if the max track sizing function is `MaxContent`, `FitContent` or `Auto` else false.
     /// "In all cases, treat auto and fit-content() as max-content, except where specified otherwise for fit-content()."
     /// See: <https://www.w3.org/TR/css-grid-1/#algo-terms>
@@ -530,6 +569,10 @@ impl<'de> serde::Deserialize<'de> for CompactLength {
                 | CompactLength::FIT_CONTENT_PX_TAG
                 | CompactLength::FIT_CONTENT_PERCENT_TAG
                 | CompactLength::FR_TAG
+                | CompactLength::STRETCH_TAG
+                | CompactLength::ANCHOR_TAG
+                | CompactLength::ANCHOR_INSIDE_TAG
+                | CompactLength::ANCHOR_OUTSIDE_TAG
         ) {
             Ok(value)
         } else {
@@ -1,7 +1,9 @@
 //! Style types for representing lengths / sizes
-use super::CompactLength;
+use super::{AnchorSide, CompactLength};
 use crate::geometry::Rect;
-use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyZero};
+use crate::style_helpers::{
+    FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+};
 
 /// A unit of linear measurement
 ///
@@ -139,6 +141,15 @@ impl LengthPercentageAuto {
         Self(CompactLength::calc(ptr))
     }
 
+    /// A position within the border box of the node's anchor (the CSS `anchor()` function). Only valid for insets.
+    ///
+    /// `anchor()` insets are resolved by [`TaffyTree`](crate::TaffyTree) for absolutely positioned nodes with a
+    /// `position_anchor`, once the anchor has been laid out. They are otherwise treated as `auto`.
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        Self(CompactLength::anchor(side))
+    }
+
     /// Create a LengthPercentageAuto from a raw `CompactLength`.
     /// # Safety
     /// CompactLength must represent a valid variant for LengthPercentageAuto
@@ -162,6 +173,7 @@ impl LengthPercentageAuto {
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => Some(context * self.0.value()),
             CompactLength::AUTO_TAG => None,
+            _ if self.0.is_anchor() => None,
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => Some(calc_resolver(self.0.calc_value(), context)),
             _ => unreachable!("LengthPercentageAuto values cannot be constructed with other tags"),
@@ -173,6 +185,30 @@ impl LengthPercentageAuto {
     pub fn is_auto(self) -> bool {
         self.0.is_auto()
     }
+
+    /// Returns the position within the anchor for `anchor()` values
+    #[cfg(all(
+        feature = "taffy_tree",
+        any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table")
+    ))]
+    pub(crate) fn anchor_side(self) -> Option<AnchorSide> {
+        match self.0.tag() {
+            CompactLength::ANCHOR_TAG => Some(AnchorSide::Percent(self.0.value())),
+            CompactLength::ANCHOR_INSIDE_TAG => Some(AnchorSide::Inside),
+            CompactLength::ANCHOR_OUTSIDE_TAG => Some(AnchorSide::Outside),
+            _ => None,
+        }
+    }
+
+    /// The underlying `CompactLength` representation of the value, with `anchor()` values replaced by `auto`
+    #[inline(always)]
+    pub(crate) fn into_raw_without_anchor(self) -> CompactLength {
+        if self.0.is_anchor() {
+            CompactLength::auto()
+        } else {
+            self.0
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -183,7 +219,9 @@ impl<'de> serde::Deserialize<'de> for LengthPercentageAuto {
     {
         let inner = CompactLength::deserialize(deserializer)?;
         // Note: validation intentionally excludes the CALC_TAG as deserializing calc() values is not supported
-        if matches!(inner.tag(), CompactLength::LENGTH_TAG | CompactLength::PERCENT_TAG | CompactLength::AUTO_TAG) {
+        if matches!(inner.tag(), CompactLength::LENGTH_TAG | CompactLength::PERCENT_TAG | CompactLength::AUTO_TAG)
+            || inner.is_anchor()
+        {
             Ok(Self(inner))
         } else {
             Err(serde::de::Error::custom("Invalid tag"))
@@ -213,6 +251,17 @@ impl FromPercent for Dimension {
         Self::percent(value.into())
     }
 }
+impl TaffyMinContent for Dimension {
+    const MIN_CONTENT: Self = Self(CompactLength::MIN_CONTENT);
+}
+impl TaffyMaxContent for Dimension {
+    const MAX_CONTENT: Self = Self(CompactLength::MAX_CONTENT);
+}
+impl TaffyFitContent for Dimension {
+    fn fit_content(argument: LengthPercentage) -> Self {
+        Self(CompactLength::fit_content(argument))
+    }
+}
 impl From<LengthPercentage> for Dimension {
     fn from(input: LengthPercentage) -> Self {
         Self(input.0)
@@ -220,7 +269,7 @@ impl From<LengthPercentage> for Dimension {
 }
 impl From<LengthPercentageAuto> for Dimension {
     fn from(input: LengthPercentageAuto) -> Self {
-        Self(input.0)
+        Self(input.into_raw_without_anchor())
     }
 }
 
@@ -247,6 +296,55 @@ impl Dimension {
         Self(CompactLength::auto())
     }
 
+    /// The size should be the "min-content" size.
+    /// This is the smallest size that can fit the item's contents with ALL soft line-wrapping opportunities taken
+    #[inline(always)]
+    pub const fn min_content() -> Self {
+        Self(CompactLength::min_content())
+    }
+
+    /// The size should be the "max-content" size.
+    /// This is the smallest size that can fit the item's contents with NO soft line-wrapping opportunities taken
+    #[inline(always)]
+    pub const fn max_content() -> Self {
+        Self(CompactLength::max_content())
+    }
+
+    /// The size should be computed according to the "fit content" formula:
+    ///    `max(min_content, min(max_content, limit))`
+    /// where:
+    ///    - `min_content` is the [min-content](Self::min_content) size
+    ///    - `max_content` is the [max-content](Self::max_content) size
+    ///    - `limit` is a LENGTH value passed to this function
+    ///
+    /// The effect of this is that the item takes the size of `limit` clamped
+    /// by the min-content and max-content sizes.
+    #[inline(always)]
+    pub const fn fit_content_px(limit: f32) -> Self {
+        Self(CompactLength::fit_content_px(limit))
+    }
+
+    /// The size should be computed according to the "fit content" formula:
+    ///    `max(min_content, min(max_content, limit))`
+    /// where:
+    ///    - `min_content` is the [min-content](Self::min_content) size
+    ///    - `max_content` is the [max-content](Self::max_content) size
+    ///    - `limit` is a PERCENTAGE value passed to this function
+    ///
+    /// The effect of this is that the item takes the size of `limit` clamped
+    /// by the min-content and max-content sizes.
+    #[inline(always)]
+    pub const fn fit_content_percent(limit: f32) -> Self {
+        Self(CompactLength::fit_content_percent(limit))
+    }
+
+    /// The size should fill the available space in the containing block. This is the size of the containing block
+    /// minus the box's margins (and, for absolutely positioned boxes, minus its insets).
+    #[inline(always)]
+    pub const fn stretch() -> Self {
+        Self(CompactLength::stretch())
+    }
+
     /// A `calc()` value. The value passed here is treated as an opaque handle to
     /// the actual calc representation and may be a pointer, index, etc.
     ///
@@ -257,6 +355,15 @@ impl Dimension {
         Self(CompactLength::calc(ptr))
     }
 
+    /// A position within the border box of the node's anchor (the CSS `anchor()` function). Only valid for insets.
+    ///
+    /// `anchor()` insets are resolved by [`TaffyTree`](crate::TaffyTree) for absolutely positioned nodes with a
+    /// `position_anchor`, once the anchor has been laid out. They are otherwise treated as `auto`.
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        Self(CompactLength::anchor(side))
+    }
+
     /// Create a LengthPercentageAuto from a raw `CompactLength`.
     /// # Safety
     /// CompactLength must represent a valid variant for LengthPercentageAuto
@@ -284,6 +391,18 @@ impl Dimension {
         self.0.is_auto()
     }
 
+    /// Returns true if value is an intrinsic sizing keyword (min-content, max-content or fit-content)
+    #[inline(always)]
+    pub fn is_intrinsic_sizing_keyword(self) -> bool {
+        self.0.is_min_or_max_content() || self.0.is_fit_content()
+    }
+
+    /// Returns true if value is Stretch
+    #[inline(always)]
+    pub fn is_stretch(self) -> bool {
+        self.0.is_stretch()
+    }
+
     /// Get the raw `CompactLength` tag
     pub fn tag(self) -> usize {
         self.0.tag()
@@ -303,7 +422,17 @@ impl<'de> serde::Deserialize<'de> for Dimension {
     {
         let inner = CompactLength::deserialize(deserializer)?;
         // Note: validation intentionally excludes the CALC_TAG as deserializing calc() values is not supported
-        if matches!(inner.tag(), CompactLength::LENGTH_TAG | CompactLength::PERCENT_TAG | CompactLength::AUTO_TAG) {
+        if matches!(
+            inner.tag(),
+            CompactLength::LENGTH_TAG
+                | CompactLength::PERCENT_TAG
+                | CompactLength::AUTO_TAG
+                | CompactLength::MIN_CONTENT_TAG
+                | CompactLength::MAX_CONTENT_TAG
+                | CompactLength::FIT_CONTENT_PX_TAG
+                | CompactLength::FIT_CONTENT_PERCENT_TAG
+                | CompactLength::STRETCH_TAG
+        ) {
             Ok(Self(inner))
         } else {
             Err(serde::de::Error::custom("Invalid tag"))
@@ -64,6 +64,12 @@ pub trait FlexboxItemStyle: CoreStyle {
     fn align_self(&self) -> Option<AlignSelf> {
         Style::DEFAULT.align_self
     }
+    /// The order in which this item is laid out relative to its siblings (items with the same order are laid out
+    /// in tree order)
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        Style::DEFAULT.order
+    }
 }
 
 use crate::geometry::AbsoluteAxis;
@@ -6,7 +6,7 @@ use super::{
 use crate::compute::grid::{GridCoordinate, GridLine, OriginZeroLine};
 use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, MinMax, Size};
 use crate::style_helpers::*;
-use crate::util::sys::GridTrackVec;
+use crate::util::sys::{GridTrackVec, String};
 use core::borrow::Borrow;
 use core::cmp::{max, min};
 use core::convert::Infallible;
@@ -29,17 +29,59 @@ pub trait GridContainerStyle: CoreStyle {
     fn grid_template_rows(&self) -> Self::TemplateTrackList<'_>;
     /// Defines the track sizing functions (widths) of the grid columns
     fn grid_template_columns(&self) -> Self::TemplateTrackList<'_>;
+    /// The names of the lines between the grid rows
+    #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<String>] {
+        &[]
+    }
+    /// The names of the lines between the grid columns
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<String>] {
+        &[]
+    }
+    /// The named grid areas
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea] {
+        &[]
+    }
     /// Defines the size of implicitly created rows
     fn grid_auto_rows(&self) -> Self::AutoTrackList<'_>;
     /// Defined the size of implicitly created columns
     fn grid_auto_columns(&self) -> Self::AutoTrackList<'_>;
 
+    /// Whether the grid's rows are a subgrid of its parent grid's rows
+    #[inline(always)]
+    fn subgrid_rows(&self) -> bool {
+        Style::DEFAULT.subgrid_rows
+    }
+    /// Whether the grid's columns are a subgrid of its parent grid's columns
+    #[inline(always)]
+    fn subgrid_columns(&self) -> bool {
+        Style::DEFAULT.subgrid_columns
+    }
+
     /// Controls how items get placed into the grid for auto-placed items
     #[inline(always)]
     fn grid_auto_flow(&self) -> GridAutoFlow {
         Style::DEFAULT.grid_auto_flow
     }
 
+    /// Whether the grid's rows are a masonry axis
+    #[inline(always)]
+    fn masonry_rows(&self) -> bool {
+        Style::DEFAULT.masonry_rows
+    }
+    /// Whether the grid's columns are a masonry axis
+    #[inline(always)]
+    fn masonry_columns(&self) -> bool {
+        Style::DEFAULT.masonry_columns
+    }
+    /// Controls how items get placed into the tracks of a masonry grid
+    #[inline(always)]
+    fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        Style::DEFAULT.masonry_auto_flow
+    }
+
     /// How large should the gaps between items in a grid or flex container be?
     #[inline(always)]
     fn gap(&self) -> Size<LengthPercentage> {
@@ -68,6 +110,28 @@ pub trait GridContainerStyle: CoreStyle {
     fn justify_items(&self) -> Option<AlignItems> {
         Style::DEFAULT.justify_items
     }
+    /// How the items in each track of a masonry grid should be aligned in the block axis
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        Style::DEFAULT.align_tracks
+    }
+    /// How the items in each track of a masonry grid should be aligned in the inline axis
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<AlignContent> {
+        Style::DEFAULT.justify_tracks
+    }
+
+    /// The masonry axis of the grid (if any). If both axes are set to be masonry axes, then the rows are used.
+    #[inline(always)]
+    fn masonry_axis(&self) -> Option<AbsoluteAxis> {
+        if self.masonry_rows() {
+            Some(AbsoluteAxis::Vertical)
+        } else if self.masonry_columns() {
+            Some(AbsoluteAxis::Horizontal)
+        } else {
+            None
+        }
+    }
 
     /// Get a grid item's row or column placement depending on the axis passed
     #[inline(always)]
@@ -78,6 +142,15 @@ pub trait GridContainerStyle: CoreStyle {
         }
     }
 
+    /// Get a grid container's row or column line names depending on the axis passed
+    #[inline(always)]
+    fn grid_template_line_names(&self, axis: AbsoluteAxis) -> &[GridTrackVec<String>] {
+        match axis {
+            AbsoluteAxis::Horizontal => self.grid_template_column_names(),
+            AbsoluteAxis::Vertical => self.grid_template_row_names(),
+        }
+    }
+
     /// Get a grid container's align-content or justify-content alignment depending on the axis passed
     #[inline(always)]
     fn grid_align_content(&self, axis: AbstractAxis) -> AlignContent {
@@ -113,6 +186,19 @@ pub trait GridItemStyle: CoreStyle {
     fn justify_self(&self) -> Option<AlignSelf> {
         Style::DEFAULT.justify_self
     }
+    /// The order in which this item is placed relative to its siblings (items with the same order are placed
+    /// in tree order)
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        Style::DEFAULT.order
+    }
+
+    /// Whether the item is itself a grid container whose tracks in the specified axis are a subgrid
+    /// of this grid's tracks
+    #[inline(always)]
+    fn is_subgrid(&self, _axis: AbsoluteAxis) -> bool {
+        false
+    }
 
     /// Get a grid item's row or column placement depending on the axis passed
     #[inline(always)]
@@ -170,11 +256,48 @@ impl GridAutoFlow {
     }
 }
 
+/// Controls how items are placed into the tracks of a masonry grid, and the order in which they are placed
+///
+/// Defaults to [`MasonryAutoFlow::Pack`]
+///
+/// [Specification](https://drafts.csswg.org/css-grid-3/#masonry-auto-flow)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MasonryAutoFlow {
+    /// Items are placed into the track(s) with the most space available. Items with a definite placement are placed first.
+    #[default]
+    Pack,
+    /// Items are placed into the track(s) following the previously placed item. Items with a definite placement are placed first.
+    Next,
+    /// Combines `Pack` with placing all items in order
+    PackOrdered,
+    /// Combines `Next` with placing all items in order
+    NextOrdered,
+}
+
+impl MasonryAutoFlow {
+    /// Whether items are placed into the track(s) with the most space available rather than the following track(s)
+    pub fn is_pack(&self) -> bool {
+        match self {
+            Self::Pack | Self::PackOrdered => true,
+            Self::Next | Self::NextOrdered => false,
+        }
+    }
+
+    /// Whether items are placed in order (rather than placing items with a definite placement first)
+    pub fn is_ordered(&self) -> bool {
+        match self {
+            Self::Pack | Self::Next => false,
+            Self::PackOrdered | Self::NextOrdered => true,
+        }
+    }
+}
+
 /// A grid line placement specification which is generic over the coordinate system that it uses to define
 /// grid line positions.
 ///
-/// GenericGridPlacement<GridLine> is aliased as GridPlacement and is exposed to users of Taffy to define styles.
-/// GenericGridPlacement<OriginZeroLine> is aliased as OriginZeroGridPlacement and is used internally for placement computations.
+/// GenericGridPlacement<OriginZeroLine> is aliased as OriginZeroGridPlacement and is used internally for placement computations,
+/// once any named lines in the [`GridPlacement`] that is exposed to users of Taffy have been resolved.
 ///
 /// See [`crate::compute::grid::type::coordinates`] for documentation on the different coordinate systems.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -191,12 +314,28 @@ pub enum GenericGridPlacement<LineType: GridCoordinate> {
 /// A grid line placement using the normalized OriginZero coordinates to specify line positions.
 pub(crate) type OriginZeroGridPlacement = GenericGridPlacement<OriginZeroLine>;
 
-/// A grid line placement specification. Used for grid-[row/column]-[start/end]. Named tracks are not implemented.
+/// A grid line placement specification. Used for grid-[row/column]-[start/end].
 ///
 /// Defaults to `GridPlacement::Auto`
 ///
 /// [Specification](https://www.w3.org/TR/css3-grid-layout/#typedef-grid-row-start-grid-line)
-pub type GridPlacement = GenericGridPlacement<GridLine>;
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GridPlacement {
+    /// Place item according to the auto-placement algorithm, and the parent's grid_auto_flow property
+    Auto,
+    /// Place item at specified line (column or row) index
+    Line(GridLine),
+    /// Place item at the nth line with the specified name. Negative indices count backwards from the end of the explicit grid.
+    ///
+    /// An index of zero means that no index was specified (`grid-row-start: header`). In that case the item is placed at
+    /// the `header-start` line (or `header-end` line for an end placement) if there is one, and at the first `header` line otherwise.
+    NamedLine(String, i16),
+    /// Item should span specified number of tracks (columns or rows)
+    Span(u16),
+    /// Item should span until the nth line with the specified name, counting from the line at the other end of the placement
+    NamedSpan(String, u16),
+}
 impl TaffyAuto for GridPlacement {
     const AUTO: Self = Self::Auto;
 }
@@ -227,22 +366,6 @@ impl Default for GridPlacement {
     }
 }
 
-impl GridPlacement {
-    /// Apply a mapping function if the [`GridPlacement`] is a `Track`. Otherwise return `self` unmodified.
-    pub fn into_origin_zero_placement(self, explicit_track_count: u16) -> OriginZeroGridPlacement {
-        match self {
-            Self::Auto => OriginZeroGridPlacement::Auto,
-            Self::Span(span) => OriginZeroGridPlacement::Span(span),
-            // Grid line zero is an invalid index, so it gets treated as Auto
-            // See: https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start#values
-            Self::Line(line) => match line.as_i16() {
-                0 => OriginZeroGridPlacement::Auto,
-                _ => OriginZeroGridPlacement::Line(line.into_origin_zero_line(explicit_track_count)),
-            },
-        }
-    }
-}
-
 impl<T: GridCoordinate> Line<GenericGridPlacement<T>> {
     /// Resolves the span for an indefinite placement (a placement that does not consist of two `Track`s).
     /// Panics if called on a definite placement
@@ -266,22 +389,15 @@ impl Line<GridPlacement> {
     #[inline]
     /// Whether the track position is definite in this axis (or the item will need auto placement)
     /// The track position is definite if least one of the start and end positions is a NON-ZERO track index
-    /// (0 is an invalid line in GridLine coordinates, and falls back to "auto" which is indefinite)
+    /// or a named line (0 is an invalid line in GridLine coordinates, and falls back to "auto" which is indefinite)
     pub fn is_definite(&self) -> bool {
-        match (self.start, self.end) {
-            (GenericGridPlacement::Line(line), _) if line.as_i16() != 0 => true,
-            (_, GenericGridPlacement::Line(line)) if line.as_i16() != 0 => true,
+        match (&self.start, &self.end) {
+            (GridPlacement::Line(line), _) if line.as_i16() != 0 => true,
+            (_, GridPlacement::Line(line)) if line.as_i16() != 0 => true,
+            (GridPlacement::NamedLine(..), _) | (_, GridPlacement::NamedLine(..)) => true,
             _ => false,
         }
     }
-
-    /// Apply a mapping function if the [`GridPlacement`] is a `Track`. Otherwise return `self` unmodified.
-    pub fn into_origin_zero(&self, explicit_track_count: u16) -> Line<OriginZeroGridPlacement> {
-        Line {
-            start: self.start.into_origin_zero_placement(explicit_track_count),
-            end: self.end.into_origin_zero_placement(explicit_track_count),
-        }
-    }
 }
 
 impl Line<OriginZeroGridPlacement> {
@@ -407,7 +523,7 @@ impl From<LengthPercentage> for MaxTrackSizingFunction {
 }
 impl From<LengthPercentageAuto> for MaxTrackSizingFunction {
     fn from(input: LengthPercentageAuto) -> Self {
-        Self(input.0)
+        Self(input.into_raw_without_anchor())
     }
 }
 impl From<Dimension> for MaxTrackSizingFunction {
@@ -696,7 +812,7 @@ impl From<LengthPercentage> for MinTrackSizingFunction {
 }
 impl From<LengthPercentageAuto> for MinTrackSizingFunction {
     fn from(input: LengthPercentageAuto) -> Self {
-        Self(input.0)
+        Self(input.into_raw_without_anchor())
     }
 }
 impl From<Dimension> for MinTrackSizingFunction {
@@ -991,12 +1107,15 @@ pub enum TrackSizingFunction {
     Single(NonRepeatedTrackSizingFunction),
     /// Automatically generate grid tracks to fit the available space using the specified definite track lengths
     /// Only valid if every track in template (not just the repetition) has a fixed size.
-    Repeat(GridTrackRepetition, GridTrackVec<NonRepeatedTrackSizingFunction>),
+    ///
+    /// The final field contains the names of the lines within each repetition: entry `i` contains the names of the line
+    /// before the `i`th repeated track, and the final entry contains the names of the line after the last repeated track.
+    Repeat(GridTrackRepetition, GridTrackVec<NonRepeatedTrackSizingFunction>, GridTrackVec<GridTrackVec<String>>),
 }
 impl TrackSizingFunction {
     /// Whether the track definition is a auto-repeated fragment
     pub fn is_auto_repetition(&self) -> bool {
-        matches!(self, Self::Repeat(GridTrackRepetition::AutoFit | GridTrackRepetition::AutoFill, _))
+        matches!(self, Self::Repeat(GridTrackRepetition::AutoFit | GridTrackRepetition::AutoFill, _, _))
     }
 }
 impl TaffyAuto for TrackSizingFunction {
@@ -1036,3 +1155,117 @@ impl From<MinMax<MinTrackSizingFunction, MaxTrackSizingFunction>> for TrackSizin
         Self::Single(input)
     }
 }
+
+/// A named grid area: a rectangle of grid cells that grid items can be placed into by name. Each area implicitly names
+/// the lines at its edges `<name>-start` and `<name>-end`, so an item can be placed into an area named `main` using
+/// `named_line("main", 0)` as both its row and column placements.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-areas>
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridTemplateArea {
+    /// The name of the area
+    pub name: String,
+    /// The row line at which the area starts (in CSS Grid Line coordinates, starting from 1)
+    pub row_start: u16,
+    /// The row line at which the area ends
+    pub row_end: u16,
+    /// The column line at which the area starts (in CSS Grid Line coordinates, starting from 1)
+    pub column_start: u16,
+    /// The column line at which the area ends
+    pub column_end: u16,
+}
+
+impl GridTemplateArea {
+    /// The lines at the start and end of the area in the specified axis
+    pub fn lines(&self, axis: AbsoluteAxis) -> Line<u16> {
+        match axis {
+            AbsoluteAxis::Horizontal => Line { start: self.column_start, end: self.column_end },
+            AbsoluteAxis::Vertical => Line { start: self.row_start, end: self.row_end },
+        }
+    }
+
+    /// Parse the areas from the rows of a `grid-template-areas` definition. Each row is a string of whitespace-separated
+    /// cell names, where a sequence of one or more `.` characters is a cell that does not belong to any area.
+    ///
+    /// ```
+    /// # use taffy::style::GridTemplateArea;
+    /// let areas = GridTemplateArea::parse(&["header header", "sidebar main", ". footer"]).unwrap();
+    /// assert_eq!(areas.len(), 4);
+    /// assert_eq!((areas[0].column_start, areas[0].column_end), (1, 3));
+    /// ```
+    pub fn parse(rows: &[&str]) -> Result<GridTrackVec<GridTemplateArea>, InvalidGridTemplateAreas> {
+        let mut areas: GridTrackVec<GridTemplateArea> = GridTrackVec::new();
+        let mut cell_counts: GridTrackVec<u16> = GridTrackVec::new();
+        let mut column_count = None;
+
+        for (row_index, row) in rows.iter().enumerate() {
+            let row_line = row_index as u16 + 1;
+            let mut row_cell_count = 0;
+            for (column_index, cell) in row.split_whitespace().enumerate() {
+                row_cell_count += 1;
+                if cell.chars().all(|c| c == '.') {
+                    continue;
+                }
+
+                let column_line = column_index as u16 + 1;
+                match areas.iter().position(|area| area.name == cell) {
+                    Some(index) => {
+                        let area = &mut areas[index];
+                        area.row_start = min(area.row_start, row_line);
+                        area.row_end = max(area.row_end, row_line + 1);
+                        area.column_start = min(area.column_start, column_line);
+                        area.column_end = max(area.column_end, column_line + 1);
+                        cell_counts[index] += 1;
+                    }
+                    None => {
+                        areas.push(GridTemplateArea {
+                            name: cell.into(),
+                            row_start: row_line,
+                            row_end: row_line + 1,
+                            column_start: column_line,
+                            column_end: column_line + 1,
+                        });
+                        cell_counts.push(1);
+                    }
+                }
+            }
+
+            // Every row must contain the same (non-zero) number of cells
+            if row_cell_count == 0 || *column_count.get_or_insert(row_cell_count) != row_cell_count {
+                return Err(InvalidGridTemplateAreas::InconsistentRowLength);
+            }
+        }
+
+        // The cells of each area must form a filled rectangle
+        for (area, cell_count) in areas.iter().zip(cell_counts) {
+            let rectangle_cell_count = (area.row_end - area.row_start) * (area.column_end - area.column_start);
+            if rectangle_cell_count != cell_count {
+                return Err(InvalidGridTemplateAreas::NonRectangularArea(area.name.clone()));
+            }
+        }
+
+        Ok(areas)
+    }
+}
+
+/// Error returned when trying to parse the rows of a `grid-template-areas` definition that is invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidGridTemplateAreas {
+    /// A row contains no cells, or a different number of cells to the previous rows
+    InconsistentRowLength,
+    /// The cells with the specified name do not form a filled rectangle
+    NonRectangularArea(String),
+}
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidGridTemplateAreas {}
+impl core::fmt::Display for InvalidGridTemplateAreas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InconsistentRowLength => {
+                f.write_str("every row of grid-template-areas must contain the same number of cells")
+            }
+            Self::NonRectangularArea(name) => write!(f, "the grid area '{name}' is not a rectangle"),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! A typed representation of [CSS style properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust. Used as input to layout computation.
 mod alignment;
+mod anchor;
 mod available_space;
 mod compact_length;
 mod dimension;
@@ -10,33 +11,45 @@ mod block;
 mod flex;
 #[cfg(feature = "grid")]
 mod grid;
+#[cfg(feature = "table")]
+mod table;
 
 pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf};
+pub use self::anchor::{AnchorSide, PositionArea, PositionAreaSpan, PositionTryFallback};
 pub use self::available_space::AvailableSpace;
 pub use self::compact_length::CompactLength;
 pub use self::dimension::{Dimension, LengthPercentage, LengthPercentageAuto};
 
+#[cfg(feature = "inline_layout")]
+pub use self::block::DisplayOutside;
 #[cfg(feature = "block_layout")]
-pub use self::block::{BlockContainerStyle, BlockItemStyle, TextAlign};
+pub use self::block::{BlockContainerStyle, BlockItemStyle, Clear, ColumnFill, ColumnSpan, Float, TextAlign};
 #[cfg(feature = "flexbox")]
 pub use self::flex::{FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "grid")]
 pub(crate) use self::grid::{GenericGridPlacement, OriginZeroGridPlacement};
 #[cfg(feature = "grid")]
 pub use self::grid::{
-    GridAutoFlow, GridContainerStyle, GridItemStyle, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction,
-    MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    GridAutoFlow, GridContainerStyle, GridItemStyle, GridPlacement, GridTemplateArea, GridTrackRepetition,
+    InvalidGridTemplateAreas, MasonryAutoFlow, MaxTrackSizingFunction, MinTrackSizingFunction,
+    NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
+#[cfg(feature = "table")]
+pub use self::table::{TableContainerStyle, TableItemStyle, TableLayout, TableRole};
 
 use crate::geometry::{Point, Rect, Size};
 use crate::style_helpers::TaffyAuto as _;
 
 #[cfg(feature = "grid")]
-use crate::geometry::Line;
+use crate::geometry::{AbsoluteAxis, Line};
 #[cfg(feature = "serde")]
 use crate::style_helpers;
 #[cfg(feature = "grid")]
 use crate::util::sys::GridTrackVec;
+#[cfg(any(feature = "grid", feature = "taffy_tree"))]
+use crate::util::sys::String;
+#[cfg(feature = "taffy_tree")]
+use crate::util::sys::Vec;
 
 /// The core set of styles that are shared between all CSS layout nodes
 ///
@@ -65,6 +78,21 @@ pub trait CoreStyle {
     fn box_sizing(&self) -> BoxSizing {
         BoxSizing::BorderBox
     }
+    /// The inline base direction of this node. `None` means that the direction is inherited from the parent
+    #[inline(always)]
+    fn direction(&self) -> Option<Direction> {
+        Style::DEFAULT.direction
+    }
+    /// The writing mode of this node. `None` means that the writing mode is inherited from the parent
+    #[inline(always)]
+    fn writing_mode(&self) -> Option<WritingMode> {
+        Style::DEFAULT.writing_mode
+    }
+    /// Whether the node is visible. Only [`Visibility::Collapse`] affects layout.
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        Style::DEFAULT.visibility
+    }
 
     // Overflow properties
     /// How children overflowing their container should affect layout
@@ -77,6 +105,11 @@ pub trait CoreStyle {
     fn scrollbar_width(&self) -> f32 {
         0.0
     }
+    /// Whether space for the scrollbar gutter should be reserved even if the node has no scrollbar, or never
+    #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        Style::DEFAULT.scrollbar_gutter
+    }
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
@@ -112,6 +145,12 @@ pub trait CoreStyle {
     fn aspect_ratio(&self) -> Option<f32> {
         Style::DEFAULT.aspect_ratio
     }
+    /// Whether the aspect ratio was specified together with `auto` (`aspect-ratio: auto <ratio>`), in which case
+    /// a natural aspect ratio reported by the node's measure function takes precedence over it
+    #[inline(always)]
+    fn aspect_ratio_auto(&self) -> bool {
+        Style::DEFAULT.aspect_ratio_auto
+    }
 
     // Spacing Properties
     /// How large should the margin be on each side?
@@ -146,6 +185,11 @@ pub enum Display {
     /// The children will follow the CSS Grid layout algorithm
     #[cfg(feature = "grid")]
     Grid,
+    /// The children will follow the CSS Table layout algorithm
+    #[cfg(feature = "table")]
+    Table,
+    /// The node does not generate a box, and its children are laid out as if they were children of its parent
+    Contents,
     /// The node is hidden, and it's children will also be hidden
     None,
 }
@@ -178,17 +222,21 @@ impl core::fmt::Display for Display {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Display::None => write!(f, "NONE"),
+            Display::Contents => write!(f, "CONTENTS"),
             #[cfg(feature = "block_layout")]
             Display::Block => write!(f, "BLOCK"),
             #[cfg(feature = "flexbox")]
             Display::Flex => write!(f, "FLEX"),
             #[cfg(feature = "grid")]
             Display::Grid => write!(f, "GRID"),
+            #[cfg(feature = "table")]
+            Display::Table => write!(f, "TABLE"),
         }
     }
 }
 
-/// An abstracted version of the CSS `display` property where any value other than "none" is represented by "normal"
+/// An abstracted version of the CSS `display` property where any value other than "none" or "contents" is represented
+/// by "normal"
 /// See: <https://www.w3.org/TR/css-display-3/#box-generation>
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -197,6 +245,11 @@ pub enum BoxGenerationMode {
     Normal,
     /// The node and it's descendants generate no boxes (they are hidden)
     None,
+    /// The node itself generates no box, but its children generate boxes as if they were children of the node's parent
+    ///
+    /// The layout algorithms expect such nodes to have been replaced by their children when iterating over the
+    /// children of a node (as [`TaffyTree`](crate::TaffyTree) does), so they are never laid out directly.
+    Contents,
 }
 
 impl BoxGenerationMode {
@@ -213,7 +266,8 @@ impl Default for BoxGenerationMode {
 /// The positioning strategy for this item.
 ///
 /// This controls both how the origin is determined for the [`Style::position`] field,
-/// and whether or not the item will be controlled by flexbox's layout algorithm.
+/// whether or not the item will be controlled by flexbox's layout algorithm, and whether the item is the containing
+/// block of its absolutely positioned descendants.
 ///
 /// WARNING: this enum follows the behavior of [CSS's `position` property](https://developer.mozilla.org/en-US/docs/Web/CSS/position),
 /// which can be unintuitive.
@@ -231,6 +285,50 @@ pub enum Position {
     ///
     /// WARNING: to opt-out of layouting entirely, you must use [`Display::None`] instead on your [`Style`] object.
     Absolute,
+    /// The item is positioned by the layout algorithm, and its offsets are ignored.
+    ///
+    /// Unlike all other positions, the item is not a "positioned ancestor": its absolutely positioned descendants are
+    /// laid out against the padding box of its nearest positioned ancestor instead (or of the root node), at the
+    /// static position computed by their parent. This is only done by [`TaffyTree`](crate::TaffyTree), as the layout
+    /// algorithms only have access to a node's direct children. Other trees which call the layout algorithms through
+    /// [`compute_root_layout`](crate::compute_root_layout) lay such descendants out against the item itself.
+    Static,
+    /// As [`Position::Absolute`], except that the offset is computed relative to the viewport: the available space
+    /// passed to [`TaffyTree::compute_layout`](crate::TaffyTree::compute_layout). Its location is still reported
+    /// relative to its parent.
+    ///
+    /// The layout algorithms lay the item out like an absolutely positioned item. It is then laid out again against the
+    /// viewport by [`TaffyTree`](crate::TaffyTree) once the rest of the tree has been laid out. Other trees which call the
+    /// layout algorithms through [`compute_root_layout`](crate::compute_root_layout) lay the item out against its parent,
+    /// as for [`Position::Absolute`].
+    Fixed,
+    /// The item is positioned by the layout algorithm, and is then offset to keep it within the visible area of its
+    /// nearest scroll container, as far as its parent's content box allows. Its insets give the distances from the
+    /// edges of the scroll container's visible area that it sticks to.
+    ///
+    /// This offset is computed by [`TaffyTree`](crate::TaffyTree) from the scroll offsets set with
+    /// [`TaffyTree::set_scroll_offset`](crate::TaffyTree::set_scroll_offset), and reported in
+    /// [`Layout::sticky_offset`](crate::Layout::sticky_offset) rather than in its location.
+    Sticky,
+}
+
+impl Position {
+    /// Returns true for positions that take the item out of flow (`Position::Absolute` and `Position::Fixed`)
+    #[inline(always)]
+    pub(crate) fn is_absolutely_positioned(self) -> bool {
+        match self {
+            Self::Relative | Self::Static | Self::Sticky => false,
+            Self::Absolute | Self::Fixed => true,
+        }
+    }
+
+    /// Returns false for `Position::Static`, which does not establish a containing block for its absolutely positioned
+    /// children
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    #[inline(always)]
+    pub(crate) fn is_positioned(self) -> bool {
+        self != Self::Static
+    }
 }
 
 impl Default for Position {
@@ -267,6 +365,89 @@ impl Default for BoxSizing {
     }
 }
 
+/// The inline base direction of a node, which determines whether inline content flows from left to right or from right to left
+///
+/// This property is inherited: nodes that do not specify a direction use the direction of their parent (and the root
+/// node defaults to [`Direction::Ltr`]). In a right-to-left context:
+///
+///   - The main-start edge of row Flexbox containers is the right edge
+///   - CSS Grid columns are placed from right to left
+///   - The `Start`/`End` values of `justify-*` alignment properties (and `align-*` properties in column Flexbox containers) are swapped
+///   - `TextAlign::Auto` aligns block children to the right
+///   - The scrollbar gutter is placed on the left
+///   - Absolutely positioned items with both a `left` and a `right` inset (and a definite width) are positioned using the `right` inset
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/direction>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    /// Inline content flows from left to right
+    #[default]
+    Ltr,
+    /// Inline content flows from right to left
+    Rtl,
+}
+
+impl Direction {
+    /// Returns true if the direction is [`Direction::Rtl`]
+    #[inline(always)]
+    pub fn is_rtl(self) -> bool {
+        self == Self::Rtl
+    }
+}
+
+/// The writing mode of a node, which determines the physical axis and direction of its inline and block axes
+///
+/// This property is inherited: nodes that do not specify a writing mode use the writing mode of their parent (and
+/// the root node defaults to [`WritingMode::HorizontalTb`]). In the vertical writing modes, the inline axis is the
+/// vertical axis: Flexbox rows, CSS Grid columns and inline content run from top to bottom (or from bottom to top if
+/// the direction is [`Direction::Rtl`]), while Block children and Flexbox columns are stacked horizontally.
+///
+/// A node whose writing mode differs from that of its parent is in an orthogonal flow: it is sized and laid out using
+/// its own writing mode and establishes an independent formatting context. Table layout does not support the vertical
+/// writing modes and always lays out tables in the horizontal writing mode.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/writing-mode>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WritingMode {
+    /// The inline axis is horizontal and blocks are stacked from top to bottom
+    #[default]
+    HorizontalTb,
+    /// The inline axis is vertical and blocks are stacked from right to left
+    VerticalRl,
+    /// The inline axis is vertical and blocks are stacked from left to right
+    VerticalLr,
+}
+
+impl WritingMode {
+    /// Returns true if the writing mode is one of the vertical writing modes
+    #[inline(always)]
+    pub fn is_vertical(self) -> bool {
+        self != Self::HorizontalTb
+    }
+}
+
+/// Whether a node is visible
+///
+/// Taffy doesn't paint nodes, so [`Visibility::Hidden`] has no effect on layout. [`Visibility::Collapse`] removes Flexbox
+/// items from their container (as if they were [`Display::None`]) while preserving the cross size of the flex line that
+/// they would have been placed in, which allows items to be hidden without the size of the line changing. Collapsed
+/// items that are not Flexbox items (including CSS Grid items) are treated as [`Visibility::Hidden`].
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/visibility>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Visibility {
+    /// The node is visible
+    #[default]
+    Visible,
+    /// The node is invisible, but still affects layout
+    Hidden,
+    /// The node is removed from Flexbox layout, but still contributes a strut to its flex line's cross size
+    Collapse,
+}
+
 /// How children overflowing their container should affect layout
 ///
 /// In CSS the primary effect of this property is to control whether contents of a parent container that overflow that container should
@@ -275,9 +456,10 @@ impl Default for BoxSizing {
 ///
 ///   - The automatic minimum size Flexbox/CSS Grid items with non-`Visible` overflow is `0` rather than being content based
 ///   - `Overflow::Scroll` nodes have space in the layout reserved for a scrollbar (width controlled by the `scrollbar_width` property)
+///   - `Overflow::Auto` nodes have space reserved for a scrollbar only if their content overflows them
 ///
 /// In Taffy, we only implement the layout related secondary effects as we are not concerned with drawing/painting. The amount of space reserved for
-/// a scrollbar is controlled by the `scrollbar_width` property. If this is `0` then `Scroll` behaves identically to `Hidden`.
+/// a scrollbar is controlled by the `scrollbar_width` property. If this is `0` then `Scroll` and `Auto` behave identically to `Hidden`.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/CSS/overflow>
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
@@ -297,6 +479,15 @@ pub enum Overflow {
     /// for a scrollbar. The amount of space reserved is controlled by the `scrollbar_width` property.
     /// Content that overflows this node should *not* contribute to the scroll region of its parent.
     Scroll,
+    /// The automatic minimum size of this node as a flexbox/grid item should be `0`. Additionally, space should be reserved
+    /// for a scrollbar if the node's content overflows it, in which case the node is laid out a second time with the
+    /// scrollbar present. The amount of space reserved is controlled by the `scrollbar_width` property.
+    /// Content that overflows this node should *not* contribute to the scroll region of its parent.
+    ///
+    /// Whether the content overflows is only known once the node has been fully laid out, so scrollbars are not taken
+    /// into account when measuring the node's size (unless it is an `Overflow::Scroll` axis). Requires the
+    /// `content_size` feature, without which `Auto` behaves identically to `Hidden`.
+    Auto,
 }
 
 impl Overflow {
@@ -306,7 +497,7 @@ impl Overflow {
     pub(crate) fn is_scroll_container(self) -> bool {
         match self {
             Self::Visible | Self::Clip => false,
-            Self::Hidden | Self::Scroll => true,
+            Self::Hidden | Self::Scroll | Self::Auto => true,
         }
     }
 
@@ -321,6 +512,30 @@ impl Overflow {
     }
 }
 
+/// Whether space should be reserved for a node's scrollbar gutter, and on which edges
+///
+/// This only affects the gutter of the scrollbar in the inline axis (the vertical scrollbar in horizontal writing
+/// modes). The space reserved for each scrollbar is controlled by the `scrollbar_width` property.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/scrollbar-gutter>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScrollbarGutter {
+    /// Space is only reserved for a scrollbar when it is shown: always for `Overflow::Scroll`, and for `Overflow::Auto`
+    /// when the node's content overflows it
+    #[default]
+    Auto,
+    /// Space is reserved on the inline-end edge of any scroll container (`Overflow::Hidden`, `Overflow::Scroll` or
+    /// `Overflow::Auto`), whether or not its scrollbar is shown. This prevents the layout from shifting when the
+    /// content starts to overflow.
+    Stable,
+    /// As `Stable`, but the same amount of space is also reserved on the inline-start edge so that the content stays
+    /// centered
+    StableBothEdges,
+    /// Scrollbars are drawn over the content (as is common on mobile platforms), so no space is ever reserved for them
+    Overlay,
+}
+
 /// A typed representation of the CSS style information for a single node.
 ///
 /// The most important idea in flexbox is the notion of a "main" and "cross" axis, which are always perpendicular to each other.
@@ -342,19 +557,28 @@ pub struct Style {
     /// What layout strategy should be used?
     pub display: Display,
     /// Whether a child is display:table or not. This affects children of block layouts.
-    /// This should really be part of `Display`, but it is currently seperate because table layout isn't implemented
+    /// Nodes with [`Display::Table`] are always treated as tables. This flag allows other nodes (or tables when
+    /// the `table` feature is disabled) to opt in to the same behaviour.
     pub item_is_table: bool,
     /// Is it a replaced element like an image or form field?
     /// https://drafts.csswg.org/css-sizing-3/#min-content-zero
     pub item_is_replaced: bool,
     /// Should size styles apply to the content box or the border box of the node
     pub box_sizing: BoxSizing,
+    /// The inline base direction of the node. `None` inherits the direction of the parent node
+    pub direction: Option<Direction>,
+    /// The writing mode of the node. `None` inherits the writing mode of the parent node
+    pub writing_mode: Option<WritingMode>,
+    /// Whether the node is visible. Only [`Visibility::Collapse`] affects layout.
+    pub visibility: Visibility,
 
     // Overflow properties
     /// How children overflowing their container should affect layout
     pub overflow: Point<Overflow>,
     /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` and `Overflow::Auto` nodes.
     pub scrollbar_width: f32,
+    /// Whether space for the scrollbar gutter should be reserved even if the node has no scrollbar, or never
+    pub scrollbar_gutter: ScrollbarGutter,
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
@@ -363,6 +587,29 @@ pub struct Style {
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
     pub inset: Rect<LengthPercentageAuto>,
 
+    // Anchor positioning properties
+    //
+    // These are only resolved by `TaffyTree`, and so only exist when the `taffy_tree` feature is enabled (adding 72 bytes
+    // to the size of `Style`).
+    /// The name by which absolutely positioned nodes can refer to this node in their `position_anchor`
+    #[cfg(feature = "taffy_tree")]
+    pub anchor_name: Option<String>,
+    /// The `anchor_name` of the node that the `anchor()` insets and the `position_area` of this node are resolved
+    /// against. The anchor must precede this node in tree order (and must not be one of its descendants).
+    ///
+    /// Only has an effect on absolutely positioned nodes, whose layout is then resolved by [`TaffyTree`](crate::TaffyTree)
+    /// once the rest of the tree has been laid out. If no such anchor exists then `anchor()` insets behave as `auto`
+    /// and the `position_area` is ignored.
+    #[cfg(feature = "taffy_tree")]
+    pub position_anchor: Option<String>,
+    /// The area around the node's anchor in which it is positioned
+    #[cfg(feature = "taffy_tree")]
+    pub position_area: Option<PositionArea>,
+    /// Alternative positions which are tried in order if the anchor-positioned node overflows its containing block.
+    /// The first one in which the node fits is used, or the node's own position if it does not fit in any of them.
+    #[cfg(feature = "taffy_tree")]
+    pub position_try_fallbacks: Vec<PositionTryFallback>,
+
     // Size properties
     /// Sets the initial size of the item
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
@@ -377,6 +624,10 @@ pub struct Style {
     ///
     /// The ratio is calculated as width divided by height.
     pub aspect_ratio: Option<f32>,
+    /// Whether `aspect_ratio` is combined with `auto` (`aspect-ratio: auto <ratio>` in CSS). If it is, then a natural
+    /// aspect ratio reported by the node's measure function (e.g. for an image) is used instead of `aspect_ratio`, and
+    /// applies to the content box rather than to the box specified by `box_sizing`.
+    pub aspect_ratio_auto: bool,
 
     // Spacing Properties
     /// How large should the margin be on each side?
@@ -404,14 +655,18 @@ pub struct Style {
     /// Falls back to the parents [`JustifyItems`] if not set
     #[cfg(feature = "grid")]
     pub justify_self: Option<AlignSelf>,
+    /// The order in which this node is laid out relative to its siblings within a Flexbox or CSS Grid container.
+    /// Siblings with the same order are laid out in tree order. Does not affect [`Layout::order`](crate::Layout::order).
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub order: i32,
     /// How should content contained within this item be aligned in the cross/block axis
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_content: Option<AlignContent>,
     /// How should content contained within this item be aligned in the main/inline axis
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub justify_content: Option<JustifyContent>,
-    /// How large should the gaps between items in a grid or flex container be?
-    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    /// How large should the gaps between items in a grid or flex container (or between the columns of a multi-column container) be?
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
     pub gap: Size<LengthPercentage>,
 
@@ -419,6 +674,29 @@ pub struct Style {
     /// How items elements should aligned in the inline axis
     #[cfg(feature = "block_layout")]
     pub text_align: TextAlign,
+    /// The number of columns of a multi-column container. `None` corresponds to `auto`.
+    #[cfg(feature = "block_layout")]
+    pub column_count: Option<u16>,
+    /// The ideal width of the columns of a multi-column container
+    #[cfg(feature = "block_layout")]
+    pub column_width: Dimension,
+    /// How content is distributed between the columns of a multi-column container
+    #[cfg(feature = "block_layout")]
+    pub column_fill: ColumnFill,
+
+    // Block item properties
+    /// Whether the node is floated to one side of its parent block container
+    #[cfg(feature = "block_layout")]
+    pub float: Float,
+    /// Whether the node must be placed below the preceding floats of its parent block container
+    #[cfg(feature = "block_layout")]
+    pub clear: Clear,
+    /// Whether the node spans across all of the columns of its parent multi-column container
+    #[cfg(feature = "block_layout")]
+    pub column_span: ColumnSpan,
+    /// Whether the node is laid out as a block-level or an inline-level box by its parent block container
+    #[cfg(feature = "inline_layout")]
+    pub display_outside: DisplayOutside,
 
     // Flexbox container properties
     /// Which direction does the main axis flow in?
@@ -450,6 +728,33 @@ pub struct Style {
     /// Defines the track sizing functions (widths) of the grid columns
     #[cfg(feature = "grid")]
     pub grid_template_columns: GridTrackVec<TrackSizingFunction>,
+    /// The names of the lines between the grid rows (`[header-start] 40px [header-end main-start] 1fr [main-end]`)
+    ///
+    /// Entry `i` contains the names of the line before the `i`th entry of `grid_template_rows`, and the final entry
+    /// contains the names of the line after the last entry. Names within a `repeat()` are specified as part of the repetition.
+    #[cfg(feature = "grid")]
+    pub grid_template_row_names: GridTrackVec<GridTrackVec<String>>,
+    /// The names of the lines between the grid columns. See `grid_template_row_names`.
+    #[cfg(feature = "grid")]
+    pub grid_template_column_names: GridTrackVec<GridTrackVec<String>>,
+    /// Defines named grid areas, which items can be placed into by name (see [`GridTemplateArea::parse`])
+    ///
+    /// The explicit grid is extended (with tracks sized by `grid_auto_rows` and `grid_auto_columns`) if required to
+    /// contain all of the areas.
+    #[cfg(feature = "grid")]
+    pub grid_template_areas: GridTrackVec<GridTemplateArea>,
+    /// Whether the grid's rows are a subgrid of its parent grid's rows (`grid-template-rows: subgrid`)
+    ///
+    /// If `true` then `grid_template_rows` and `grid_auto_rows` are ignored and the rows of the parent grid that
+    /// this node spans are used instead. Has no effect if the node is not a grid item of another grid.
+    #[cfg(feature = "grid")]
+    pub subgrid_rows: bool,
+    /// Whether the grid's columns are a subgrid of its parent grid's columns (`grid-template-columns: subgrid`)
+    ///
+    /// If `true` then `grid_template_columns` and `grid_auto_columns` are ignored and the columns of the parent grid that
+    /// this node spans are used instead. Has no effect if the node is not a grid item of another grid.
+    #[cfg(feature = "grid")]
+    pub subgrid_columns: bool,
     /// Defines the size of implicitly created rows
     #[cfg(feature = "grid")]
     pub grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
@@ -459,6 +764,26 @@ pub struct Style {
     /// Controls how items get placed into the grid for auto-placed items
     #[cfg(feature = "grid")]
     pub grid_auto_flow: GridAutoFlow,
+    /// Whether the grid's rows are a masonry axis (`grid-template-rows: masonry`)
+    ///
+    /// If `true` then items are packed into the grid's columns rather than being placed into rows. Takes precedence
+    /// over `masonry_columns` if both are set.
+    #[cfg(feature = "grid")]
+    pub masonry_rows: bool,
+    /// Whether the grid's columns are a masonry axis (`grid-template-columns: masonry`)
+    ///
+    /// If `true` then items are packed into the grid's rows rather than being placed into columns.
+    #[cfg(feature = "grid")]
+    pub masonry_columns: bool,
+    /// Controls how items get placed into the tracks of a masonry grid
+    #[cfg(feature = "grid")]
+    pub masonry_auto_flow: MasonryAutoFlow,
+    /// How the items in each column of a masonry grid are aligned in the block axis (if the rows are the masonry axis)
+    #[cfg(feature = "grid")]
+    pub align_tracks: Option<AlignContent>,
+    /// How the items in each row of a masonry grid are aligned in the inline axis (if the columns are the masonry axis)
+    #[cfg(feature = "grid")]
+    pub justify_tracks: Option<AlignContent>,
 
     // Grid child properties
     /// Defines which row in the grid the item should start and end at
@@ -467,6 +792,26 @@ pub struct Style {
     /// Defines which column in the grid the item should start and end at
     #[cfg(feature = "grid")]
     pub grid_column: Line<GridPlacement>,
+
+    // Table container properties
+    /// Which algorithm should be used to determine the widths of the table's columns
+    #[cfg(feature = "table")]
+    pub table_layout: TableLayout,
+    /// The spacing between adjacent cells (and between the outermost cells and the table's content box edges)
+    #[cfg(feature = "table")]
+    #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
+    pub border_spacing: Size<LengthPercentage>,
+
+    // Table item properties
+    /// The role the node plays in the structure of a table. Only has an effect within a [`Display::Table`] node.
+    #[cfg(feature = "table")]
+    pub table_role: TableRole,
+    /// The number of columns a table cell spans
+    #[cfg(feature = "table")]
+    pub colspan: u16,
+    /// The number of rows a table cell spans. A value of 0 causes the cell to span to the end of its row group.
+    #[cfg(feature = "table")]
+    pub rowspan: u16,
 }
 
 impl Style {
@@ -476,10 +821,22 @@ impl Style {
         item_is_table: false,
         item_is_replaced: false,
         box_sizing: BoxSizing::BorderBox,
+        direction: None,
+        writing_mode: None,
+        visibility: Visibility::Visible,
         overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
         scrollbar_width: 0.0,
+        scrollbar_gutter: ScrollbarGutter::Auto,
         position: Position::Relative,
         inset: Rect::auto(),
+        #[cfg(feature = "taffy_tree")]
+        anchor_name: None,
+        #[cfg(feature = "taffy_tree")]
+        position_anchor: None,
+        #[cfg(feature = "taffy_tree")]
+        position_area: None,
+        #[cfg(feature = "taffy_tree")]
+        position_try_fallbacks: Vec::new(),
         margin: Rect::zero(),
         padding: Rect::zero(),
         border: Rect::zero(),
@@ -487,7 +844,8 @@ impl Style {
         min_size: Size::auto(),
         max_size: Size::auto(),
         aspect_ratio: None,
-        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        aspect_ratio_auto: false,
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
         gap: Size::zero(),
         // Alignment
         #[cfg(any(feature = "flexbox", feature = "grid"))]
@@ -499,12 +857,28 @@ impl Style {
         #[cfg(feature = "grid")]
         justify_self: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
+        order: 0,
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_content: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         justify_content: None,
         // Block
         #[cfg(feature = "block_layout")]
         text_align: TextAlign::Auto,
+        #[cfg(feature = "block_layout")]
+        column_count: None,
+        #[cfg(feature = "block_layout")]
+        column_width: Dimension::auto(),
+        #[cfg(feature = "block_layout")]
+        column_fill: ColumnFill::Balance,
+        #[cfg(feature = "block_layout")]
+        float: Float::None,
+        #[cfg(feature = "block_layout")]
+        clear: Clear::None,
+        #[cfg(feature = "block_layout")]
+        column_span: ColumnSpan::None,
+        #[cfg(feature = "inline_layout")]
+        display_outside: DisplayOutside::Block,
         // Flexbox
         #[cfg(feature = "flexbox")]
         flex_direction: FlexDirection::Row,
@@ -522,15 +896,46 @@ impl Style {
         #[cfg(feature = "grid")]
         grid_template_columns: GridTrackVec::new(),
         #[cfg(feature = "grid")]
+        grid_template_row_names: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
+        grid_template_column_names: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
+        grid_template_areas: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
+        subgrid_rows: false,
+        #[cfg(feature = "grid")]
+        subgrid_columns: false,
+        #[cfg(feature = "grid")]
         grid_auto_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_auto_columns: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_auto_flow: GridAutoFlow::Row,
         #[cfg(feature = "grid")]
+        masonry_rows: false,
+        #[cfg(feature = "grid")]
+        masonry_columns: false,
+        #[cfg(feature = "grid")]
+        masonry_auto_flow: MasonryAutoFlow::Pack,
+        #[cfg(feature = "grid")]
+        align_tracks: None,
+        #[cfg(feature = "grid")]
+        justify_tracks: None,
+        #[cfg(feature = "grid")]
         grid_row: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
         #[cfg(feature = "grid")]
         grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
+        // Table
+        #[cfg(feature = "table")]
+        table_layout: TableLayout::Auto,
+        #[cfg(feature = "table")]
+        border_spacing: Size::zero(),
+        #[cfg(feature = "table")]
+        table_role: TableRole::Auto,
+        #[cfg(feature = "table")]
+        colspan: 1,
+        #[cfg(feature = "table")]
+        rowspan: 1,
     };
 }
 
@@ -545,6 +950,7 @@ impl CoreStyle for Style {
     fn box_generation_mode(&self) -> BoxGenerationMode {
         match self.display {
             Display::None => BoxGenerationMode::None,
+            Display::Contents => BoxGenerationMode::Contents,
             _ => BoxGenerationMode::Normal,
         }
     }
@@ -562,6 +968,18 @@ impl CoreStyle for Style {
         self.box_sizing
     }
     #[inline(always)]
+    fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+    #[inline(always)]
+    fn writing_mode(&self) -> Option<WritingMode> {
+        self.writing_mode
+    }
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+    #[inline(always)]
     fn overflow(&self) -> Point<Overflow> {
         self.overflow
     }
@@ -570,6 +988,10 @@ impl CoreStyle for Style {
         self.scrollbar_width
     }
     #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        self.scrollbar_gutter
+    }
+    #[inline(always)]
     fn position(&self) -> Position {
         self.position
     }
@@ -594,6 +1016,10 @@ impl CoreStyle for Style {
         self.aspect_ratio
     }
     #[inline(always)]
+    fn aspect_ratio_auto(&self) -> bool {
+        self.aspect_ratio_auto
+    }
+    #[inline(always)]
     fn margin(&self) -> Rect<LengthPercentageAuto> {
         self.margin
     }
@@ -625,6 +1051,18 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).box_sizing()
     }
     #[inline(always)]
+    fn direction(&self) -> Option<Direction> {
+        (*self).direction()
+    }
+    #[inline(always)]
+    fn writing_mode(&self) -> Option<WritingMode> {
+        (*self).writing_mode()
+    }
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        (*self).visibility()
+    }
+    #[inline(always)]
     fn overflow(&self) -> Point<Overflow> {
         (*self).overflow()
     }
@@ -633,6 +1071,10 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).scrollbar_width()
     }
     #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        (*self).scrollbar_gutter()
+    }
+    #[inline(always)]
     fn position(&self) -> Position {
         (*self).position()
     }
@@ -657,6 +1099,10 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).aspect_ratio()
     }
     #[inline(always)]
+    fn aspect_ratio_auto(&self) -> bool {
+        (*self).aspect_ratio_auto()
+    }
+    #[inline(always)]
     fn margin(&self) -> Rect<LengthPercentageAuto> {
         (*self).margin()
     }
@@ -676,6 +1122,22 @@ impl BlockContainerStyle for &Style {
     fn text_align(&self) -> TextAlign {
         self.text_align
     }
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        self.column_count
+    }
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        self.column_width
+    }
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        self.gap.width
+    }
+    #[inline(always)]
+    fn column_fill(&self) -> ColumnFill {
+        self.column_fill
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -684,14 +1146,55 @@ impl<T: BlockContainerStyle> BlockContainerStyle for &'_ T {
     fn text_align(&self) -> TextAlign {
         (*self).text_align()
     }
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        (*self).column_count()
+    }
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        (*self).column_width()
+    }
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        (*self).column_gap()
+    }
+    #[inline(always)]
+    fn column_fill(&self) -> ColumnFill {
+        (*self).column_fill()
+    }
 }
 
 #[cfg(feature = "block_layout")]
 impl BlockItemStyle for Style {
     #[inline(always)]
     fn is_table(&self) -> bool {
+        #[cfg(feature = "table")]
+        if self.display == Display::Table {
+            return true;
+        }
         self.item_is_table
     }
+
+    #[inline(always)]
+    fn float(&self) -> Float {
+        self.float
+    }
+
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        self.clear
+    }
+
+    #[inline(always)]
+    fn column_span(&self) -> ColumnSpan {
+        self.column_span
+    }
+
+    #[cfg(feature = "inline_layout")]
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        self.display_outside
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -700,6 +1203,27 @@ impl<T: BlockItemStyle> BlockItemStyle for &'_ T {
     fn is_table(&self) -> bool {
         (*self).is_table()
     }
+
+    #[inline(always)]
+    fn float(&self) -> Float {
+        (*self).float()
+    }
+
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        (*self).clear()
+    }
+
+    #[inline(always)]
+    fn column_span(&self) -> ColumnSpan {
+        (*self).column_span()
+    }
+
+    #[cfg(feature = "inline_layout")]
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        (*self).display_outside()
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -776,6 +1300,10 @@ impl FlexboxItemStyle for Style {
     fn align_self(&self) -> Option<AlignSelf> {
         self.align_self
     }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.order
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -796,6 +1324,10 @@ impl<T: FlexboxItemStyle> FlexboxItemStyle for &'_ T {
     fn align_self(&self) -> Option<AlignSelf> {
         (*self).align_self()
     }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        (*self).order()
+    }
 }
 
 #[cfg(feature = "grid")]
@@ -818,6 +1350,18 @@ impl GridContainerStyle for Style {
         &self.grid_template_columns
     }
     #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<String>] {
+        &self.grid_template_row_names
+    }
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<String>] {
+        &self.grid_template_column_names
+    }
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea] {
+        &self.grid_template_areas
+    }
+    #[inline(always)]
     fn grid_auto_rows(&self) -> &[NonRepeatedTrackSizingFunction] {
         &self.grid_auto_rows
     }
@@ -826,10 +1370,38 @@ impl GridContainerStyle for Style {
         &self.grid_auto_columns
     }
     #[inline(always)]
+    fn subgrid_rows(&self) -> bool {
+        self.subgrid_rows
+    }
+    #[inline(always)]
+    fn subgrid_columns(&self) -> bool {
+        self.subgrid_columns
+    }
+    #[inline(always)]
     fn grid_auto_flow(&self) -> GridAutoFlow {
         self.grid_auto_flow
     }
     #[inline(always)]
+    fn masonry_rows(&self) -> bool {
+        self.masonry_rows
+    }
+    #[inline(always)]
+    fn masonry_columns(&self) -> bool {
+        self.masonry_columns
+    }
+    #[inline(always)]
+    fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        self.masonry_auto_flow
+    }
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        self.align_tracks
+    }
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<AlignContent> {
+        self.justify_tracks
+    }
+    #[inline(always)]
     fn gap(&self) -> Size<LengthPercentage> {
         self.gap
     }
@@ -871,6 +1443,18 @@ impl<T: GridContainerStyle> GridContainerStyle for &'_ T {
         (*self).grid_template_columns()
     }
     #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<String>] {
+        (*self).grid_template_row_names()
+    }
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<String>] {
+        (*self).grid_template_column_names()
+    }
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea] {
+        (*self).grid_template_areas()
+    }
+    #[inline(always)]
     fn grid_auto_rows(&self) -> Self::AutoTrackList<'_> {
         (*self).grid_auto_rows()
     }
@@ -879,10 +1463,38 @@ impl<T: GridContainerStyle> GridContainerStyle for &'_ T {
         (*self).grid_auto_columns()
     }
     #[inline(always)]
+    fn subgrid_rows(&self) -> bool {
+        (*self).subgrid_rows()
+    }
+    #[inline(always)]
+    fn subgrid_columns(&self) -> bool {
+        (*self).subgrid_columns()
+    }
+    #[inline(always)]
     fn grid_auto_flow(&self) -> GridAutoFlow {
         (*self).grid_auto_flow()
     }
     #[inline(always)]
+    fn masonry_rows(&self) -> bool {
+        (*self).masonry_rows()
+    }
+    #[inline(always)]
+    fn masonry_columns(&self) -> bool {
+        (*self).masonry_columns()
+    }
+    #[inline(always)]
+    fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        (*self).masonry_auto_flow()
+    }
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        (*self).align_tracks()
+    }
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<AlignContent> {
+        (*self).justify_tracks()
+    }
+    #[inline(always)]
     fn gap(&self) -> Size<LengthPercentage> {
         (*self).gap()
     }
@@ -908,11 +1520,11 @@ impl<T: GridContainerStyle> GridContainerStyle for &'_ T {
 impl GridItemStyle for &'_ Style {
     #[inline(always)]
     fn grid_row(&self) -> Line<GridPlacement> {
-        self.grid_row
+        self.grid_row.clone()
     }
     #[inline(always)]
     fn grid_column(&self) -> Line<GridPlacement> {
-        self.grid_column
+        self.grid_column.clone()
     }
     #[inline(always)]
     fn align_self(&self) -> Option<AlignSelf> {
@@ -922,6 +1534,18 @@ impl GridItemStyle for &'_ Style {
     fn justify_self(&self) -> Option<AlignSelf> {
         self.justify_self
     }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.order
+    }
+    #[inline(always)]
+    fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        self.display == Display::Grid
+            && match axis {
+                AbsoluteAxis::Horizontal => self.subgrid_columns,
+                AbsoluteAxis::Vertical => self.subgrid_rows,
+            }
+    }
 }
 
 #[cfg(feature = "grid")]
@@ -942,6 +1566,70 @@ impl<T: GridItemStyle> GridItemStyle for &'_ T {
     fn justify_self(&self) -> Option<AlignSelf> {
         (*self).justify_self()
     }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        (*self).order()
+    }
+    #[inline(always)]
+    fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        (*self).is_subgrid(axis)
+    }
+}
+
+#[cfg(feature = "table")]
+impl TableContainerStyle for &Style {
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        self.table_layout
+    }
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        self.border_spacing
+    }
+}
+
+#[cfg(feature = "table")]
+impl<T: TableContainerStyle> TableContainerStyle for &'_ T {
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        (*self).table_layout()
+    }
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        (*self).border_spacing()
+    }
+}
+
+#[cfg(feature = "table")]
+impl TableItemStyle for &Style {
+    #[inline(always)]
+    fn table_role(&self) -> TableRole {
+        self.table_role
+    }
+    #[inline(always)]
+    fn colspan(&self) -> u16 {
+        self.colspan
+    }
+    #[inline(always)]
+    fn rowspan(&self) -> u16 {
+        self.rowspan
+    }
+}
+
+#[cfg(feature = "table")]
+impl<T: TableItemStyle> TableItemStyle for &'_ T {
+    #[inline(always)]
+    fn table_role(&self) -> TableRole {
+        (*self).table_role()
+    }
+    #[inline(always)]
+    fn colspan(&self) -> u16 {
+        (*self).colspan()
+    }
+    #[inline(always)]
+    fn rowspan(&self) -> u16 {
+        (*self).rowspan()
+    }
 }
 
 #[cfg(test)]
@@ -959,8 +1647,12 @@ mod tests {
             item_is_table: false,
             item_is_replaced: false,
             box_sizing: Default::default(),
+            direction: None,
+            writing_mode: None,
+            visibility: Default::default(),
             overflow: Default::default(),
             scrollbar_width: 0.0,
+            scrollbar_gutter: Default::default(),
             position: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_direction: Default::default(),
@@ -975,16 +1667,40 @@ mod tests {
             #[cfg(feature = "grid")]
             justify_self: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
+            order: 0,
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_content: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             justify_content: Default::default(),
             inset: Rect::auto(),
+            #[cfg(feature = "taffy_tree")]
+            anchor_name: None,
+            #[cfg(feature = "taffy_tree")]
+            position_anchor: None,
+            #[cfg(feature = "taffy_tree")]
+            position_area: None,
+            #[cfg(feature = "taffy_tree")]
+            position_try_fallbacks: Default::default(),
             margin: Rect::zero(),
             padding: Rect::zero(),
             border: Rect::zero(),
             gap: Size::zero(),
             #[cfg(feature = "block_layout")]
             text_align: Default::default(),
+            #[cfg(feature = "block_layout")]
+            column_count: None,
+            #[cfg(feature = "block_layout")]
+            column_width: super::Dimension::AUTO,
+            #[cfg(feature = "block_layout")]
+            column_fill: Default::default(),
+            #[cfg(feature = "block_layout")]
+            float: Default::default(),
+            #[cfg(feature = "block_layout")]
+            clear: Default::default(),
+            #[cfg(feature = "block_layout")]
+            column_span: Default::default(),
+            #[cfg(feature = "inline_layout")]
+            display_outside: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_grow: 0.0,
             #[cfg(feature = "flexbox")]
@@ -995,10 +1711,18 @@ mod tests {
             min_size: Size::auto(),
             max_size: Size::auto(),
             aspect_ratio: Default::default(),
+            aspect_ratio_auto: false,
             #[cfg(feature = "grid")]
             grid_template_rows: Default::default(),
             #[cfg(feature = "grid")]
             grid_template_columns: Default::default(),
+            grid_template_row_names: Default::default(),
+            grid_template_column_names: Default::default(),
+            grid_template_areas: Default::default(),
+            #[cfg(feature = "grid")]
+            subgrid_rows: false,
+            #[cfg(feature = "grid")]
+            subgrid_columns: false,
             #[cfg(feature = "grid")]
             grid_auto_rows: Default::default(),
             #[cfg(feature = "grid")]
@@ -1006,9 +1730,29 @@ mod tests {
             #[cfg(feature = "grid")]
             grid_auto_flow: Default::default(),
             #[cfg(feature = "grid")]
+            masonry_rows: false,
+            #[cfg(feature = "grid")]
+            masonry_columns: false,
+            #[cfg(feature = "grid")]
+            masonry_auto_flow: Default::default(),
+            #[cfg(feature = "grid")]
+            align_tracks: None,
+            #[cfg(feature = "grid")]
+            justify_tracks: None,
+            #[cfg(feature = "grid")]
             grid_row: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
             #[cfg(feature = "grid")]
             grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
+            #[cfg(feature = "table")]
+            table_layout: Default::default(),
+            #[cfg(feature = "table")]
+            border_spacing: Size::zero(),
+            #[cfg(feature = "table")]
+            table_role: Default::default(),
+            #[cfg(feature = "table")]
+            colspan: 1,
+            #[cfg(feature = "table")]
+            rowspan: 1,
         };
 
         assert_eq!(Style::DEFAULT, Style::default());
@@ -1028,6 +1772,8 @@ mod tests {
             let name = name.replace("taffy::style::alignment::", "");
             let name = name.replace("taffy::style::flex::", "");
             let name = name.replace("taffy::style::grid::", "");
+            let name = name.replace("taffy::style::table::", "");
+            let name = name.replace("taffy::style::block::", "");
 
             assert_eq!(
                 ::core::mem::size_of::<T>(),
@@ -1042,8 +1788,12 @@ mod tests {
         // Display and Position
         assert_type_size::<Display>(1);
         assert_type_size::<BoxSizing>(1);
+        assert_type_size::<Direction>(1);
+        assert_type_size::<WritingMode>(1);
+        assert_type_size::<Visibility>(1);
         assert_type_size::<Position>(1);
         assert_type_size::<Overflow>(1);
+        assert_type_size::<ScrollbarGutter>(1);
 
         // Dimensions and aggregations of Dimensions
         assert_type_size::<f32>(4);
@@ -1068,18 +1818,35 @@ mod tests {
 
         // CSS Grid Container
         assert_type_size::<GridAutoFlow>(1);
+        assert_type_size::<MasonryAutoFlow>(1);
         assert_type_size::<MinTrackSizingFunction>(8);
         assert_type_size::<MaxTrackSizingFunction>(8);
         assert_type_size::<NonRepeatedTrackSizingFunction>(16);
-        assert_type_size::<TrackSizingFunction>(32);
+        assert_type_size::<TrackSizingFunction>(56);
         assert_type_size::<Vec<NonRepeatedTrackSizingFunction>>(24);
         assert_type_size::<Vec<TrackSizingFunction>>(24);
 
         // CSS Grid Item
-        assert_type_size::<GridPlacement>(4);
-        assert_type_size::<Line<GridPlacement>>(8);
+        assert_type_size::<GridPlacement>(32);
+        assert_type_size::<Line<GridPlacement>>(64);
+
+        // Block Container
+        assert_type_size::<ColumnFill>(1);
+
+        // Block Item
+        assert_type_size::<Float>(1);
+        assert_type_size::<ColumnSpan>(1);
+        assert_type_size::<Clear>(1);
+        assert_type_size::<DisplayOutside>(1);
+
+        // CSS Table
+        assert_type_size::<TableLayout>(1);
+        assert_type_size::<TableRole>(1);
 
         // Overall
-        assert_type_size::<Style>(352);
+        #[cfg(feature = "taffy_tree")]
+        assert_type_size::<Style>(664);
+        #[cfg(not(feature = "taffy_tree"))]
+        assert_type_size::<Style>(592);
     }
 }
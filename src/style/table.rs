@@ -0,0 +1,90 @@
+//! Style types for CSS Table layout
+use super::{CoreStyle, LengthPercentage, Style};
+use crate::geometry::Size;
+
+/// The set of styles required for a CSS Table container
+pub trait TableContainerStyle: CoreStyle {
+    /// Which algorithm should be used to determine the widths of the table's columns
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        Style::DEFAULT.table_layout
+    }
+
+    /// The spacing between adjacent cells (and between the outermost cells and the table's content box edges)
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        Style::DEFAULT.border_spacing
+    }
+}
+
+/// The set of styles required for a descendant of a CSS Table container (a row group, row or cell)
+pub trait TableItemStyle: CoreStyle {
+    /// The role the node plays in the structure of the table
+    #[inline(always)]
+    fn table_role(&self) -> TableRole {
+        Style::DEFAULT.table_role
+    }
+
+    /// The number of columns a cell spans. A value of 0 is treated as 1.
+    #[inline(always)]
+    fn colspan(&self) -> u16 {
+        Style::DEFAULT.colspan
+    }
+
+    /// The number of rows a cell spans. A value of 0 causes the cell to span to the end of its row group.
+    #[inline(always)]
+    fn rowspan(&self) -> u16 {
+        Style::DEFAULT.rowspan
+    }
+}
+
+/// Controls which algorithm is used to determine the widths of a table's columns
+///
+/// Defaults to [`TableLayout::Auto`]
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/table-layout)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableLayout {
+    /// Column widths are determined by the min-content and max-content widths of every cell in the table
+    #[default]
+    Auto,
+    /// Column widths are determined by the table's width and the widths of the cells in the first row only.
+    ///
+    /// Only takes effect if the table has a definite width. Otherwise the table falls back to the auto algorithm.
+    Fixed,
+}
+
+/// The role a node plays in the structure of a table. This corresponds to the `table-*` values of the CSS `display` property.
+///
+/// Taffy separates these roles from [`Display`](super::Display) so that a table cell can still choose the
+/// algorithm used to lay out its own children (block, flexbox, grid, etc). Roles only have an effect on nodes
+/// that are descendants of a [`Display::Table`](super::Display::Table) node and are otherwise ignored.
+///
+/// Defaults to [`TableRole::Auto`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableRole {
+    /// The role is inferred from the node's position in the tree. Consecutive children of a table or row group
+    /// which are not rows are wrapped in an anonymous row, and each of them is treated as a cell.
+    #[default]
+    Auto,
+    /// The node groups a set of rows (like `<tbody>`)
+    RowGroup,
+    /// The node groups a set of rows that are placed before all other rows (like `<thead>`)
+    HeaderGroup,
+    /// The node groups a set of rows that are placed after all other rows (like `<tfoot>`)
+    FooterGroup,
+    /// The node is a row of cells (like `<tr>`)
+    Row,
+    /// The node is a cell (like `<td>`)
+    Cell,
+}
+
+impl TableRole {
+    /// Whether the role is one of the row group roles
+    #[inline(always)]
+    pub fn is_row_group(self) -> bool {
+        matches!(self, Self::RowGroup | Self::HeaderGroup | Self::FooterGroup)
+    }
+}
@@ -8,10 +8,10 @@ use crate::{
 use crate::{
     geometry::MinMax,
     style::{
-        GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction,
-        TrackSizingFunction,
+        GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+        NonRepeatedTrackSizingFunction, TrackSizingFunction,
     },
-    util::sys::Vec,
+    util::sys::{String, Vec},
 };
 #[cfg(feature = "grid")]
 use core::fmt::Debug;
@@ -23,7 +23,22 @@ where
     Input: TryInto<GridTrackRepetition>,
     <Input as TryInto<GridTrackRepetition>>::Error: Debug,
 {
-    TrackSizingFunction::Repeat(repetition_kind.try_into().unwrap(), track_list)
+    TrackSizingFunction::Repeat(repetition_kind.try_into().unwrap(), track_list, Vec::new())
+}
+
+/// Returns a repeated track definition with named lines. `line_names` contains the names of the lines before each
+/// repeated track followed by the names of the line after the last repeated track.
+#[cfg(feature = "grid")]
+pub fn repeat_with_line_names<Input>(
+    repetition_kind: Input,
+    track_list: Vec<NonRepeatedTrackSizingFunction>,
+    line_names: Vec<Vec<String>>,
+) -> TrackSizingFunction
+where
+    Input: TryInto<GridTrackRepetition>,
+    <Input as TryInto<GridTrackRepetition>>::Error: Debug,
+{
+    TrackSizingFunction::Repeat(repetition_kind.try_into().unwrap(), track_list, line_names)
 }
 
 #[cfg(feature = "grid")]
@@ -50,6 +65,26 @@ pub trait TaffyGridLine {
     fn from_line_index(index: i16) -> Self;
 }
 
+/// Returns a GridPlacement::NamedLine, which places an item at the nth line with the specified name.
+/// An index of zero places the item at the edge of the named area (or the first line with the name).
+#[cfg(feature = "grid")]
+pub fn named_line(name: impl Into<String>, index: i16) -> GridPlacement {
+    GridPlacement::NamedLine(name.into(), index)
+}
+
+/// Returns a GridPlacement::NamedSpan, which spans until the nth line with the specified name
+#[cfg(feature = "grid")]
+pub fn named_span(name: impl Into<String>, span: u16) -> GridPlacement {
+    GridPlacement::NamedSpan(name.into(), span)
+}
+
+/// Returns a placement that spans the named grid area with the specified name, in either axis
+#[cfg(feature = "grid")]
+pub fn named_area(name: impl Into<String>) -> Line<GridPlacement> {
+    let name = name.into();
+    Line { start: GridPlacement::NamedLine(name.clone(), 0), end: GridPlacement::NamedLine(name, 0) }
+}
+
 /// Returns a GridPlacement::Span
 pub fn span<T: TaffyGridSpan>(span: u16) -> T {
     T::from_span(span)
@@ -531,16 +566,25 @@ mod repeat_fn_tests {
 
     #[test]
     fn test_repeat_u16() {
-        assert_eq!(repeat(123, TEST_VEC), TrackSizingFunction::Repeat(GridTrackRepetition::Count(123), TEST_VEC));
+        assert_eq!(
+            repeat(123, TEST_VEC),
+            TrackSizingFunction::Repeat(GridTrackRepetition::Count(123), TEST_VEC, vec![])
+        );
     }
 
     #[test]
     fn test_repeat_auto_fit_str() {
-        assert_eq!(repeat("auto-fit", TEST_VEC), TrackSizingFunction::Repeat(GridTrackRepetition::AutoFit, TEST_VEC));
+        assert_eq!(
+            repeat("auto-fit", TEST_VEC),
+            TrackSizingFunction::Repeat(GridTrackRepetition::AutoFit, TEST_VEC, vec![])
+        );
     }
 
     #[test]
     fn test_repeat_auto_fill_str() {
-        assert_eq!(repeat("auto-fill", TEST_VEC), TrackSizingFunction::Repeat(GridTrackRepetition::AutoFill, TEST_VEC));
+        assert_eq!(
+            repeat("auto-fill", TEST_VEC),
+            TrackSizingFunction::Repeat(GridTrackRepetition::AutoFill, TEST_VEC, vec![])
+        );
     }
 }
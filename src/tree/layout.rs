@@ -2,6 +2,8 @@
 use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
 use crate::style::AvailableSpace;
 use crate::style_helpers::TaffyMaxContent;
+#[cfg(all(feature = "detailed_layout_info", any(feature = "grid", feature = "inline_layout")))]
+use crate::util::sys::Box;
 use crate::util::sys::{f32_max, f32_min};
 
 /// Whether we are performing a full layout, or we merely need to size the node
@@ -155,7 +157,7 @@ impl LayoutInput {
 /// A baseline is the line on which text sits. Your node likely has a baseline if it is a text node, or contains
 /// children that may be text nodes. See <https://www.w3.org/TR/css-writing-modes-3/#intro-baselines> for details.
 /// If your node does not have a baseline (or you are unsure how to compute it), then simply return `Point::NONE`
-/// for the first_baselines field
+/// for the first_baselines and last_baselines fields
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LayoutOutput {
@@ -164,8 +166,13 @@ pub struct LayoutOutput {
     #[cfg(feature = "content_size")]
     /// The size of the content within the node
     pub content_size: Size<f32>,
+    /// The size of the scrollbars that space was reserved for in each dimension, including any `Overflow::Auto`
+    /// scrollbars that were added because the node's content overflowed it
+    pub scrollbar_size: Size<f32>,
     /// The first baseline of the node in each dimension, if any
     pub first_baselines: Point<Option<f32>>,
+    /// The last baseline of the node in each dimension, if any
+    pub last_baselines: Point<Option<f32>>,
     /// Top margin that can be collapsed with. This is used for CSS block layout and can be set to
     /// `CollapsibleMarginSet::ZERO` for other layout modes that don't support margin collapsing
     pub top_margin: CollapsibleMarginSet,
@@ -183,7 +190,9 @@ impl LayoutOutput {
         size: Size::ZERO,
         #[cfg(feature = "content_size")]
         content_size: Size::ZERO,
+        scrollbar_size: Size::ZERO,
         first_baselines: Point::NONE,
+        last_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: false,
@@ -192,17 +201,29 @@ impl LayoutOutput {
     /// A blank layout output
     pub const DEFAULT: Self = Self::HIDDEN;
 
-    /// Constructor to create a `LayoutOutput` from just the size and baselines
+    /// Constructor to create a `LayoutOutput` from just the size and first baselines
     pub fn from_sizes_and_baselines(
+        size: Size<f32>,
+        content_size: Size<f32>,
+        first_baselines: Point<Option<f32>>,
+    ) -> Self {
+        Self::from_sizes_and_first_and_last_baselines(size, content_size, first_baselines, Point::NONE)
+    }
+
+    /// Constructor to create a `LayoutOutput` from just the size and both the first and last baselines
+    pub fn from_sizes_and_first_and_last_baselines(
         size: Size<f32>,
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))] content_size: Size<f32>,
         first_baselines: Point<Option<f32>>,
+        last_baselines: Point<Option<f32>>,
     ) -> Self {
         Self {
             size,
             #[cfg(feature = "content_size")]
             content_size,
+            scrollbar_size: Size::ZERO,
             first_baselines,
+            last_baselines,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: false,
@@ -239,6 +260,11 @@ pub struct Layout {
     pub content_size: Size<f32>,
     /// The size of the scrollbars in each dimension. If there is no scrollbar then the size will be zero.
     pub scrollbar_size: Size<f32>,
+    /// The offset of a `Position::Sticky` node from its `location`, given the scroll offsets of its ancestors.
+    ///
+    /// This is kept separate from `location` so that scrolling does not require the layout to be recomputed. It is
+    /// zero for nodes which are not sticky.
+    pub sticky_offset: Point<f32>,
     /// The size of the borders of the node
     pub border: Rect<f32>,
     /// The size of the padding of the node
@@ -268,6 +294,7 @@ impl Layout {
             #[cfg(feature = "content_size")]
             content_size: Size::zero(),
             scrollbar_size: Size::zero(),
+            sticky_offset: Point::ZERO,
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
@@ -287,6 +314,7 @@ impl Layout {
             #[cfg(feature = "content_size")]
             content_size: Size::zero(),
             scrollbar_size: Size::zero(),
+            sticky_offset: Point::ZERO,
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
@@ -352,6 +380,9 @@ pub enum DetailedLayoutInfo {
     /// Enum variant for [`DetailedGridInfo`](crate::compute::grid::DetailedGridInfo)
     #[cfg(feature = "grid")]
     Grid(Box<crate::compute::grid::DetailedGridInfo>),
+    /// Enum variant for [`DetailedInlineInfo`](crate::compute::block::DetailedInlineInfo)
+    #[cfg(feature = "inline_layout")]
+    Inline(Box<crate::compute::block::DetailedInlineInfo>),
     /// For node that hasn't had any detailed information yet
     None,
 }
@@ -24,6 +24,9 @@ pub use traits::LayoutGridContainer;
 #[cfg(feature = "block_layout")]
 pub use traits::LayoutBlockContainer;
 
+#[cfg(feature = "table")]
+pub use traits::LayoutTableContainer;
+
 #[cfg(feature = "taffy_tree")]
 mod taffy_tree;
 #[cfg(feature = "taffy_tree")]
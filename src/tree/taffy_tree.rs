@@ -5,8 +5,10 @@ use slotmap::SecondaryMap;
 use slotmap::SparseSecondaryMap as SecondaryMap;
 use slotmap::{DefaultKey, SlotMap};
 
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Display, Style};
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+use crate::geometry::Rect;
+use crate::geometry::{Point, Size};
+use crate::style::{AvailableSpace, Direction, Display, Position, Style, WritingMode};
 use crate::tree::{
     Cache, ClearState, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode,
     TraversePartialTree, TraverseTree,
@@ -14,21 +16,42 @@ use crate::tree::{
 use crate::util::debug::{debug_log, debug_log_node};
 use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
 
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+use crate::compute::common::containing_block::{
+    compute_absolute_layout_in_containing_block, compute_sticky_offset, overflows_containing_block, AbsolutePlacement,
+    ContainingBlock,
+};
+#[cfg(all(
+    feature = "content_size",
+    any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table")
+))]
+use crate::compute::common::content_size::compute_content_size_contribution;
 use crate::compute::{
     compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    LeafMeasureOutput,
 };
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+use crate::style::PositionTryFallback;
 use crate::CacheTree;
 #[cfg(feature = "block_layout")]
-use crate::{compute::compute_block_layout, LayoutBlockContainer};
+use crate::{compute::compute_block_layout, LayoutBlockContainer, PlacedFloat};
 #[cfg(feature = "flexbox")]
 use crate::{compute::compute_flexbox_layout, LayoutFlexboxContainer};
 #[cfg(feature = "grid")]
-use crate::{compute::compute_grid_layout, LayoutGridContainer};
+use crate::{compute::compute_grid_layout, LayoutGridContainer, SubgridTracks};
+#[cfg(feature = "table")]
+use crate::{compute::compute_table_layout, LayoutTableContainer};
 
+#[cfg(all(feature = "detailed_layout_info", feature = "inline_layout"))]
+use crate::compute::block::DetailedInlineInfo;
 #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
 use crate::compute::grid::DetailedGridInfo;
+#[cfg(feature = "inline_layout")]
+use crate::compute::ShapedTextRun;
 #[cfg(feature = "detailed_layout_info")]
 use crate::tree::layout::DetailedLayoutInfo;
+#[cfg(all(feature = "detailed_layout_info", any(feature = "grid", feature = "inline_layout")))]
+use crate::util::sys::Box;
 
 /// The error Taffy generates on invalid operations
 pub type TaffyResult<T> = Result<T, TaffyError>;
@@ -96,6 +119,14 @@ struct NodeData {
     /// layout to avoid errors from rounding already-rounded values. See <https://github.com/DioxusLabs/taffy/issues/501>.
     pub(crate) unrounded_layout: Layout,
 
+    /// The unrounded layout of the node as computed by its parent. This only differs from `unrounded_layout` for
+    /// absolutely positioned nodes whose containing block is not their parent, for which it determines the static
+    /// position.
+    pub(crate) static_layout: Layout,
+
+    /// The offset by which the contents of the node are scrolled, if it is a scroll container
+    pub(crate) scroll_offset: Point<f32>,
+
     /// The final results of the layout computation.
     /// These may be rounded or unrounded depending on what the `use_rounding` config setting is set to.
     pub(crate) final_layout: Layout,
@@ -106,9 +137,27 @@ struct NodeData {
     /// The cached results of the layout computation
     pub(crate) cache: Cache,
 
+    /// The inline base direction of the node (resolved against its ancestors) when it was last laid out
+    pub(crate) direction: Direction,
+
+    /// The writing mode of the node (resolved against its ancestors) when it was last laid out
+    pub(crate) writing_mode: WritingMode,
+
     /// The computation result from layout algorithm
     #[cfg(feature = "detailed_layout_info")]
     pub(crate) detailed_layout_info: DetailedLayoutInfo,
+
+    /// The tracks inherited from the parent grid if the node is a subgrid
+    #[cfg(feature = "grid")]
+    pub(crate) subgrid_tracks: SubgridTracks,
+
+    /// The floats inherited from the parent block container if the node continues its parent's float context
+    #[cfg(feature = "block_layout")]
+    pub(crate) inherited_floats: Option<Vec<PlacedFloat>>,
+
+    /// The floats placed within the node's float context if it continues its parent's float context
+    #[cfg(feature = "block_layout")]
+    pub(crate) placed_floats: Option<Vec<PlacedFloat>>,
 }
 
 impl NodeData {
@@ -118,20 +167,41 @@ impl NodeData {
         Self {
             style,
             cache: Cache::new(),
+            direction: Direction::Ltr,
+            writing_mode: WritingMode::HorizontalTb,
             unrounded_layout: Layout::new(),
+            static_layout: Layout::new(),
+            scroll_offset: Point::ZERO,
             final_layout: Layout::new(),
             has_context: false,
             #[cfg(feature = "detailed_layout_info")]
             detailed_layout_info: DetailedLayoutInfo::None,
+            #[cfg(feature = "grid")]
+            subgrid_tracks: SubgridTracks::NONE,
+            #[cfg(feature = "block_layout")]
+            inherited_floats: None,
+            #[cfg(feature = "block_layout")]
+            placed_floats: None,
         }
     }
 
+    /// Whether the layout of the node is adjusted by [`TaffyView::compute_absolute_descendants_layout`] once the rest of
+    /// the tree has been laid out
+    fn is_positioned_by_tree(&self) -> bool {
+        matches!(self.style.position, Position::Static | Position::Fixed | Position::Sticky)
+            || self.style.position_anchor.is_some()
+    }
+
     /// Marks a node and all of its ancestors as requiring relayout
     ///
     /// This clears any cached data and signals that the data must be recomputed.
     /// If the node was already marked as dirty, returns true
     #[inline]
     pub fn mark_dirty(&mut self) -> ClearState {
+        #[cfg(feature = "block_layout")]
+        {
+            self.placed_floats = None;
+        }
         self.cache.clear()
     }
 }
@@ -157,6 +227,24 @@ pub struct TaffyTree<NodeContext = ()> {
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     parents: SlotMap<DefaultKey, Option<NodeId>>,
 
+    /// The children of each node that has `display: contents` children, as seen by the layout algorithms (with each
+    /// `display: contents` child replaced by its own children). Recomputed before layout if `layout_children_dirty`.
+    layout_children: SecondaryMap<DefaultKey, ChildrenVec<NodeId>>,
+
+    /// Whether the tree has been modified since `layout_children` was last computed
+    layout_children_dirty: bool,
+
+    /// The number of nodes with `display: contents` in the tree
+    contents_node_count: usize,
+
+    /// The number of nodes in the tree whose layout is adjusted once the rest of the tree has been laid out (see
+    /// [`NodeData::is_positioned_by_tree`])
+    positioned_node_count: usize,
+
+    /// The root node and available space of the last layout in which such nodes were laid out
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    positioned_layout_input: Option<(NodeId, Size<AvailableSpace>)>,
+
     /// Layout mode configuration
     config: TaffyConfig,
 }
@@ -242,6 +330,7 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
 
         match (num_children, display) {
             (_, Display::None) => "NONE",
+            (_, Display::Contents) => "CONTENTS",
             (0, _) => "LEAF",
             #[cfg(feature = "block_layout")]
             (_, Display::Block) => "BLOCK",
@@ -255,6 +344,8 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
             }
             #[cfg(feature = "grid")]
             (_, Display::Grid) => "GRID",
+            #[cfg(feature = "table")]
+            (_, Display::Table) => "TABLE",
         }
     }
 
@@ -271,22 +362,27 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
 /// View over the Taffy tree that holds the tree itself along with a reference to the context
 /// and implements LayoutTree. This allows the context to be stored outside of the TaffyTree struct
 /// which makes the lifetimes of the context much more flexible.
-pub(crate) struct TaffyView<'t, NodeContext, MeasureFunction>
-where
-    MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
-{
+pub(crate) struct TaffyView<'t, NodeContext, MeasureFunction> {
     /// A reference to the TaffyTree
     pub(crate) taffy: &'t mut TaffyTree<NodeContext>,
     /// The context provided for passing to measure functions if layout is run over this struct
     pub(crate) measure_function: MeasureFunction,
+    /// The function used to shape the text of inline-level leaf nodes (if any)
+    #[cfg(feature = "inline_layout")]
+    pub(crate) shape_function: Option<&'t mut ShapeFunction<'t, NodeContext>>,
 }
 
+/// The type of the function used by [`TaffyView`] to shape the text of inline-level leaf nodes
+#[cfg(feature = "inline_layout")]
+pub(crate) type ShapeFunction<'t, NodeContext> =
+    dyn FnMut(NodeId, Option<&mut NodeContext>, &Style) -> Option<ShapedTextRun> + 't;
+
 // TraversePartialTree impl for TaffyView
-impl<NodeContext, MeasureFunction> TraversePartialTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> TraversePartialTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     type ChildIter<'a>
         = TaffyTreeChildIter<'a>
@@ -295,32 +391,35 @@ where
 
     #[inline(always)]
     fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
-        self.taffy.child_ids(parent_node_id)
+        TaffyTreeChildIter(self.taffy.layout_children(parent_node_id).iter())
     }
 
     #[inline(always)]
     fn child_count(&self, parent_node_id: NodeId) -> usize {
-        self.taffy.child_count(parent_node_id)
+        self.taffy.layout_children(parent_node_id).len()
     }
 
     #[inline(always)]
     fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
-        self.taffy.get_child_id(parent_node_id, child_index)
+        self.taffy.layout_children(parent_node_id)[child_index]
     }
 }
 
 // TraverseTree impl for TaffyView
-impl<NodeContext, MeasureFunction> TraverseTree for TaffyView<'_, NodeContext, MeasureFunction> where
+impl<NodeContext, MeasureFunction, MeasureOutput> TraverseTree for TaffyView<'_, NodeContext, MeasureFunction>
+where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
 }
 
 // LayoutPartialTree impl for TaffyView
-impl<NodeContext, MeasureFunction> LayoutPartialTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> LayoutPartialTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     type CoreContainerStyle<'a>
         = &'a Style
@@ -332,9 +431,26 @@ where
         &self.taffy.nodes[node_id.into()].style
     }
 
+    #[inline(always)]
+    fn resolve_direction(&self, node_id: NodeId) -> Direction {
+        self.taffy.nodes[node_id.into()].direction
+    }
+
+    #[inline(always)]
+    fn resolve_writing_mode(&self, node_id: NodeId) -> WritingMode {
+        // Containers query the writing mode of their children before laying them out, so this can't
+        // simply return the writing mode stored by `update_inherited_styles`
+        match self.taffy.nodes[node_id.into()].style.writing_mode {
+            Some(writing_mode) => writing_mode,
+            None => self.taffy.inherited(node_id, |style| style.writing_mode, |data| data.writing_mode),
+        }
+    }
+
     #[inline(always)]
     fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
-        self.taffy.nodes[node_id.into()].unrounded_layout = *layout;
+        let node = &mut self.taffy.nodes[node_id.into()];
+        node.unrounded_layout = *layout;
+        node.static_layout = *layout;
     }
 
     #[inline(always)]
@@ -351,6 +467,8 @@ where
             return compute_hidden_layout(self, node);
         }
 
+        self.taffy.update_inherited_styles(node);
+
         // We run the following wrapped in "compute_cached_layout", which will check the cache for an entry matching the node and inputs and:
         //   - Return that entry if exists
         //   - Else call the passed closure (below) to compute the result
@@ -371,13 +489,17 @@ where
 
             // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
             match (display_mode, has_children) {
-                (Display::None, _) => compute_hidden_layout(tree, node),
+                // A `display: contents` node is only laid out directly if it is the root node, in which case it
+                // generates no boxes at all
+                (Display::None | Display::Contents, _) => compute_hidden_layout(tree, node),
                 #[cfg(feature = "block_layout")]
                 (Display::Block, true) => compute_block_layout(tree, node, inputs),
                 #[cfg(feature = "flexbox")]
                 (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
                 #[cfg(feature = "grid")]
                 (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
+                #[cfg(feature = "table")]
+                (Display::Table, true) => compute_table_layout(tree, node, inputs),
                 (_, false) => {
                     let node_key = node.into();
                     let style = &tree.taffy.nodes[node_key].style;
@@ -394,10 +516,11 @@ where
     }
 }
 
-impl<NodeContext, MeasureFunction> CacheTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> CacheTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     fn cache_get(
         &self,
@@ -426,10 +549,11 @@ where
 }
 
 #[cfg(feature = "block_layout")]
-impl<NodeContext, MeasureFunction> LayoutBlockContainer for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> LayoutBlockContainer for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     type BlockContainerStyle<'a>
         = &'a Style
@@ -449,13 +573,59 @@ where
     fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_> {
         self.get_core_container_style(child_node_id)
     }
+
+    #[cfg(feature = "inline_layout")]
+    fn shape_text_run(&mut self, child_node_id: NodeId) -> Option<ShapedTextRun> {
+        let shape_function = self.shape_function.as_mut()?;
+        if self.taffy.child_count(child_node_id) > 0 {
+            return None;
+        }
+
+        let node_key = child_node_id.into();
+        let style = &self.taffy.nodes[node_key].style;
+        let has_context = self.taffy.nodes[node_key].has_context;
+        let node_context = has_context.then(|| self.taffy.node_context_data.get_mut(node_key)).flatten();
+        shape_function(child_node_id, node_context, style)
+    }
+
+    #[inline(always)]
+    #[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+    fn set_detailed_inline_info(&mut self, node_id: NodeId, detailed_inline_info: DetailedInlineInfo) {
+        self.taffy.nodes[node_id.into()].detailed_layout_info =
+            DetailedLayoutInfo::Inline(Box::new(detailed_inline_info));
+    }
+
+    #[inline(always)]
+    fn get_inherited_floats(&self, node_id: NodeId) -> Option<Vec<PlacedFloat>> {
+        self.taffy.nodes[node_id.into()].inherited_floats.clone()
+    }
+
+    fn set_inherited_floats(&mut self, node_id: NodeId, floats: Option<Vec<PlacedFloat>>) {
+        let node = &mut self.taffy.nodes[node_id.into()];
+        if node.inherited_floats != floats {
+            node.inherited_floats = floats;
+            node.placed_floats = None;
+            node.cache.clear();
+        }
+    }
+
+    #[inline(always)]
+    fn get_placed_floats(&self, node_id: NodeId) -> Option<Vec<PlacedFloat>> {
+        self.taffy.nodes[node_id.into()].placed_floats.clone()
+    }
+
+    #[inline(always)]
+    fn set_placed_floats(&mut self, node_id: NodeId, floats: Vec<PlacedFloat>) {
+        self.taffy.nodes[node_id.into()].placed_floats = Some(floats);
+    }
 }
 
 #[cfg(feature = "flexbox")]
-impl<NodeContext, MeasureFunction> LayoutFlexboxContainer for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> LayoutFlexboxContainer for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     type FlexboxContainerStyle<'a>
         = &'a Style
@@ -478,10 +648,11 @@ where
 }
 
 #[cfg(feature = "grid")]
-impl<NodeContext, MeasureFunction> LayoutGridContainer for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> LayoutGridContainer for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     type GridContainerStyle<'a>
         = &'a Style
@@ -507,13 +678,355 @@ where
     fn set_detailed_grid_info(&mut self, node_id: NodeId, detailed_grid_info: DetailedGridInfo) {
         self.taffy.nodes[node_id.into()].detailed_layout_info = DetailedLayoutInfo::Grid(Box::new(detailed_grid_info));
     }
+
+    #[inline(always)]
+    fn get_subgrid_tracks(&self, node_id: NodeId) -> SubgridTracks {
+        self.taffy.nodes[node_id.into()].subgrid_tracks.clone()
+    }
+
+    fn set_subgrid_tracks(&mut self, node_id: NodeId, tracks: SubgridTracks) {
+        let node = &mut self.taffy.nodes[node_id.into()];
+        if node.subgrid_tracks != tracks {
+            node.subgrid_tracks = tracks;
+            node.cache.clear();
+        }
+    }
+}
+
+#[cfg(feature = "table")]
+impl<NodeContext, MeasureFunction, MeasureOutput> LayoutTableContainer for TaffyView<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
+{
+    type TableContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+    type TableItemStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_table_container_style(&self, node_id: NodeId) -> Self::TableContainerStyle<'_> {
+        &self.taffy.nodes[node_id.into()].style
+    }
+
+    #[inline(always)]
+    fn get_table_child_style(&self, child_node_id: NodeId) -> Self::TableItemStyle<'_> {
+        &self.taffy.nodes[child_node_id.into()].style
+    }
+}
+
+impl<NodeContext, MeasureFunction, MeasureOutput> TaffyView<'_, NodeContext, MeasureFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
+{
+    /// Lays out the absolutely positioned nodes within `root` whose containing block is not their parent, once the rest
+    /// of the tree has been laid out (as the layout algorithms only have access to a node's direct children):
+    ///
+    ///   - Nodes whose parent is `Position::Static` are laid out against the padding box of their nearest positioned
+    ///     ancestor (or of `root`)
+    ///   - `Position::Fixed` nodes are laid out against the viewport, which is given by the `available_space` that the
+    ///     tree was laid out with (or the size of `root` in axes in which the available space is not definite)
+    ///
+    /// Absolutely positioned nodes with a `position_anchor` are laid out relative to their anchor, which is the last
+    /// node with that `anchor_name` preceding them in tree order. The sticky offsets of `Position::Sticky` nodes are
+    /// also computed, from the scroll offsets of their ancestors.
+    ///
+    /// If `relayout` is false then the rest of the tree has not changed since this was last run, so only the sticky
+    /// offsets are updated.
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    fn compute_absolute_descendants_layout(
+        &mut self,
+        root: NodeId,
+        available_space: Size<AvailableSpace>,
+        relayout: bool,
+    ) {
+        let layout = self.taffy.nodes[root.into()].unrounded_layout;
+        let containing_block = ContainingBlock::new(self, root, &layout);
+        let viewport = ContainingBlock {
+            node: root,
+            offset: Point::ZERO,
+            size: Size {
+                width: available_space.width.into_option().unwrap_or(layout.size.width),
+                height: available_space.height.into_option().unwrap_or(layout.size.height),
+            },
+            direction: self.resolve_direction(root),
+        };
+
+        // If the root is not a scroll container, its scroll offset is that of the viewport
+        let scrollport = self.scrollport(root, &containing_block).unwrap_or_else(|| {
+            let scroll_offset = self.taffy.nodes[root.into()].scroll_offset - layout.location;
+            Rect {
+                left: scroll_offset.x,
+                right: scroll_offset.x + viewport.size.width,
+                top: scroll_offset.y,
+                bottom: scroll_offset.y + viewport.size.height,
+            }
+        });
+
+        let context = PositioningContext {
+            containing_block,
+            containing_block_offset: Point::ZERO,
+            viewport_offset: layout.location,
+            scrollport,
+        };
+        let mut anchors = Vec::new();
+        if relayout && self.taffy.nodes[root.into()].style.anchor_name.is_some() {
+            let location = layout.location;
+            let size = layout.size;
+            let border_box = Rect {
+                left: location.x,
+                right: location.x + size.width,
+                top: location.y,
+                bottom: location.y + size.height,
+            };
+            anchors.push((root, border_box));
+        }
+        self.compute_absolute_descendants_layout_within(root, &context, &viewport, relayout, &mut anchors);
+    }
+
+    /// Recursive helper for [`Self::compute_absolute_descendants_layout`]
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    fn compute_absolute_descendants_layout_within(
+        &mut self,
+        node: NodeId,
+        context: &PositioningContext,
+        viewport: &ContainingBlock,
+        relayout: bool,
+        anchors: &mut Vec<(NodeId, Rect<f32>)>,
+    ) {
+        let direction = self.resolve_direction(node);
+        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+        let is_positioned = self.taffy.nodes[node.into()].style.position.is_positioned();
+        let node_layout = self.taffy.nodes[node.into()].unrounded_layout;
+        let padding_box = ContainingBlock::new(self, node, &node_layout);
+        let content_box = Rect {
+            left: padding_box.offset.x + node_layout.padding.left,
+            right: padding_box.offset.x + padding_box.size.width - node_layout.padding.right,
+            top: padding_box.offset.y + node_layout.padding.top,
+            bottom: padding_box.offset.y + padding_box.size.height - node_layout.padding.bottom,
+        };
+
+        for index in 0..self.child_count(node) {
+            let child = self.get_child_id(node, index);
+            let child_style = &self.taffy.nodes[child.into()].style;
+            if child_style.display == Display::None {
+                continue;
+            }
+            let position = child_style.position;
+            #[cfg(feature = "content_size")]
+            let overflow = child_style.overflow;
+
+            let anchor = match relayout && position.is_absolutely_positioned() {
+                true => self.find_anchor(child, anchors),
+                false => None,
+            };
+
+            // The absolutely positioned children of positioned nodes have already been laid out by their parent
+            // (unless they are positioned relative to an anchor)
+            let relayout_against = match position {
+                _ if !relayout => None,
+                Position::Absolute if context.containing_block.node != node || anchor.is_some() => {
+                    Some((&context.containing_block, context.containing_block_offset))
+                }
+                Position::Fixed => Some((viewport, context.viewport_offset)),
+                _ => None,
+            };
+            if let Some((containing_block, offset)) = relayout_against {
+                let static_layout = self.taffy.nodes[child.into()].static_layout;
+                let static_layout = Layout { location: static_layout.location + offset, ..static_layout };
+                let layout = match anchor {
+                    Some(anchor) => {
+                        // Anchors are recorded relative to the viewport, so are translated to be relative to the
+                        // padding box of the containing block
+                        let origin = context.viewport_offset - offset + containing_block.offset;
+                        let anchor = Rect {
+                            left: anchor.left - origin.x,
+                            right: anchor.right - origin.x,
+                            top: anchor.top - origin.y,
+                            bottom: anchor.bottom - origin.y,
+                        };
+                        self.compute_anchored_layout(child, containing_block, anchor, &static_layout, direction)
+                    }
+                    None => {
+                        let placement = AbsolutePlacement::new(self, child, containing_block);
+                        compute_absolute_layout_in_containing_block(
+                            self,
+                            child,
+                            containing_block,
+                            &placement,
+                            &static_layout,
+                            direction,
+                        )
+                    }
+                };
+                self.taffy.nodes[child.into()].unrounded_layout =
+                    Layout { location: layout.location - offset, ..layout };
+            }
+
+            // The absolutely positioned children of `Position::Static` nodes (which their parent excludes from its
+            // content size) and of anchor-positioned nodes contribute to the content size of their containing block
+            #[cfg(feature = "content_size")]
+            if relayout && position == Position::Absolute && (!is_positioned || anchor.is_some()) {
+                let layout = self.taffy.nodes[child.into()].unrounded_layout;
+                let contribution = compute_content_size_contribution(
+                    layout.location + context.containing_block_offset,
+                    layout.size,
+                    layout.content_size,
+                    overflow,
+                );
+                let containing_block_layout =
+                    &mut self.taffy.nodes[context.containing_block.node.into()].unrounded_layout;
+                containing_block_layout.content_size = containing_block_layout.content_size.f32_max(contribution);
+            }
+
+            if position == Position::Sticky {
+                let layout = self.taffy.nodes[child.into()].unrounded_layout;
+                let sticky_offset = compute_sticky_offset(self, child, &layout, content_box, context.scrollport);
+                self.taffy.nodes[child.into()].unrounded_layout.sticky_offset = sticky_offset;
+            }
+
+            let layout = self.taffy.nodes[child.into()].unrounded_layout;
+            if relayout && self.taffy.nodes[child.into()].style.anchor_name.is_some() {
+                let location = context.viewport_offset + layout.location;
+                let size = layout.size;
+                let border_box = Rect {
+                    left: location.x,
+                    right: location.x + size.width,
+                    top: location.y,
+                    bottom: location.y + size.height,
+                };
+                anchors.push((child, border_box));
+            }
+
+            let child_containing_block = ContainingBlock::new(self, child, &layout);
+            let child_context = PositioningContext {
+                containing_block: match position {
+                    Position::Static => context.containing_block,
+                    _ => child_containing_block,
+                },
+                containing_block_offset: match position {
+                    Position::Static => context.containing_block_offset + layout.location,
+                    _ => Point::ZERO,
+                },
+                viewport_offset: context.viewport_offset + layout.location,
+                scrollport: self.scrollport(child, &child_containing_block).unwrap_or(Rect {
+                    left: context.scrollport.left - layout.location.x,
+                    right: context.scrollport.right - layout.location.x,
+                    top: context.scrollport.top - layout.location.y,
+                    bottom: context.scrollport.bottom - layout.location.y,
+                }),
+            };
+            self.compute_absolute_descendants_layout_within(child, &child_context, viewport, relayout, anchors);
+        }
+    }
+
+    /// The border box (relative to the viewport) of the anchor of the absolutely positioned `node`, if it has one
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    fn find_anchor(&self, node: NodeId, anchors: &[(NodeId, Rect<f32>)]) -> Option<Rect<f32>> {
+        let name = self.taffy.nodes[node.into()].style.position_anchor.as_deref()?;
+        anchors
+            .iter()
+            .rev()
+            .find(|(anchor, _)| self.taffy.nodes[(*anchor).into()].style.anchor_name.as_deref() == Some(name))
+            .map(|(_, border_box)| *border_box)
+    }
+
+    /// Lays out the anchor-positioned `node` within `containing_block`, given the border box of its anchor relative to
+    /// the padding box of the containing block. If the node overflows the containing block then each of its
+    /// `position_try_fallbacks` is tried in turn.
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    fn compute_anchored_layout(
+        &mut self,
+        node: NodeId,
+        containing_block: &ContainingBlock,
+        anchor: Rect<f32>,
+        static_layout: &Layout,
+        static_direction: Direction,
+    ) -> Layout {
+        let position_area = self.taffy.nodes[node.into()].style.position_area;
+        let fallback_count = self.taffy.nodes[node.into()].style.position_try_fallbacks.len();
+        let is_vertical = self.resolve_writing_mode(node).is_vertical();
+
+        let compute_layout = |tree: &mut Self, position_area, flip| {
+            let placement = AbsolutePlacement::anchored(tree, node, containing_block, anchor, position_area, flip);
+            compute_absolute_layout_in_containing_block(
+                tree,
+                node,
+                containing_block,
+                &placement,
+                static_layout,
+                static_direction,
+            )
+        };
+
+        let no_flip = Point { x: false, y: false };
+        let layout = compute_layout(self, position_area, no_flip);
+        if fallback_count == 0 || !overflows_containing_block(&layout, containing_block) {
+            return layout;
+        }
+        for index in 0..fallback_count {
+            let (position_area, flip) = match self.taffy.nodes[node.into()].style.position_try_fallbacks[index] {
+                PositionTryFallback::FlipBlock => (position_area, Point { x: is_vertical, y: !is_vertical }),
+                PositionTryFallback::FlipInline => (position_area, Point { x: !is_vertical, y: is_vertical }),
+                PositionTryFallback::FlipBlockAndInline => (position_area, Point { x: true, y: true }),
+                PositionTryFallback::PositionArea(position_area) => (Some(position_area), no_flip),
+            };
+            let layout = compute_layout(self, position_area, flip);
+            if !overflows_containing_block(&layout, containing_block) {
+                return layout;
+            }
+        }
+
+        // The node doesn't fit in any of its fallback positions, so it is laid out in its own position again (so that
+        // the layout of its descendants matches)
+        compute_layout(self, position_area, no_flip)
+    }
+
+    /// The edges of the visible area of `node` relative to its border box, given its scroll offset and its padding box,
+    /// or `None` if the node is not a scroll container
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    fn scrollport(&self, node: NodeId, padding_box: &ContainingBlock) -> Option<Rect<f32>> {
+        let node_data = &self.taffy.nodes[node.into()];
+        let overflow = node_data.style.overflow;
+        if !overflow.x.is_scroll_container() && !overflow.y.is_scroll_container() {
+            return None;
+        }
+        let origin = padding_box.offset + node_data.scroll_offset;
+        Some(Rect {
+            left: origin.x,
+            right: origin.x + padding_box.size.width,
+            top: origin.y,
+            bottom: origin.y + padding_box.size.height,
+        })
+    }
+}
+
+/// The ancestors of a node which determine the layout of its absolutely positioned and sticky children
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+struct PositioningContext {
+    /// The containing block of the node's absolutely positioned children
+    containing_block: ContainingBlock,
+    /// The position of the node relative to the node establishing `containing_block`
+    containing_block_offset: Point<f32>,
+    /// The position of the node relative to the viewport
+    viewport_offset: Point<f32>,
+    /// The edges of the visible area of the node's nearest scroll container (or of the viewport) relative to the node
+    scrollport: Rect<f32>,
 }
 
 // RoundTree impl for TaffyView
-impl<NodeContext, MeasureFunction> RoundTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, MeasureOutput> RoundTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+    MeasureOutput: Into<LeafMeasureOutput>,
 {
     #[inline(always)]
     fn get_unrounded_layout(&self, node: NodeId) -> &Layout {
@@ -546,6 +1059,12 @@ impl<NodeContext> TaffyTree<NodeContext> {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             node_context_data: SecondaryMap::with_capacity(capacity),
+            layout_children: SecondaryMap::new(),
+            layout_children_dirty: false,
+            contents_node_count: 0,
+            positioned_node_count: 0,
+            #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+            positioned_layout_input: None,
             config: TaffyConfig::default(),
         }
     }
@@ -562,7 +1081,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Creates and adds a new unattached leaf node to the tree, and returns the node of the new node
     pub fn new_leaf(&mut self, layout: Style) -> TaffyResult<NodeId> {
-        let id = self.nodes.insert(NodeData::new(layout));
+        self.update_contents_node_count(None, Some(layout.display));
+        let data = NodeData::new(layout);
+        self.update_positioned_node_count(false, data.is_positioned_by_tree());
+        let id = self.nodes.insert(data);
         let _ = self.children.insert(new_vec_with_capacity(0));
         let _ = self.parents.insert(None);
 
@@ -573,8 +1095,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
     ///
     /// Creates and adds a new leaf node with a supplied context
     pub fn new_leaf_with_context(&mut self, layout: Style, context: NodeContext) -> TaffyResult<NodeId> {
+        self.update_contents_node_count(None, Some(layout.display));
         let mut data = NodeData::new(layout);
         data.has_context = true;
+        self.update_positioned_node_count(false, data.is_positioned_by_tree());
 
         let id = self.nodes.insert(data);
         self.node_context_data.insert(id, context);
@@ -587,7 +1111,11 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Creates and adds a new node, which may have any number of `children`
     pub fn new_with_children(&mut self, layout: Style, children: &[NodeId]) -> TaffyResult<NodeId> {
-        let id = NodeId::from(self.nodes.insert(NodeData::new(layout)));
+        self.update_contents_node_count(None, Some(layout.display));
+        self.layout_children_dirty = true;
+        let data = NodeData::new(layout);
+        self.update_positioned_node_count(false, data.is_positioned_by_tree());
+        let id = NodeId::from(self.nodes.insert(data));
 
         for child in children {
             self.parents[(*child).into()] = Some(id);
@@ -604,6 +1132,9 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        self.layout_children.clear();
+        self.contents_node_count = 0;
+        self.positioned_node_count = 0;
     }
 
     /// Remove a specific node from the tree and drop it
@@ -626,7 +1157,11 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         let _ = self.children.remove(key);
         let _ = self.parents.remove(key);
-        let _ = self.nodes.remove(key);
+        if let Some(data) = self.nodes.remove(key) {
+            self.update_contents_node_count(Some(data.style.display), None);
+            self.update_positioned_node_count(data.is_positioned_by_tree(), false);
+        }
+        self.layout_children_dirty = true;
 
         Ok(node)
     }
@@ -826,8 +1361,13 @@ impl<NodeContext> TaffyTree<NodeContext> {
     /// Sets the [`Style`] of the provided `node`
     #[inline]
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
-        self.nodes[node.into()].style = style;
+        self.update_contents_node_count(Some(self.nodes[node.into()].style.display), Some(style.display));
+        // The node is marked as dirty before its style is replaced so that, if it was `display: contents`, its
+        // ancestors are marked as dirty too
         self.mark_dirty(node)?;
+        let was_positioned = self.nodes[node.into()].is_positioned_by_tree();
+        self.nodes[node.into()].style = style;
+        self.update_positioned_node_count(was_positioned, self.nodes[node.into()].is_positioned_by_tree());
         Ok(())
     }
 
@@ -847,6 +1387,23 @@ impl<NodeContext> TaffyTree<NodeContext> {
         }
     }
 
+    /// Sets the offset by which the contents of the provided scroll container `node` are scrolled
+    ///
+    /// This only affects the `sticky_offset` of `Position::Sticky` descendants of the node, and so does not mark the
+    /// node as dirty. The sticky offsets are updated by the next call to [`TaffyTree::compute_layout`], which does not
+    /// need to lay out any node again if nothing else has changed.
+    #[inline]
+    pub fn set_scroll_offset(&mut self, node: NodeId, scroll_offset: Point<f32>) -> TaffyResult<()> {
+        self.nodes[node.into()].scroll_offset = scroll_offset;
+        Ok(())
+    }
+
+    /// Gets the scroll offset of the provided `node` (see [`TaffyTree::set_scroll_offset`])
+    #[inline]
+    pub fn scroll_offset(&self, node: NodeId) -> TaffyResult<Point<f32>> {
+        Ok(self.nodes[node.into()].scroll_offset)
+    }
+
     /// Returns this node layout with unrounded values relative to its parent.
     #[inline]
     pub fn unrounded_layout(&self, node: NodeId) -> &Layout {
@@ -855,8 +1412,9 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Get the "detailed layout info" for a node.
     ///
-    /// Currently this is only implemented for CSS Grid containers where it contains
-    /// the computed size of each grid track and the computed placement of each grid item
+    /// Currently this is only implemented for:
+    ///   - CSS Grid containers, where it contains the computed size of each grid track and the computed placement of each grid item
+    ///   - Block containers with inline-level children, where it contains the line boxes and the fragments placed within them
     #[cfg(feature = "detailed_layout_info")]
     #[inline]
     pub fn detailed_layout_info(&self, node_id: NodeId) -> &DetailedLayoutInfo {
@@ -871,12 +1429,14 @@ impl<NodeContext> TaffyTree<NodeContext> {
             node_key: DefaultKey,
         ) {
             match nodes[node_key].mark_dirty() {
-                ClearState::AlreadyEmpty => {
+                // A `display: contents` node is never laid out itself (so never has a cached layout), but its children
+                // are laid out by its parent
+                ClearState::AlreadyEmpty if nodes[node_key].style.display != Display::Contents => {
                     // Node was already marked as dirty.
                     // No need to visit ancestors
                     // as they should be marked as dirty already.
                 }
-                ClearState::Cleared => {
+                _ => {
                     if let Some(Some(node)) = parents.get(node_key) {
                         mark_dirty_recursive(nodes, parents, (*node).into());
                     }
@@ -885,10 +1445,134 @@ impl<NodeContext> TaffyTree<NodeContext> {
         }
 
         mark_dirty_recursive(&mut self.nodes, &self.parents, node.into());
+        self.layout_children_dirty = true;
 
         Ok(())
     }
 
+    /// Keep track of the number of `display: contents` nodes when a node is added, removed or restyled
+    fn update_contents_node_count(&mut self, old_display: Option<Display>, new_display: Option<Display>) {
+        let was_contents = old_display == Some(Display::Contents);
+        let is_contents = new_display == Some(Display::Contents);
+        if was_contents != is_contents {
+            if is_contents {
+                self.contents_node_count += 1;
+            } else {
+                self.contents_node_count -= 1;
+            }
+            self.layout_children_dirty = true;
+        }
+    }
+
+    /// Keep track of the number of nodes that are positioned by [`TaffyView::compute_absolute_descendants_layout`] when
+    /// a node is added, removed or restyled
+    fn update_positioned_node_count(&mut self, was_positioned: bool, is_positioned: bool) {
+        if was_positioned != is_positioned {
+            if is_positioned {
+                self.positioned_node_count += 1;
+            } else {
+                self.positioned_node_count -= 1;
+            }
+        }
+    }
+
+    /// Resolve the inherited styles (direction and writing mode) of a node before it is laid out. Nodes are laid out
+    /// top-down, so the values stored for the node's parent are already up to date. If a resolved value has changed
+    /// since the node was last laid out then its cached layouts are cleared.
+    fn update_inherited_styles(&mut self, node: NodeId) {
+        let key = node.into();
+        let direction = match self.nodes[key].style.direction {
+            Some(direction) => direction,
+            None => self.inherited(node, |style| style.direction, |data| data.direction),
+        };
+        let writing_mode = match self.nodes[key].style.writing_mode {
+            Some(writing_mode) => writing_mode,
+            None => self.inherited(node, |style| style.writing_mode, |data| data.writing_mode),
+        };
+        let data = &mut self.nodes[key];
+        if data.direction != direction || data.writing_mode != writing_mode {
+            data.direction = direction;
+            data.writing_mode = writing_mode;
+            data.cache.clear();
+        }
+    }
+
+    /// The value of an inherited style that a node inherits from its parent. `display: contents` ancestors are never
+    /// laid out, so their value is resolved from their style.
+    fn inherited<T: Default>(
+        &self,
+        node: NodeId,
+        specified: impl Fn(&Style) -> Option<T>,
+        resolved: impl Fn(&NodeData) -> T,
+    ) -> T {
+        let mut parent = self.parents[node.into()];
+        while let Some(parent_id) = parent {
+            let parent_data = &self.nodes[parent_id.into()];
+            if parent_data.style.display != Display::Contents {
+                return resolved(parent_data);
+            }
+            if let Some(value) = specified(&parent_data.style) {
+                return value;
+            }
+            parent = self.parents[parent_id.into()];
+        }
+        T::default()
+    }
+
+    /// The children of a node as seen by the layout algorithms, with each `display: contents` child replaced by
+    /// its own children
+    #[inline(always)]
+    fn layout_children(&self, parent: NodeId) -> &ChildrenVec<NodeId> {
+        let key = parent.into();
+        self.layout_children.get(key).unwrap_or(&self.children[key])
+    }
+
+    /// Recompute the children of nodes with `display: contents` children (as seen by the layout algorithms), and give
+    /// each `display: contents` node a zero-sized layout.
+    fn update_layout_children(&mut self) {
+        if !self.layout_children_dirty {
+            return;
+        }
+        self.layout_children_dirty = false;
+        if !self.layout_children.is_empty() {
+            self.layout_children.clear();
+        }
+        if self.contents_node_count == 0 {
+            return;
+        }
+
+        fn push_layout_children(
+            nodes: &SlotMap<DefaultKey, NodeData>,
+            children: &SlotMap<DefaultKey, ChildrenVec<NodeId>>,
+            parent_key: DefaultKey,
+            layout_children: &mut ChildrenVec<NodeId>,
+        ) {
+            for &child in children[parent_key].iter() {
+                if nodes[child.into()].style.display == Display::Contents {
+                    push_layout_children(nodes, children, child.into(), layout_children);
+                } else {
+                    layout_children.push(child);
+                }
+            }
+        }
+
+        for (key, node_children) in self.children.iter() {
+            let is_contents = |child: &NodeId| self.nodes[(*child).into()].style.display == Display::Contents;
+            if node_children.iter().any(is_contents) {
+                let mut layout_children = new_vec_with_capacity(node_children.len());
+                push_layout_children(&self.nodes, &self.children, key, &mut layout_children);
+                self.layout_children.insert(key, layout_children);
+            }
+        }
+
+        for (_, node) in self.nodes.iter_mut() {
+            if node.style.display == Display::Contents {
+                node.unrounded_layout = Layout::new();
+                node.final_layout = Layout::new();
+            }
+        }
+    }
+
     /// Indicates whether the layout of this node needs to be recomputed
     #[inline]
     pub fn dirty(&self, node: NodeId) -> TaffyResult<bool> {
@@ -896,7 +1580,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
     }
 
     /// Updates the stored layout of the provided `node` and its children
-    pub fn compute_layout_with_measure<MeasureFunction>(
+    pub fn compute_layout_with_measure<MeasureFunction, MeasureOutput>(
         &mut self,
         node_id: NodeId,
         available_space: Size<AvailableSpace>,
@@ -904,17 +1588,80 @@ impl<NodeContext> TaffyTree<NodeContext> {
     ) -> Result<(), TaffyError>
     where
         MeasureFunction:
-            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+        MeasureOutput: Into<LeafMeasureOutput>,
     {
+        self.update_layout_children();
         let use_rounding = self.config.use_rounding;
-        let mut taffy_view = TaffyView { taffy: self, measure_function };
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+        let positioning = self.positioning_pass(node_id, available_space);
+        let mut taffy_view = TaffyView {
+            taffy: self,
+            measure_function,
+            #[cfg(feature = "inline_layout")]
+            shape_function: None,
+        };
         compute_root_layout(&mut taffy_view, node_id, available_space);
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+        if let Some(relayout) = positioning {
+            taffy_view.compute_absolute_descendants_layout(node_id, available_space, relayout);
+        }
         if use_rounding {
             round_layout(&mut taffy_view, node_id);
         }
         Ok(())
     }
 
+    /// Updates the stored layout of the provided `node` and its children.
+    ///
+    /// The `shape_function` is used to shape the text of inline-level leaf nodes (see [`DisplayOutside::Inline`](crate::DisplayOutside::Inline)).
+    /// Leaf nodes for which it returns `None` are laid out as atomic inline boxes, and sized using the `measure_function`.
+    #[cfg(feature = "inline_layout")]
+    pub fn compute_layout_with_measure_and_shape<MeasureFunction, MeasureOutput, ShapeFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+        mut shape_function: ShapeFunction,
+    ) -> Result<(), TaffyError>
+    where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> MeasureOutput,
+        MeasureOutput: Into<LeafMeasureOutput>,
+        ShapeFunction: FnMut(NodeId, Option<&mut NodeContext>, &Style) -> Option<ShapedTextRun>,
+    {
+        self.update_layout_children();
+        let use_rounding = self.config.use_rounding;
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+        let positioning = self.positioning_pass(node_id, available_space);
+        let mut taffy_view = TaffyView { taffy: self, measure_function, shape_function: Some(&mut shape_function) };
+        compute_root_layout(&mut taffy_view, node_id, available_space);
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+        if let Some(relayout) = positioning {
+            taffy_view.compute_absolute_descendants_layout(node_id, available_space, relayout);
+        }
+        if use_rounding {
+            round_layout(&mut taffy_view, node_id);
+        }
+        Ok(())
+    }
+
+    /// Determines whether [`TaffyView::compute_absolute_descendants_layout`] needs to run for a layout of `root` with
+    /// `available_space`: `None` if the tree has no nodes that it positions, otherwise whether those nodes need to be
+    /// laid out again. They don't if the layout of `root` is cached and was computed with the same available space, in
+    /// which case only the sticky offsets (which depend on scroll offsets) are updated.
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout", feature = "table"))]
+    fn positioning_pass(&mut self, root: NodeId, available_space: Size<AvailableSpace>) -> Option<bool> {
+        if self.positioned_node_count == 0 {
+            self.positioned_layout_input = None;
+            return None;
+        }
+        let layout_input = Some((root, available_space));
+        let is_cached = !self.nodes[root.into()].cache.is_empty() && self.positioned_layout_input == layout_input;
+        self.positioned_layout_input = layout_input;
+        Some(!is_cached)
+    }
+
     /// Updates the stored layout of the provided `node` and its children
     pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
         self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
@@ -929,7 +1676,12 @@ impl<NodeContext> TaffyTree<NodeContext> {
     /// Returns an instance of LayoutTree representing the TaffyTree
     #[cfg(test)]
     pub(crate) fn as_layout_tree(&mut self) -> impl LayoutPartialTree + CacheTree + '_ {
-        TaffyView { taffy: self, measure_function: |_, _, _, _, _| Size::ZERO }
+        TaffyView {
+            taffy: self,
+            measure_function: |_, _, _, _: Option<&mut NodeContext>, _: &Style| Size::ZERO,
+            #[cfg(feature = "inline_layout")]
+            shape_function: None,
+        }
     }
 }
 
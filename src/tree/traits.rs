@@ -130,16 +130,28 @@ use super::{Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, S
 #[cfg(feature = "detailed_layout_info")]
 use crate::debug::debug_log;
 use crate::geometry::{AbsoluteAxis, Line, Size};
-use crate::style::{AvailableSpace, CoreStyle};
+use crate::style::{AvailableSpace, CoreStyle, Direction, WritingMode};
 #[cfg(feature = "flexbox")]
 use crate::style::{FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "grid")]
 use crate::style::{GridContainerStyle, GridItemStyle};
+#[cfg(feature = "table")]
+use crate::style::{TableContainerStyle, TableItemStyle};
 #[cfg(feature = "block_layout")]
 use crate::{BlockContainerStyle, BlockItemStyle};
 
+#[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+use crate::compute::block::DetailedInlineInfo;
+#[cfg(feature = "block_layout")]
+use crate::compute::block::PlacedFloat;
+#[cfg(feature = "inline_layout")]
+use crate::compute::block::ShapedTextRun;
 #[cfg(all(feature = "grid", feature = "detailed_layout_info"))]
 use crate::compute::grid::DetailedGridInfo;
+#[cfg(feature = "grid")]
+use crate::compute::grid::SubgridTracks;
+#[cfg(feature = "block_layout")]
+use crate::util::sys::Vec;
 
 /// Taffy's abstraction for downward tree traversal.
 ///
@@ -188,6 +200,24 @@ pub trait LayoutPartialTree: TraversePartialTree {
         0.0
     }
 
+    /// Resolve the inline base direction of the node, taking inheritance from the node's ancestors into account
+    ///
+    /// The default implementation only considers the node's own style (treating an unset direction as
+    /// [`Direction::Ltr`]). Trees that can access a node's ancestors should override this to implement inheritance.
+    #[inline(always)]
+    fn resolve_direction(&self, node_id: NodeId) -> Direction {
+        self.get_core_container_style(node_id).direction().unwrap_or_default()
+    }
+
+    /// Resolve the writing mode of the node, taking inheritance from the node's ancestors into account
+    ///
+    /// The default implementation only considers the node's own style (treating an unset writing mode as
+    /// [`WritingMode::HorizontalTb`]). Trees that can access a node's ancestors should override this to implement inheritance.
+    #[inline(always)]
+    fn resolve_writing_mode(&self, node_id: NodeId) -> WritingMode {
+        self.get_core_container_style(node_id).writing_mode().unwrap_or_default()
+    }
+
     /// Set the node's unrounded layout
     fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout);
 
@@ -289,6 +319,20 @@ pub trait LayoutGridContainer: LayoutPartialTree {
     fn set_detailed_grid_info(&mut self, _node_id: NodeId, _detailed_grid_info: DetailedGridInfo) {
         debug_log!("LayoutGridContainer::set_detailed_grid_info called");
     }
+
+    /// Get the tracks that a subgrid has inherited from its parent grid (see [`LayoutGridContainer::set_subgrid_tracks`])
+    ///
+    /// Implementing this method (along with `set_subgrid_tracks`) is optional. If it is not implemented then subgrids are
+    /// laid out using their own tracks, although their children still contribute to the sizing of the parent grid's tracks.
+    fn get_subgrid_tracks(&self, _node_id: NodeId) -> SubgridTracks {
+        SubgridTracks::NONE
+    }
+
+    /// Set the tracks that a subgrid inherits from its parent grid
+    ///
+    /// This is called by the parent grid before the subgrid is laid out. As the inherited tracks are an input to the
+    /// subgrid's layout, implementations should clear the subgrid's layout cache if they have changed.
+    fn set_subgrid_tracks(&mut self, _node_id: NodeId, _tracks: SubgridTracks) {}
 }
 
 #[cfg(feature = "block_layout")]
@@ -308,6 +352,79 @@ pub trait LayoutBlockContainer: LayoutPartialTree {
 
     /// Get the child's styles
     fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_>;
+
+    /// Shape the text content of an inline-level child node into segments that can be laid out into line boxes.
+    ///
+    /// Returning `None` indicates that the node is not a text run, in which case it is laid out as an atomic inline box
+    /// (sized using [`LayoutPartialTree::compute_child_layout`]). The default implementation treats all inline-level
+    /// nodes as atomic inline boxes.
+    #[cfg(feature = "inline_layout")]
+    fn shape_text_run(&mut self, _child_node_id: NodeId) -> Option<ShapedTextRun> {
+        None
+    }
+
+    /// Set the node's detailed inline layout information
+    ///
+    /// Implementing this method is optional. Doing so allows you to access the line boxes generated for the node's
+    /// inline-level children, including the position of each fragment of each text run.
+    #[cfg(all(feature = "inline_layout", feature = "detailed_layout_info"))]
+    fn set_detailed_inline_info(&mut self, _node_id: NodeId, _detailed_inline_info: DetailedInlineInfo) {
+        debug_log!("LayoutBlockContainer::set_detailed_inline_info called");
+    }
+
+    /// Get the floats that a block container has inherited from its parent (see [`LayoutBlockContainer::set_inherited_floats`])
+    ///
+    /// Implementing this method (along with `set_inherited_floats`, `get_placed_floats` and `set_placed_floats`) is
+    /// optional. If it is not implemented then every block container establishes a new block formatting context, and
+    /// so grows to contain its own floats and is shortened to fit beside the floats of its parent.
+    fn get_inherited_floats(&self, _node_id: NodeId) -> Option<Vec<PlacedFloat>> {
+        None
+    }
+
+    /// Set the floats that a block container inherits from its parent
+    ///
+    /// This is called by the parent before each block-level child is laid out. Children which don't establish a new
+    /// block formatting context inherit the floats placed so far (relative to the child's border box), and other
+    /// children inherit `None`. As the inherited floats are an input to the child's layout, implementations should
+    /// clear the child's layout cache (and any floats set by `set_placed_floats`) if they have changed.
+    fn set_inherited_floats(&mut self, _node_id: NodeId, _floats: Option<Vec<PlacedFloat>>) {}
+
+    /// Get the floats placed within a block container that inherited floats from its parent (see
+    /// [`LayoutBlockContainer::set_placed_floats`])
+    fn get_placed_floats(&self, _node_id: NodeId) -> Option<Vec<PlacedFloat>> {
+        None
+    }
+
+    /// Set the floats placed within a block container that inherited floats from its parent
+    ///
+    /// This is called once the container has been laid out, with its inherited floats followed by the floats placed
+    /// among its descendants (relative to its border box). The parent reads them back to continue placing floats.
+    fn set_placed_floats(&mut self, _node_id: NodeId, _floats: Vec<PlacedFloat>) {}
+}
+
+#[cfg(feature = "table")]
+/// Extends [`LayoutPartialTree`] with getters for the styles required for CSS Table layout
+///
+/// Unlike the other layout algorithms, table layout needs to look beyond the container's immediate children:
+/// the rows of row groups and the cells of rows are all part of the table's formatting context. Implementors of
+/// this trait must therefore support calling [`TraversePartialTree::child_ids`] (and the other traversal methods)
+/// on the table's row groups and rows. Cells are laid out using [`LayoutPartialTree::compute_child_layout`] as usual.
+pub trait LayoutTableContainer: LayoutPartialTree {
+    /// The style type representing the CSS Table container's styles
+    type TableContainerStyle<'a>: TableContainerStyle
+    where
+        Self: 'a;
+
+    /// The style type representing the styles of each CSS Table row group, row and cell
+    type TableItemStyle<'a>: TableItemStyle
+    where
+        Self: 'a;
+
+    /// Get the container's styles
+    fn get_table_container_style(&self, node_id: NodeId) -> Self::TableContainerStyle<'_>;
+
+    /// Get the styles of a row group, row or cell within the table
+    fn get_table_child_style(&self, child_node_id: NodeId) -> Self::TableItemStyle<'_>;
 }
 
 // --- PRIVATE TRAITS
@@ -47,6 +47,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
             CompactLength::AUTO_TAG => None,
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            // anchor() values can only be resolved once the anchor has been laid out
+            _ if self.0.is_anchor() => None,
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
@@ -65,6 +67,9 @@ impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
+            // Intrinsic sizing keywords can only be resolved by sizing the box's contents, and stretch values can only
+            // be resolved by the parent's layout algorithm (which knows the box's margins and containing block)
+            _ if self.is_intrinsic_sizing_keyword() || self.is_stretch() => None,
             _ => unreachable!(),
         }
     }
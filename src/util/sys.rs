@@ -19,9 +19,15 @@ mod std {
     pub(crate) type Vec<A> = std::vec::Vec<A>;
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = std::vec::Vec<A>;
+    /// An allocation-backend agnostic box type
+    #[allow(dead_code)]
+    pub(crate) type Box<A> = std::boxed::Box<A>;
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = std::vec::Vec<A>;
+    #[cfg(any(feature = "grid", feature = "taffy_tree"))]
+    /// An allocation-backend agnostic string type
+    pub(crate) type String = std::string::String;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -80,9 +86,15 @@ mod alloc {
     pub(crate) type Vec<A> = alloc::vec::Vec<A>;
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = alloc::vec::Vec<A>;
+    /// An allocation-backend agnostic box type
+    #[allow(dead_code)]
+    pub(crate) type Box<A> = alloc::boxed::Box<A>;
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = alloc::vec::Vec<A>;
+    #[cfg(any(feature = "grid", feature = "taffy_tree"))]
+    /// An allocation-backend agnostic string type
+    pub(crate) type String = alloc::string::String;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod anchor_positioning {
+    use taffy::prelude::*;
+    use taffy::{AnchorSide, Point, PositionArea, PositionAreaSpan, PositionTryFallback};
+
+    /// A 400x300 root containing an 80x20 node named "button" at `button_location`, followed by `tooltip`
+    fn anchored(taffy: &mut TaffyTree, button_location: Point<f32>, tooltip: Style) -> NodeId {
+        let button = taffy
+            .new_leaf(Style {
+                anchor_name: Some("button".into()),
+                margin: Rect {
+                    left: length(button_location.x),
+                    right: zero(),
+                    top: length(button_location.y),
+                    bottom: zero(),
+                },
+                size: Size { width: length(80.0), height: length(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let tooltip = taffy.new_leaf(Style { position: Position::Absolute, ..tooltip }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    align_items: Some(AlignItems::Start),
+                    size: Size { width: length(400.0), height: length(300.0) },
+                    ..Default::default()
+                },
+                &[button, tooltip],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        tooltip
+    }
+
+    /// Insets that place the top-left corner of the node at the bottom-left corner of its anchor
+    fn below_anchor() -> Rect<LengthPercentageAuto> {
+        Rect {
+            left: LengthPercentageAuto::anchor(AnchorSide::Start),
+            right: auto(),
+            top: LengthPercentageAuto::anchor(AnchorSide::End),
+            bottom: auto(),
+        }
+    }
+
+    #[test]
+    fn anchor_insets() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("button".into()),
+            inset: below_anchor(),
+            size: Size { width: length(60.0), height: length(30.0) },
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 100.0, y: 50.0 }, style);
+
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 100.0, y: 70.0 });
+    }
+
+    #[test]
+    fn anchor_insets_on_end_edges() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("button".into()),
+            inset: Rect {
+                left: auto(),
+                right: LengthPercentageAuto::anchor(AnchorSide::Inside),
+                top: auto(),
+                bottom: LengthPercentageAuto::anchor(AnchorSide::Percent(0.5)),
+            },
+            size: Size { width: length(60.0), height: length(30.0) },
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 100.0, y: 50.0 }, style);
+
+        // Right-aligned with the anchor, with its bottom edge at the anchor's vertical center
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 120.0, y: 30.0 });
+    }
+
+    #[test]
+    fn position_area_aligns_towards_anchor() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("button".into()),
+            position_area: Some(PositionArea { x: PositionAreaSpan::Center, y: PositionAreaSpan::Start }),
+            size: Size { width: length(40.0), height: length(10.0) },
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 100.0, y: 50.0 }, style);
+
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 120.0, y: 40.0 });
+    }
+
+    #[test]
+    fn position_area_fits_content() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let content = taffy
+            .new_leaf(Style { size: Size { width: length(30.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let button = taffy
+            .new_leaf(Style {
+                anchor_name: Some("button".into()),
+                margin: Rect { left: length(100.0), right: zero(), top: length(50.0), bottom: zero() },
+                size: Size { width: length(80.0), height: length(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let menu = taffy
+            .new_with_children(
+                Style {
+                    position: Position::Absolute,
+                    position_anchor: Some("button".into()),
+                    position_area: Some(PositionArea { x: PositionAreaSpan::SpanEnd, y: PositionAreaSpan::End }),
+                    ..Default::default()
+                },
+                &[content],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    align_items: Some(AlignItems::Start),
+                    size: Size { width: length(400.0), height: length(300.0) },
+                    ..Default::default()
+                },
+                &[button, menu],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(menu).unwrap().location, Point { x: 100.0, y: 70.0 });
+        assert_eq!(taffy.layout(menu).unwrap().size, Size { width: 30.0, height: 10.0 });
+    }
+
+    #[test]
+    fn flips_when_overflowing() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("button".into()),
+            inset: below_anchor(),
+            size: Size { width: length(60.0), height: length(30.0) },
+            position_try_fallbacks: vec![PositionTryFallback::FlipInline, PositionTryFallback::FlipBlock],
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 100.0, y: 270.0 }, style);
+
+        // Flipping the inline axis doesn't help, so the tooltip is placed above the anchor
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 100.0, y: 240.0 });
+    }
+
+    #[test]
+    fn falls_back_to_position_area() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("button".into()),
+            position_area: Some(PositionArea { x: PositionAreaSpan::End, y: PositionAreaSpan::Center }),
+            size: Size { width: length(60.0), height: length(20.0) },
+            position_try_fallbacks: vec![PositionTryFallback::PositionArea(PositionArea {
+                x: PositionAreaSpan::Center,
+                y: PositionAreaSpan::End,
+            })],
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 300.0, y: 50.0 }, style);
+
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 310.0, y: 70.0 });
+    }
+
+    #[test]
+    fn keeps_own_position_if_no_fallback_fits() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("button".into()),
+            inset: below_anchor(),
+            size: Size { width: length(60.0), height: length(300.0) },
+            position_try_fallbacks: vec![PositionTryFallback::FlipBlock],
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 100.0, y: 50.0 }, style);
+
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 100.0, y: 70.0 });
+    }
+
+    #[test]
+    fn missing_anchor_behaves_as_auto() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position_anchor: Some("missing".into()),
+            inset: below_anchor(),
+            size: Size { width: length(60.0), height: length(30.0) },
+            ..Default::default()
+        };
+        let tooltip = anchored(&mut taffy, Point { x: 100.0, y: 50.0 }, style);
+
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 0.0, y: 0.0 });
+    }
+
+    #[test]
+    fn anchor_in_other_containing_block() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let button = taffy
+            .new_leaf(Style {
+                anchor_name: Some("button".into()),
+                size: Size { width: length(80.0), height: length(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let toolbar = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { left: length(50.0), right: zero(), top: zero(), bottom: zero() },
+                    padding: length(10.0),
+                    ..Default::default()
+                },
+                &[button],
+            )
+            .unwrap();
+        let tooltip = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                position_anchor: Some("button".into()),
+                inset: below_anchor(),
+                size: Size { width: length(60.0), height: length(30.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let panel = taffy
+            .new_with_children(
+                Style {
+                    margin: Rect { left: zero(), right: zero(), top: length(100.0), bottom: zero() },
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[tooltip],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { display: Display::Block, ..Default::default() }, &[toolbar, panel])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The button is at (60, 10) and the panel at (0, 140)
+        assert_eq!(taffy.layout(tooltip).unwrap().location, Point { x: 60.0, y: -110.0 });
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod aspect_ratio_auto {
+    use taffy::prelude::*;
+    use taffy::LeafMeasureOutput;
+
+    /// Lays out the leaves in a 200px wide block container. Leaves with a context report it as their natural aspect ratio.
+    fn layout(taffy: &mut TaffyTree<f32>, leaves: &[NodeId]) {
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                leaves,
+            )
+            .unwrap();
+        taffy
+            .compute_layout_with_measure(root, Size::MAX_CONTENT, |known_dimensions, _, _, natural_aspect_ratio, _| {
+                LeafMeasureOutput {
+                    size: known_dimensions.unwrap_or(Size { width: 50.0, height: 50.0 }),
+                    natural_aspect_ratio: natural_aspect_ratio.copied(),
+                }
+            })
+            .unwrap();
+    }
+
+    fn leaf_style(aspect_ratio_auto: bool) -> Style {
+        Style {
+            size: Size { width: length(100.0), height: auto() },
+            aspect_ratio: Some(2.0),
+            aspect_ratio_auto,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn natural_ratio_overrides_auto_ratio() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        let auto_ratio = taffy.new_leaf_with_context(leaf_style(true), 1.0).unwrap();
+        let fixed_ratio = taffy.new_leaf_with_context(leaf_style(false), 1.0).unwrap();
+        layout(&mut taffy, &[auto_ratio, fixed_ratio]);
+
+        assert_eq!(taffy.layout(auto_ratio).unwrap().size, Size { width: 100.0, height: 100.0 });
+        assert_eq!(taffy.layout(fixed_ratio).unwrap().size, Size { width: 100.0, height: 50.0 });
+    }
+
+    #[test]
+    fn auto_ratio_without_natural_ratio() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        let leaf = taffy.new_leaf(leaf_style(true)).unwrap();
+        layout(&mut taffy, &[leaf]);
+
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 100.0, height: 50.0 });
+    }
+
+    #[test]
+    fn natural_ratio_applies_to_content_box() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        let leaf = taffy
+            .new_leaf_with_context(
+                Style {
+                    size: Size { width: length(120.0), height: auto() },
+                    padding: Rect::length(10.0),
+                    ..Default::default()
+                },
+                2.0,
+            )
+            .unwrap();
+        layout(&mut taffy, &[leaf]);
+
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 120.0, height: 70.0 });
+    }
+}
@@ -1,4 +1,4 @@
-use taffy::{AvailableSpace, NodeId, Size, Style, TaffyTree};
+use taffy::{AvailableSpace, NodeId, ShapedTextRun, Size, Style, TaffyTree, TextSegment};
 
 /// Creates a `TaffyTree` that uses `TestNodeContext`. The purpose of this function is
 /// to allow `TaffyTree` to be monomophised once in this crate rather than separately for
@@ -89,6 +89,18 @@ pub fn test_measure_function(
     }
 }
 
+/// A shape function for tests that works with `TestNodeContext`. Only horizontal text using the Ahem font can be shaped.
+pub fn test_shape_function(
+    _node_id: NodeId,
+    context: Option<&mut TestNodeContext>,
+    _style: &Style,
+) -> Option<ShapedTextRun> {
+    match context?.measure_data {
+        TestMeasureData::AhemText(data) if data.writing_mode == WritingMode::Horizontal => Some(data.shape()),
+        _ => None,
+    }
+}
+
 /// Measure data for nodes that returns results based on an intrinsic aspect ratio
 #[derive(Debug, Copy, Clone)]
 pub struct AspectRatioMeasureData {
@@ -120,6 +132,35 @@ pub struct AhemTextMeasureData {
     pub writing_mode: WritingMode,
 }
 impl AhemTextMeasureData {
+    /// Split the text into segments at spaces (which become trailing whitespace), zero-width spaces and newlines
+    /// (which become forced breaks). Each character is 10px wide and each line is 10px tall with a baseline at 8px.
+    fn shape(&self) -> ShapedTextRun {
+        const H_WIDTH: f32 = 10.0;
+
+        let mut segments = Vec::new();
+        let mut segment = TextSegment { width: 0.0, trailing_whitespace: 0.0, forced_break: false };
+        for char in self.text_content.chars() {
+            match char {
+                ' ' => segment.trailing_whitespace += H_WIDTH,
+                '\u{200B}' | '\n' => {
+                    segment.forced_break = char == '\n';
+                    segments.push(segment);
+                    segment = TextSegment { width: 0.0, trailing_whitespace: 0.0, forced_break: false };
+                }
+                _ if segment.trailing_whitespace > 0.0 => {
+                    segments.push(segment);
+                    segment = TextSegment { width: H_WIDTH, trailing_whitespace: 0.0, forced_break: false };
+                }
+                _ => segment.width += H_WIDTH,
+            }
+        }
+        if segment.width > 0.0 || segment.trailing_whitespace > 0.0 {
+            segments.push(segment);
+        }
+
+        ShapedTextRun { segments, line_height: 10.0, baseline: 8.0 }
+    }
+
     fn measure(
         &self,
         known_dimensions: taffy::Size<Option<f32>>,
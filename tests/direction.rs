@@ -0,0 +1,272 @@
+#[cfg(test)]
+mod direction {
+    use taffy::prelude::*;
+    use taffy::{Overflow, Point};
+    use taffy_test_helpers::{new_test_tree, test_measure_function, test_shape_function, TestNodeContext, WritingMode};
+
+    fn item(taffy: &mut TaffyTree, width: f32) -> NodeId {
+        taffy
+            .new_leaf(Style { size: Size { width: length(width), height: length(10.0) }, ..Default::default() })
+            .unwrap()
+    }
+
+    fn container(direction: Option<Direction>, style: Style) -> Style {
+        Style { direction, size: Size { width: length(100.0), height: length(100.0) }, ..style }
+    }
+
+    #[test]
+    fn flex_row_starts_at_the_right_edge() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 20.0);
+        let second = item(&mut taffy, 30.0);
+        let root =
+            taffy.new_with_children(container(Some(Direction::Rtl), Style::default()), &[first, second]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.x, 80.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 50.0);
+    }
+
+    #[test]
+    fn flex_row_reverse_and_justify_content() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 20.0);
+        let second = item(&mut taffy, 30.0);
+        let reversed = taffy
+            .new_with_children(
+                container(
+                    Some(Direction::Rtl),
+                    Style { flex_direction: FlexDirection::RowReverse, ..Default::default() },
+                ),
+                &[first],
+            )
+            .unwrap();
+        let justified = taffy
+            .new_with_children(
+                container(
+                    Some(Direction::Rtl),
+                    Style { justify_content: Some(JustifyContent::Start), ..Default::default() },
+                ),
+                &[second],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[reversed, justified]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 70.0);
+    }
+
+    #[test]
+    fn flex_column_cross_axis_alignment() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 20.0);
+        let second = item(&mut taffy, 20.0);
+        let root = taffy
+            .new_with_children(
+                container(
+                    Some(Direction::Rtl),
+                    Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: Some(AlignItems::Start),
+                        ..Default::default()
+                    },
+                ),
+                &[first, second],
+            )
+            .unwrap();
+        taffy
+            .set_style(second, Style { align_self: Some(AlignSelf::End), ..taffy.style(second).unwrap().clone() })
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.x, 80.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 0.0);
+    }
+
+    #[test]
+    fn direction_is_inherited() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = item(&mut taffy, 20.0);
+        let inner = taffy.new_with_children(container(None, Style::default()), &[child]).unwrap();
+        let root =
+            taffy.new_with_children(Style { direction: Some(Direction::Rtl), ..Default::default() }, &[inner]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, 80.0);
+
+        // Changing the direction of an ancestor invalidates the cached layout of its descendants
+        taffy.set_style(root, Style { direction: Some(Direction::Ltr), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+
+        // An explicit direction overrides the inherited direction
+        taffy.set_style(inner, container(Some(Direction::Rtl), Style::default())).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, 80.0);
+    }
+
+    #[test]
+    fn grid_columns_are_placed_from_the_right() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let narrow = taffy
+            .new_leaf(Style {
+                size: Size { width: length(10.0), height: auto() },
+                justify_self: Some(JustifySelf::Start),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                container(
+                    Some(Direction::Rtl),
+                    Style {
+                        display: Display::Grid,
+                        grid_template_columns: vec![length(30.0), length(30.0)],
+                        padding: Rect { left: length(5.0), right: zero(), top: zero(), bottom: zero() },
+                        ..Default::default()
+                    },
+                ),
+                &[first, second, narrow],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 70.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().size.width, 30.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 40.0);
+        // The third item wraps onto the second row, and `justify-self: start` aligns it to the right of its area
+        assert_eq!(taffy.layout(narrow).unwrap().location.x, 90.0);
+    }
+
+    #[test]
+    fn block_children_are_aligned_to_the_right() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = item(&mut taffy, 30.0);
+        let root = taffy
+            .new_with_children(
+                container(Some(Direction::Rtl), Style { display: Display::Block, ..Default::default() }),
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location.x, 70.0);
+    }
+
+    #[test]
+    fn inline_fragments_are_placed_from_the_right() {
+        let mut taffy = new_test_tree();
+        let inline_style = Style { display_outside: DisplayOutside::Inline, ..Default::default() };
+        let short_text = taffy
+            .new_leaf_with_context(inline_style.clone(), TestNodeContext::ahem_text("HH ", WritingMode::Horizontal))
+            .unwrap();
+        let atomic = taffy.new_leaf_with_context(inline_style.clone(), TestNodeContext::fixed(30.0, 30.0)).unwrap();
+        let wrapped_text = taffy
+            .new_leaf_with_context(inline_style, TestNodeContext::ahem_text("HHHH HHHH HH", WritingMode::Horizontal))
+            .unwrap();
+        let block_style = || container(Some(Direction::Rtl), Style { display: Display::Block, ..Default::default() });
+        let first = taffy.new_with_children(block_style(), &[short_text, atomic]).unwrap();
+        let second = taffy.new_with_children(block_style(), &[wrapped_text]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[first, second]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        assert_eq!(taffy.layout(short_text).unwrap().location.x, 80.0);
+        assert_eq!(taffy.layout(atomic).unwrap().location.x, 40.0);
+        // The first line ("HHHH HHHH") spans 10..100 and the second line ("HH") spans 80..100
+        assert_eq!(taffy.layout(wrapped_text).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(wrapped_text).unwrap().size, Size { width: 90.0, height: 20.0 });
+    }
+
+    #[test]
+    fn columns_are_placed_from_the_right() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children: Vec<NodeId> = (0..3).map(|_| item(&mut taffy, 10.0)).collect();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    direction: Some(Direction::Rtl),
+                    display: Display::Block,
+                    column_count: Some(2),
+                    gap: Size { width: length(10.0), height: zero() },
+                    size: Size { width: length(210.0), height: length(10.0) },
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Only one child fits into each column, so the last child is placed into an overflow column to the left
+        assert_eq!(taffy.layout(children[0]).unwrap().location, Point { x: 200.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 90.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: -20.0, y: 0.0 });
+    }
+
+    #[test]
+    fn scrollbar_gutter_is_on_the_left() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let flex_child = taffy.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+        let block_child = taffy.new_leaf(Style::default()).unwrap();
+        let scroll_container = |display| {
+            container(
+                Some(Direction::Rtl),
+                Style {
+                    display,
+                    overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                    scrollbar_width: 10.0,
+                    ..Default::default()
+                },
+            )
+        };
+        let flex = taffy.new_with_children(scroll_container(Display::Flex), &[flex_child]).unwrap();
+        let block = taffy.new_with_children(scroll_container(Display::Block), &[block_child]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[flex, block]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(flex_child).unwrap().location.x, 10.0);
+        assert_eq!(taffy.layout(flex_child).unwrap().size.width, 90.0);
+        assert_eq!(taffy.layout(block_child).unwrap().location.x, 10.0);
+        assert_eq!(taffy.layout(block_child).unwrap().size.width, 90.0);
+    }
+
+    #[test]
+    fn absolute_insets_prefer_right() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let absolute = |taffy: &mut TaffyTree| {
+            taffy
+                .new_leaf(Style {
+                    position: Position::Absolute,
+                    size: Size { width: length(20.0), height: length(10.0) },
+                    inset: Rect { left: length(10.0), right: length(5.0), top: auto(), bottom: auto() },
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let flex_child = absolute(&mut taffy);
+        let grid_child = absolute(&mut taffy);
+        let block_child = absolute(&mut taffy);
+        let flex = taffy.new_with_children(container(Some(Direction::Rtl), Style::default()), &[flex_child]).unwrap();
+        let grid = taffy
+            .new_with_children(
+                container(Some(Direction::Rtl), Style { display: Display::Grid, ..Default::default() }),
+                &[grid_child],
+            )
+            .unwrap();
+        let block = taffy
+            .new_with_children(
+                container(Some(Direction::Rtl), Style { display: Display::Block, ..Default::default() }),
+                &[block_child],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style::default(), &[flex, grid, block]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(flex_child).unwrap().location.x, 75.0);
+        assert_eq!(taffy.layout(grid_child).unwrap().location.x, 75.0);
+        assert_eq!(taffy.layout(block_child).unwrap().location.x, 75.0);
+    }
+}
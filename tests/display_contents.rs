@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod display_contents {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    fn item(taffy: &mut TaffyTree, width: f32) -> NodeId {
+        taffy
+            .new_leaf(Style { size: Size { width: length(width), height: length(10.0) }, ..Default::default() })
+            .unwrap()
+    }
+
+    fn contents(taffy: &mut TaffyTree, children: &[NodeId]) -> NodeId {
+        taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, children).unwrap()
+    }
+
+    #[test]
+    fn children_are_flex_items_of_parent() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 10.0);
+        let second = item(&mut taffy, 20.0);
+        let third = item(&mut taffy, 30.0);
+        let wrapper = contents(&mut taffy, &[second, third]);
+        let root =
+            taffy.new_with_children(Style { gap: length(5.0), ..Default::default() }, &[first, wrapper]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 70.0, height: 10.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 15.0, y: 0.0 });
+        assert_eq!(taffy.layout(third).unwrap().location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(taffy.layout(wrapper).unwrap().size, Size::ZERO);
+        assert_eq!(taffy.layout(wrapper).unwrap().location, Point::ZERO);
+    }
+
+    #[test]
+    fn children_are_grid_items_of_parent() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 10.0);
+        let second = item(&mut taffy, 10.0);
+        let third = item(&mut taffy, 10.0);
+        let inner = contents(&mut taffy, &[third]);
+        let outer = contents(&mut taffy, &[second, inner]);
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![length(20.0); 2], ..Default::default() },
+                &[first, outer],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 20.0, y: 0.0 });
+        assert_eq!(taffy.layout(third).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 40.0, height: 20.0 });
+    }
+
+    #[test]
+    fn children_are_block_children_of_parent() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 10.0);
+        let second = item(&mut taffy, 20.0);
+        let wrapper = contents(&mut taffy, &[first, second]);
+        let root =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[wrapper]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 20.0 });
+    }
+
+    #[test]
+    fn changes_to_contents_nodes_cause_relayout() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 10.0);
+        let second = item(&mut taffy, 20.0);
+        let wrapper = contents(&mut taffy, &[first]);
+        let root = taffy.new_with_children(Style::default(), &[wrapper]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().size.width, 10.0);
+
+        // Adding a child to the contents node adds a flex item to the root
+        taffy.add_child(wrapper, second).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().size.width, 30.0);
+        assert_eq!(taffy.layout(second).unwrap().location.x, 10.0);
+
+        // The wrapper generates a box once it is no longer `display: contents`
+        taffy.set_style(wrapper, Style { flex_direction: FlexDirection::Column, ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(wrapper).unwrap().size, Size { width: 20.0, height: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+    }
+}
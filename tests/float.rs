@@ -0,0 +1,340 @@
+#[cfg(test)]
+mod float {
+    use taffy::prelude::*;
+    use taffy::{DetailedLayoutInfo, Point};
+    use taffy_test_helpers::{new_test_tree, test_measure_function, test_shape_function, TestNodeContext, WritingMode};
+
+    fn float_style(float: Float, width: f32, height: f32) -> Style {
+        Style { float, size: Size { width: length(width), height: length(height) }, ..Default::default() }
+    }
+
+    fn block_style(width: f32) -> Style {
+        Style { display: Display::Block, size: Size { width: length(width), height: auto() }, ..Default::default() }
+    }
+
+    #[test]
+    fn left_and_right_floats_are_placed_at_the_container_edges() {
+        let mut taffy = new_test_tree();
+        let left = taffy.new_leaf(float_style(Float::Left, 30.0, 20.0)).unwrap();
+        let right = taffy.new_leaf(float_style(Float::Right, 20.0, 40.0)).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { padding: Rect { left: length(5.0), right: length(5.0), ..Rect::zero() }, ..block_style(100.0) },
+                &[left, right],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(left).unwrap().location, Point { x: 5.0, y: 0.0 });
+        assert_eq!(taffy.layout(right).unwrap().location, Point { x: 75.0, y: 0.0 });
+
+        // The container's auto height grows to contain its floats
+        assert_eq!(taffy.layout(root).unwrap().size.height, 40.0);
+    }
+
+    #[test]
+    fn floats_wrap_below_when_there_is_not_enough_room() {
+        let mut taffy = new_test_tree();
+        let first = taffy.new_leaf(float_style(Float::Left, 60.0, 20.0)).unwrap();
+        let second = taffy.new_leaf(float_style(Float::Left, 60.0, 10.0)).unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[first, second]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+    }
+
+    #[test]
+    fn block_beside_float_is_shrunk() {
+        let mut taffy = new_test_tree();
+        let float = taffy
+            .new_leaf(Style {
+                margin: Rect { right: length(10.0), ..Rect::zero() },
+                ..float_style(Float::Left, 30.0, 50.0)
+            })
+            .unwrap();
+        let block =
+            taffy.new_leaf(Style { size: Size { width: auto(), height: length(20.0) }, ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[float, block]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(block).unwrap().location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(taffy.layout(block).unwrap().size, Size { width: 60.0, height: 20.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+    }
+
+    #[test]
+    fn clear_places_block_below_floats() {
+        let mut taffy = new_test_tree();
+        let left = taffy.new_leaf(float_style(Float::Left, 30.0, 20.0)).unwrap();
+        let right = taffy.new_leaf(float_style(Float::Right, 30.0, 40.0)).unwrap();
+        let clear_left = taffy
+            .new_leaf(Style {
+                clear: Clear::Left,
+                size: Size { width: auto(), height: length(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let clear_both = taffy
+            .new_leaf(Style {
+                clear: Clear::Both,
+                size: Size { width: auto(), height: length(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[left, right, clear_left, clear_both]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The first block clears only the left float, so is still shrunk by the right float
+        assert_eq!(taffy.layout(clear_left).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(clear_left).unwrap().size.width, 70.0);
+        assert_eq!(taffy.layout(clear_both).unwrap().location, Point { x: 0.0, y: 40.0 });
+        assert_eq!(taffy.layout(clear_both).unwrap().size.width, 100.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+    }
+
+    #[test]
+    fn clearance_prevents_margin_collapsing() {
+        let mut taffy = new_test_tree();
+        let first = taffy
+            .new_leaf(Style {
+                size: Size { width: auto(), height: length(10.0) },
+                margin: Rect { bottom: length(20.0), ..Rect::zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let float = taffy.new_leaf(float_style(Float::Left, 30.0, 50.0)).unwrap();
+        let cleared = taffy
+            .new_leaf(Style {
+                clear: Clear::Left,
+                size: Size { width: auto(), height: length(10.0) },
+                margin: Rect { top: length(10.0), ..Rect::zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[first, float, cleared]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The float is placed below the collapsed margin, and the cleared block is placed directly below the float
+        assert_eq!(taffy.layout(float).unwrap().location, Point { x: 0.0, y: 30.0 });
+        assert_eq!(taffy.layout(cleared).unwrap().location.y, 80.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 90.0);
+    }
+
+    #[test]
+    fn text_wraps_around_float() {
+        let mut taffy = new_test_tree();
+        let float = taffy.new_leaf(float_style(Float::Left, 40.0, 15.0)).unwrap();
+        let text = taffy
+            .new_leaf_with_context(
+                Style { display_outside: DisplayOutside::Inline, ..Default::default() },
+                TestNodeContext::ahem_text("HHHH HHHH HHHH", WritingMode::Horizontal),
+            )
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[float, text]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The first two lines are shortened by the float, and the third line is below it
+        let DetailedLayoutInfo::Inline(info) = taffy.detailed_layout_info(root) else { panic!("expected inline info") };
+        assert_eq!(info.lines.len(), 3);
+        assert_eq!(info.lines[0].location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(info.lines[0].size.width, 60.0);
+        assert_eq!(info.lines[1].location, Point { x: 40.0, y: 10.0 });
+        assert_eq!(info.lines[2].location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(info.lines[2].size.width, 100.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+    }
+
+    #[test]
+    fn intrinsic_width_includes_floats() {
+        let mut taffy = new_test_tree();
+        let float = taffy.new_leaf(float_style(Float::Left, 40.0, 10.0)).unwrap();
+        let block = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(30.0, 10.0)).unwrap();
+        let root =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[float, block]).unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().size.width, 70.0);
+        assert_eq!(taffy.layout(block).unwrap().location, Point { x: 40.0, y: 0.0 });
+
+        taffy.compute_layout_with_measure(root, Size::MIN_CONTENT, test_measure_function).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().size.width, 40.0);
+    }
+
+    #[test]
+    fn floats_in_nested_block_are_contained_by_formatting_context_root() {
+        let mut taffy = new_test_tree();
+        let float = taffy.new_leaf(float_style(Float::Left, 50.0, 30.0)).unwrap();
+        let inner = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[float]).unwrap();
+        let sibling = taffy
+            .new_leaf(Style {
+                display: Display::Block,
+                size: Size { width: auto(), height: length(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy.new_with_children(block_style(200.0), &[inner, sibling]).unwrap();
+        let root =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[container]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Block containers which don't establish a block formatting context don't grow to contain their floats,
+        // and block boxes beside the floats are not shortened
+        assert_eq!(taffy.layout(float).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(inner).unwrap().size.height, 0.0);
+        assert_eq!(taffy.layout(sibling).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(sibling).unwrap().size.width, 200.0);
+        assert_eq!(taffy.layout(container).unwrap().size.height, 10.0);
+
+        // The root establishes a block formatting context, so grows to contain the float
+        assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+    }
+
+    #[test]
+    fn text_in_nested_block_wraps_around_float() {
+        let mut taffy = new_test_tree();
+        let float = taffy.new_leaf(float_style(Float::Left, 40.0, 15.0)).unwrap();
+        let text = taffy
+            .new_leaf_with_context(
+                Style { display_outside: DisplayOutside::Inline, ..Default::default() },
+                TestNodeContext::ahem_text("HHHH HHHH HHHH", WritingMode::Horizontal),
+            )
+            .unwrap();
+        let inner = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { top: length(5.0), ..Rect::zero() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[float, inner]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The block is not shortened by the float, but its first line box is. The second line box is below the float,
+        // so the rest of the text fits onto it.
+        assert_eq!(taffy.layout(inner).unwrap().location, Point { x: 0.0, y: 5.0 });
+        assert_eq!(taffy.layout(inner).unwrap().size.width, 100.0);
+        let DetailedLayoutInfo::Inline(info) = taffy.detailed_layout_info(inner) else {
+            panic!("expected inline info")
+        };
+        assert_eq!(info.lines.len(), 2);
+        assert_eq!(info.lines[0].location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(info.lines[0].size.width, 60.0);
+        assert_eq!(info.lines[1].location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(info.lines[1].size.width, 100.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 25.0);
+    }
+
+    #[test]
+    fn floats_are_inherited_through_collapsed_margins() {
+        let mut taffy = new_test_tree();
+        let float = taffy.new_leaf(float_style(Float::Left, 40.0, 15.0)).unwrap();
+        let text = taffy
+            .new_leaf_with_context(
+                Style { display_outside: DisplayOutside::Inline, ..Default::default() },
+                TestNodeContext::ahem_text("HHHH HHHH", WritingMode::Horizontal),
+            )
+            .unwrap();
+        let paragraph = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { top: length(10.0), ..Rect::zero() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        let inner =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[paragraph]).unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[float, inner]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The paragraph's top margin collapses through the top of the block, so only its first line is beside the float
+        assert_eq!(taffy.layout(inner).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(paragraph).unwrap().location, Point { x: 0.0, y: 0.0 });
+        let DetailedLayoutInfo::Inline(info) = taffy.detailed_layout_info(paragraph) else {
+            panic!("expected inline info")
+        };
+        assert_eq!(info.lines.len(), 2);
+        assert_eq!(info.lines[0].location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(info.lines[1].location, Point { x: 0.0, y: 10.0 });
+    }
+
+    #[test]
+    fn text_after_nested_block_wraps_around_its_floats() {
+        let mut taffy = new_test_tree();
+        let float = taffy.new_leaf(float_style(Float::Right, 40.0, 15.0)).unwrap();
+        let inner = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[float]).unwrap();
+        let text = taffy
+            .new_leaf_with_context(
+                Style { display_outside: DisplayOutside::Inline, ..Default::default() },
+                TestNodeContext::ahem_text("HHHH HHHH", WritingMode::Horizontal),
+            )
+            .unwrap();
+        let paragraph =
+            taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[text]).unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[inner, paragraph]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        assert_eq!(taffy.layout(float).unwrap().location, Point { x: 60.0, y: 0.0 });
+        let DetailedLayoutInfo::Inline(info) = taffy.detailed_layout_info(paragraph) else {
+            panic!("expected inline info")
+        };
+        assert_eq!(info.lines.len(), 2);
+        assert_eq!(info.lines[0].size.width, 60.0);
+        assert_eq!(info.lines[1].size.width, 60.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+    }
+
+    #[test]
+    fn line_box_is_shortened_by_float_beside_lower_part_of_line() {
+        let mut taffy = new_test_tree();
+        let spacer =
+            taffy.new_leaf(Style { size: Size { width: auto(), height: length(5.0) }, ..Default::default() }).unwrap();
+        let float = taffy.new_leaf(float_style(Float::Left, 40.0, 20.0)).unwrap();
+        let text = taffy
+            .new_leaf_with_context(
+                Style { display_outside: DisplayOutside::Inline, ..Default::default() },
+                TestNodeContext::ahem_text("HHHH HHHH", WritingMode::Horizontal),
+            )
+            .unwrap();
+        let paragraph = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    margin: Rect { top: length(-5.0), ..Rect::zero() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[spacer, float, paragraph]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The float starts halfway down the first line box, but still shortens it
+        assert_eq!(taffy.layout(float).unwrap().location, Point { x: 0.0, y: 5.0 });
+        assert_eq!(taffy.layout(paragraph).unwrap().location, Point { x: 0.0, y: 0.0 });
+        let DetailedLayoutInfo::Inline(info) = taffy.detailed_layout_info(paragraph) else {
+            panic!("expected inline info")
+        };
+        assert_eq!(info.lines.len(), 2);
+        assert_eq!(info.lines[0].location, Point { x: 40.0, y: 0.0 });
+        assert_eq!(info.lines[0].size.width, 60.0);
+        assert_eq!(info.lines[1].location, Point { x: 40.0, y: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 25.0);
+    }
+}
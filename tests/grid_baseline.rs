@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod grid_baseline {
+    use taffy::prelude::*;
+    use taffy::{Point, WritingMode};
+
+    fn item(taffy: &mut TaffyTree, style: Style, width: f32, height: f32) -> NodeId {
+        taffy.new_leaf(Style { size: Size { width: length(width), height: length(height) }, ..style }).unwrap()
+    }
+
+    /// A flex container whose first baseline is the bottom edge of its (only) child
+    fn item_with_baseline(taffy: &mut TaffyTree, style: Style, baseline: f32, height: f32) -> NodeId {
+        let child = item(taffy, Style::default(), 10.0, baseline);
+        taffy
+            .new_with_children(Style { size: Size { width: length(10.0), height: length(height) }, ..style }, &[child])
+            .unwrap()
+    }
+
+    #[test]
+    fn align_self_baseline_shims_row_size() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let baseline = Style { align_self: Some(AlignSelf::Baseline), ..Default::default() };
+        let first = item_with_baseline(&mut taffy, baseline.clone(), 10.0, 40.0);
+        let second = item_with_baseline(&mut taffy, baseline, 30.0, 35.0);
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![auto(), auto()], ..Default::default() },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The first item is shimmed down by 20px so that the baselines line up, which increases the size of the row
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 60.0);
+    }
+
+    #[test]
+    fn baseline_sharing_groups_are_per_row() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let baseline = Style { align_self: Some(AlignSelf::Baseline), ..Default::default() };
+        let first = item_with_baseline(&mut taffy, baseline.clone(), 10.0, 20.0);
+        let second = item_with_baseline(&mut taffy, baseline.clone(), 30.0, 30.0);
+        let third = item_with_baseline(&mut taffy, baseline.clone(), 5.0, 20.0);
+        let fourth = item_with_baseline(&mut taffy, Style::default(), 15.0, 20.0);
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![auto(), auto()], ..Default::default() },
+                &[first, second, third, fourth],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.y, 20.0);
+        assert_eq!(taffy.layout(second).unwrap().location.y, 0.0);
+        // The second row has only one baseline aligned item, so its baseline alignment has no effect
+        assert_eq!(taffy.layout(third).unwrap().location.y, 40.0);
+        assert_eq!(taffy.layout(fourth).unwrap().location.y, 40.0);
+    }
+
+    #[test]
+    fn justify_self_baseline_in_vertical_writing_mode() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let baseline = Style {
+            justify_self: Some(JustifySelf::Baseline),
+            writing_mode: Some(WritingMode::HorizontalTb),
+            ..Default::default()
+        };
+        let first = item_with_baseline(&mut taffy, baseline.clone(), 10.0, 20.0);
+        let second = item_with_baseline(&mut taffy, baseline, 25.0, 30.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    writing_mode: Some(WritingMode::VerticalLr),
+                    grid_template_rows: vec![auto(), auto()],
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The inline axis is vertical, so the items' horizontal baselines are aligned within the (vertical) column
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 15.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 35.0);
+    }
+
+    #[test]
+    fn absolutely_positioned_item_aligns_with_row_baseline() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let baseline = Style { align_self: Some(AlignSelf::Baseline), ..Default::default() };
+        let first = item_with_baseline(&mut taffy, baseline.clone(), 10.0, 40.0);
+        let second = item_with_baseline(&mut taffy, baseline.clone(), 30.0, 35.0);
+        let absolute = item_with_baseline(
+            &mut taffy,
+            Style {
+                position: Position::Absolute,
+                grid_row: Line { start: line(1), end: auto() },
+                grid_column: Line { start: line(2), end: auto() },
+                ..baseline
+            },
+            5.0,
+            10.0,
+        );
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![auto(), auto()], ..Default::default() },
+                &[first, second, absolute],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(absolute).unwrap().location, Point { x: 10.0, y: 25.0 });
+    }
+}
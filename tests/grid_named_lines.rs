@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod grid_named_lines {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn leaf(taffy: &mut TaffyTree, grid_column: Line<GridPlacement>) -> NodeId {
+        taffy
+            .new_leaf(Style { grid_column, size: Size { width: auto(), height: length(10.0) }, ..Default::default() })
+            .unwrap()
+    }
+
+    /// `[sidebar-start] 100px [sidebar-end main-start] 200px [main-end]`
+    fn sidebar_grid() -> Style {
+        Style {
+            display: Display::Grid,
+            grid_template_columns: vec![length(100.0), length(200.0)],
+            grid_template_column_names: vec![
+                names(&["sidebar-start"]),
+                names(&["sidebar-end", "main-start"]),
+                names(&["main-end"]),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn named_line_placement() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let main = leaf(&mut taffy, Line { start: named_line("main-start", 1), end: named_line("main-end", 1) });
+        let sidebar = leaf(&mut taffy, Line { start: named_line("sidebar-start", 1), end: auto() });
+        let root = taffy.new_with_children(sidebar_grid(), &[sidebar, main]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(main).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(main).unwrap().size.width, 200.0);
+        assert_eq!(taffy.layout(sidebar).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(sidebar).unwrap().size.width, 100.0);
+    }
+
+    #[test]
+    fn implicit_area_names() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let main = leaf(&mut taffy, Line { start: named_line("main", 0), end: named_line("main", 0) });
+        let root = taffy.new_with_children(sidebar_grid(), &[main]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // `main` refers to the `main-start` and `main-end` lines
+        assert_eq!(taffy.layout(main).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(main).unwrap().size.width, 200.0);
+    }
+
+    #[test]
+    fn named_lines_in_repeat() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let second_col = leaf(&mut taffy, Line { start: named_line("col", 2), end: span(1) });
+        let last_col = leaf(&mut taffy, Line { start: named_line("col", -1), end: span(1) });
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        length(10.0),
+                        repeat_with_line_names(3, vec![length(50.0)], vec![names(&["col"]), vec![]]),
+                    ],
+                    ..Default::default()
+                },
+                &[second_col, last_col],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second_col).unwrap().location, Point { x: 60.0, y: 0.0 });
+        assert_eq!(taffy.layout(last_col).unwrap().location, Point { x: 110.0, y: 0.0 });
+    }
+
+    #[test]
+    fn span_to_named_line() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let item = leaf(&mut taffy, Line { start: line(1), end: named_span("main-end", 1) });
+        let root = taffy.new_with_children(sidebar_grid(), &[item]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(item).unwrap().size.width, 300.0);
+    }
+
+    #[test]
+    fn missing_name_refers_to_implicit_lines() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let item = leaf(&mut taffy, Line { start: named_line("footer", 1), end: auto() });
+        let root = taffy
+            .new_with_children(Style { grid_auto_columns: vec![length(30.0)], ..sidebar_grid() }, &[item])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // All implicit lines are assumed to be named `footer`, so the item starts at the first line after the last
+        // explicit line, leaving an empty implicit track before it
+        assert_eq!(taffy.layout(item).unwrap().location, Point { x: 330.0, y: 0.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.width, 360.0);
+    }
+}
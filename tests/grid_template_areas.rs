@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod grid_template_areas {
+    use taffy::prelude::*;
+    use taffy::style::InvalidGridTemplateAreas;
+    use taffy::Point;
+
+    fn leaf(taffy: &mut TaffyTree, area: &str) -> NodeId {
+        taffy
+            .new_leaf(Style { grid_row: named_area(area), grid_column: named_area(area), ..Default::default() })
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_areas() {
+        let areas = GridTemplateArea::parse(&["header header", "sidebar  main", "... main"]).unwrap();
+        let header =
+            GridTemplateArea { name: "header".into(), row_start: 1, row_end: 2, column_start: 1, column_end: 3 };
+        let main = GridTemplateArea { name: "main".into(), row_start: 2, row_end: 4, column_start: 2, column_end: 3 };
+        assert_eq!(areas.len(), 3);
+        assert_eq!(areas[0], header);
+        assert_eq!(areas[2], main);
+    }
+
+    #[test]
+    fn parse_invalid_areas() {
+        assert_eq!(GridTemplateArea::parse(&["a a", "b"]), Err(InvalidGridTemplateAreas::InconsistentRowLength));
+        assert_eq!(GridTemplateArea::parse(&["a a", ""]), Err(InvalidGridTemplateAreas::InconsistentRowLength));
+        assert_eq!(
+            GridTemplateArea::parse(&["a a", "a ."]),
+            Err(InvalidGridTemplateAreas::NonRectangularArea("a".into()))
+        );
+        assert_eq!(GridTemplateArea::parse(&["a b a"]), Err(InvalidGridTemplateAreas::NonRectangularArea("a".into())));
+    }
+
+    #[test]
+    fn items_are_placed_into_named_areas() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let main = leaf(&mut taffy, "main");
+        let header = leaf(&mut taffy, "header");
+        let sidebar = leaf(&mut taffy, "sidebar");
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![length(100.0), length(200.0)],
+                    grid_template_rows: vec![length(20.0), length(50.0)],
+                    grid_template_areas: GridTemplateArea::parse(&["header header", "sidebar main"]).unwrap(),
+                    ..Default::default()
+                },
+                &[main, header, sidebar],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(header).unwrap().size, Size { width: 300.0, height: 20.0 });
+        assert_eq!(taffy.layout(sidebar).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(main).unwrap().location, Point { x: 100.0, y: 20.0 });
+        assert_eq!(taffy.layout(main).unwrap().size, Size { width: 200.0, height: 50.0 });
+    }
+
+    #[test]
+    fn areas_extend_the_explicit_grid() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let footer = leaf(&mut taffy, "footer");
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![length(100.0)],
+                    grid_auto_columns: vec![length(30.0)],
+                    grid_auto_rows: vec![length(10.0)],
+                    grid_template_areas: GridTemplateArea::parse(&["a a", "footer footer"]).unwrap(),
+                    ..Default::default()
+                },
+                &[footer],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The second column and both rows are not sized by the template, so are sized by the auto tracks
+        assert_eq!(taffy.layout(footer).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(footer).unwrap().size, Size { width: 130.0, height: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 130.0, height: 20.0 });
+    }
+
+    #[test]
+    fn area_edges_are_named_lines() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let item = taffy
+            .new_leaf(Style {
+                grid_column: Line { start: named_line("main-start", 1), end: named_line("main-end", 1) },
+                grid_row: Line { start: line(1), end: named_span("main-end", 1) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![length(10.0), length(20.0), length(40.0)],
+                    grid_template_rows: vec![length(10.0), length(20.0)],
+                    grid_template_areas: GridTemplateArea::parse(&[". main main", ". . ."]).unwrap(),
+                    ..Default::default()
+                },
+                &[item],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(item).unwrap().size, Size { width: 60.0, height: 10.0 });
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod inline {
+    use taffy::prelude::*;
+    use taffy::{DetailedLayoutInfo, Point, TextAlign};
+    use taffy_test_helpers::{new_test_tree, test_measure_function, test_shape_function, TestNodeContext, WritingMode};
+
+    fn inline_style() -> Style {
+        Style { display_outside: DisplayOutside::Inline, ..Default::default() }
+    }
+
+    fn block_style(width: f32) -> Style {
+        Style { display: Display::Block, size: Size { width: length(width), height: auto() }, ..Default::default() }
+    }
+
+    #[test]
+    fn text_wraps_into_line_boxes() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HHHH HHHH HH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[text]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 20.0 });
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 90.0, height: 20.0 });
+
+        let DetailedLayoutInfo::Inline(info) = taffy.detailed_layout_info(root) else { panic!("expected inline info") };
+        assert_eq!(info.lines.len(), 2);
+        assert_eq!(info.lines[0].baseline, 8.0);
+        assert_eq!(info.lines[0].fragments[0].segments, 0..2);
+        assert_eq!(info.lines[0].fragments[0].size, Size { width: 90.0, height: 10.0 });
+        assert_eq!(info.lines[1].location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(info.lines[1].fragments[0].segments, 2..3);
+        assert_eq!(info.lines[1].fragments[0].location, Point { x: 0.0, y: 10.0 });
+    }
+
+    #[test]
+    fn forced_break_starts_new_line() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HH\nHHH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[text]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 30.0, height: 20.0 });
+    }
+
+    #[test]
+    fn atomic_inline_box_is_aligned_to_baseline() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HH ", WritingMode::Horizontal))
+            .unwrap();
+        let atomic = taffy.new_leaf_with_context(inline_style(), TestNodeContext::fixed(30.0, 30.0)).unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[text, atomic]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The atomic box has no baseline, so its bottom edge is aligned with the text's baseline
+        assert_eq!(taffy.layout(root).unwrap().size.height, 32.0);
+        assert_eq!(taffy.layout(atomic).unwrap().location, Point { x: 30.0, y: 0.0 });
+        assert_eq!(taffy.layout(atomic).unwrap().size, Size { width: 30.0, height: 30.0 });
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 0.0, y: 22.0 });
+    }
+
+    #[test]
+    fn inline_flex_box_is_laid_out_atomically() {
+        let mut taffy = new_test_tree();
+        let flex_child = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let inline_flex =
+            taffy.new_with_children(Style { display: Display::Flex, ..inline_style() }, &[flex_child]).unwrap();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HHHHHHHHH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[inline_flex, text]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The inline-flex box is shrink-to-fit and the text doesn't fit after it, so it wraps onto the second line
+        assert_eq!(taffy.layout(inline_flex).unwrap().size, Size { width: 20.0, height: 10.0 });
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+    }
+
+    #[test]
+    fn mixed_inline_and_block_children_generate_anonymous_blocks() {
+        let mut taffy = new_test_tree();
+        let text_0 = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HH", WritingMode::Horizontal))
+            .unwrap();
+        let block = taffy
+            .new_leaf(Style {
+                size: Size { width: auto(), height: length(15.0) },
+                margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: length(5.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let text_1 = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[text_0, block, text_1]).unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        assert_eq!(taffy.layout(text_0).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(block).unwrap().location.y, 15.0);
+        assert_eq!(taffy.layout(block).unwrap().size.width, 100.0);
+        assert_eq!(taffy.layout(text_1).unwrap().location.y, 35.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 45.0);
+    }
+
+    #[test]
+    fn intrinsic_size_of_inline_content() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HHHH HH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[text]).unwrap();
+
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 70.0, height: 10.0 });
+
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MIN_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 40.0, height: 20.0 });
+    }
+
+    #[test]
+    fn text_align_applies_to_line_boxes() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { text_align: TextAlign::LegacyCenter, ..block_style(100.0) }, &[text])
+            .unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        assert_eq!(taffy.layout(text).unwrap().location, Point { x: 40.0, y: 0.0 });
+    }
+
+    #[test]
+    fn first_line_baseline_is_used_for_baseline_alignment() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HH", WritingMode::Horizontal))
+            .unwrap();
+        let block = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    padding: Rect { top: length(10.0), ..Rect::zero() },
+                    ..Default::default()
+                },
+                &[text],
+            )
+            .unwrap();
+        let fixed = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(10.0, 30.0)).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Flex, align_items: Some(AlignItems::Baseline), ..Default::default() },
+                &[block, fixed],
+            )
+            .unwrap();
+        taffy
+            .compute_layout_with_measure_and_shape(root, Size::MAX_CONTENT, test_measure_function, test_shape_function)
+            .unwrap();
+
+        // The block's baseline is 18px from its top edge (10px padding + 8px text baseline)
+        assert_eq!(taffy.layout(block).unwrap().location.y, 12.0);
+        assert_eq!(taffy.layout(fixed).unwrap().location.y, 0.0);
+    }
+
+    #[test]
+    fn inline_leaves_are_atomic_without_shape_function() {
+        let mut taffy = new_test_tree();
+        let text = taffy
+            .new_leaf_with_context(inline_style(), TestNodeContext::ahem_text("HHHH HH", WritingMode::Horizontal))
+            .unwrap();
+        let root = taffy.new_with_children(block_style(100.0), &[text]).unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        // The measure function of the Ahem test text treats spaces as part of the word, so it is 70px wide
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 70.0, height: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 10.0);
+    }
+}
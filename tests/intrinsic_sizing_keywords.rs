@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod intrinsic_sizing_keywords {
+    use taffy::prelude::*;
+    use taffy::Dimension;
+
+    /// A wrapping flex container with two 30x10 items, whose min-content width is 30 and max-content width is 60
+    fn wrapping_container(taffy: &mut TaffyTree, style: Style) -> NodeId {
+        let item_style = Style { size: Size { width: length(30.0), height: length(10.0) }, ..Default::default() };
+        let first = taffy.new_leaf(item_style.clone()).unwrap();
+        let second = taffy.new_leaf(item_style).unwrap();
+        taffy.new_with_children(Style { flex_wrap: FlexWrap::Wrap, ..style }, &[first, second]).unwrap()
+    }
+
+    #[test]
+    fn block_item_width_keywords() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let max_content = wrapping_container(
+            &mut taffy,
+            Style { size: Size { width: max_content(), height: auto() }, ..Default::default() },
+        );
+        let min_content = wrapping_container(
+            &mut taffy,
+            Style { size: Size { width: min_content(), height: auto() }, ..Default::default() },
+        );
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[max_content, min_content],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Without the keywords both items would be stretched to the width of the container
+        assert_eq!(taffy.layout(max_content).unwrap().size, Size { width: 60.0, height: 10.0 });
+        assert_eq!(taffy.layout(min_content).unwrap().size, Size { width: 30.0, height: 20.0 });
+    }
+
+    #[test]
+    fn flex_item_fit_content_max_width() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let narrow = wrapping_container(
+            &mut taffy,
+            Style { max_size: Size { width: Dimension::fit_content_px(45.0), height: auto() }, ..Default::default() },
+        );
+        let wide = wrapping_container(
+            &mut taffy,
+            Style { max_size: Size { width: Dimension::fit_content_px(100.0), height: auto() }, ..Default::default() },
+        );
+        let root = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[narrow, wide],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The stretched items are limited to fit-content(limit) = max(min-content, min(max-content, limit))
+        assert_eq!(taffy.layout(narrow).unwrap().size.width, 45.0);
+        assert_eq!(taffy.layout(wide).unwrap().size.width, 60.0);
+    }
+
+    #[test]
+    fn flex_basis_min_content() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let item = wrapping_container(&mut taffy, Style { flex_basis: min_content(), ..Default::default() });
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+                &[item],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().size, Size { width: 30.0, height: 20.0 });
+    }
+
+    #[test]
+    fn grid_item_min_content_width_is_not_stretched() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let item = wrapping_container(
+            &mut taffy,
+            Style { size: Size { width: min_content(), height: auto() }, ..Default::default() },
+        );
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![length(200.0)], ..Default::default() },
+                &[item],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(item).unwrap().size, Size { width: 30.0, height: 20.0 });
+    }
+
+    #[test]
+    fn leaf_width_keywords() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let leaf = |width| Style { size: Size { width, height: auto() }, ..Default::default() };
+        let min_content = taffy.new_leaf(leaf(min_content())).unwrap();
+        let fit_content = taffy.new_leaf(leaf(Dimension::fit_content_px(50.0))).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[min_content, fit_content],
+            )
+            .unwrap();
+
+        // Text-like content which is 80 wide when laid out on a single line and which can wrap down to 20 wide
+        taffy
+            .compute_layout_with_measure(root, Size::MAX_CONTENT, |known_dimensions, available_space, _, _, _| {
+                let width = known_dimensions.width.unwrap_or(match available_space.width {
+                    AvailableSpace::MinContent => 20.0,
+                    AvailableSpace::MaxContent => 80.0,
+                    AvailableSpace::Definite(width) => width.clamp(20.0, 80.0),
+                });
+                Size { width, height: 800.0 / width }
+            })
+            .unwrap();
+
+        assert_eq!(taffy.layout(min_content).unwrap().size, Size { width: 20.0, height: 40.0 });
+        assert_eq!(taffy.layout(fit_content).unwrap().size, Size { width: 50.0, height: 16.0 });
+    }
+}
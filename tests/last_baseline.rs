@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod last_baseline {
+    use taffy::prelude::*;
+
+    /// A wrapping flex container with two lines, whose first baseline is the bottom edge of its first line and whose
+    /// last baseline is the bottom edge of its second line
+    fn item_with_baselines(taffy: &mut TaffyTree, style: Style, first: f32, last: f32, height: f32) -> NodeId {
+        let first_line =
+            taffy.new_leaf(Style { size: Size { width: length(10.0), height: length(first) }, ..Default::default() });
+        let last_line = taffy
+            .new_leaf(Style { size: Size { width: length(10.0), height: length(last - first) }, ..Default::default() });
+        taffy
+            .new_with_children(
+                Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    align_content: Some(AlignContent::Start),
+                    size: Size { width: length(10.0), height: length(height) },
+                    ..style
+                },
+                &[first_line.unwrap(), last_line.unwrap()],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn flex_items_align_last_baselines() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item_with_baselines(&mut taffy, Style::default(), 10.0, 30.0, 40.0);
+        let second = item_with_baselines(&mut taffy, Style::default(), 5.0, 10.0, 30.0);
+        let root = taffy
+            .new_with_children(
+                Style { align_items: Some(AlignItems::LastBaseline), ..Default::default() },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The second item is shifted down by 20px so that the last baselines of both items are 30px from the top
+        assert_eq!(taffy.layout(first).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(second).unwrap().location.y, 20.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+    }
+
+    #[test]
+    fn grid_items_align_last_baselines() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let last_baseline = Style { align_self: Some(AlignSelf::LastBaseline), ..Default::default() };
+        let first = item_with_baselines(&mut taffy, last_baseline.clone(), 10.0, 30.0, 40.0);
+        let second = item_with_baselines(&mut taffy, last_baseline, 5.0, 10.0, 30.0);
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![auto(), auto()], ..Default::default() },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The first item is shimmed at its bottom edge so that the last baselines line up, which increases the size
+        // of the row
+        assert_eq!(taffy.layout(first).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(second).unwrap().location.y, 20.0);
+        assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+    }
+
+    #[test]
+    fn grid_container_last_baseline() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let last_baseline = Style { align_self: Some(AlignSelf::LastBaseline), ..Default::default() };
+        let first = item_with_baselines(&mut taffy, last_baseline.clone(), 10.0, 30.0, 40.0);
+        let second = item_with_baselines(&mut taffy, last_baseline, 5.0, 10.0, 30.0);
+        let grid = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![auto(), auto()], ..Default::default() },
+                &[first, second],
+            )
+            .unwrap();
+        let leaf = taffy
+            .new_leaf(Style { size: Size { width: length(10.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { align_items: Some(AlignItems::LastBaseline), ..Default::default() },
+                &[grid, leaf],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The last baseline of the grid is the shared last baseline of its last row. The leaf has no baseline, so its
+        // bottom edge is aligned with it.
+        assert_eq!(taffy.layout(grid).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(leaf).unwrap().location.y, 20.0);
+    }
+}
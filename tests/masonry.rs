@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod masonry {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    fn item(height: f32) -> Style {
+        Style { size: Size { width: auto(), height: length(height) }, ..Default::default() }
+    }
+
+    fn masonry_style(column_count: usize) -> Style {
+        Style {
+            display: Display::Grid,
+            masonry_rows: true,
+            grid_template_columns: vec![length(100.0); column_count],
+            ..Default::default()
+        }
+    }
+
+    fn items(taffy: &mut TaffyTree, heights: &[f32]) -> Vec<NodeId> {
+        heights.iter().map(|&height| taffy.new_leaf(item(height)).unwrap()).collect()
+    }
+
+    #[test]
+    fn items_are_placed_into_shortest_track() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = items(&mut taffy, &[50.0, 30.0, 40.0, 20.0, 10.0]);
+        let root = taffy.new_with_children(masonry_style(3), &children).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 300.0, height: 50.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: 200.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[3]).unwrap().location, Point { x: 100.0, y: 30.0 });
+        assert_eq!(taffy.layout(children[4]).unwrap().location, Point { x: 200.0, y: 40.0 });
+        assert_eq!(taffy.layout(children[4]).unwrap().size, Size { width: 100.0, height: 10.0 });
+    }
+
+    #[test]
+    fn definite_items_are_placed_first() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let auto_item = taffy.new_leaf(item(10.0)).unwrap();
+        let definite_item =
+            taffy.new_leaf(Style { grid_column: Line { start: line(1), end: auto() }, ..item(20.0) }).unwrap();
+        let root = taffy.new_with_children(masonry_style(2), &[auto_item, definite_item]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(definite_item).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(auto_item).unwrap().location, Point { x: 100.0, y: 0.0 });
+    }
+
+    #[test]
+    fn masonry_auto_flow_next_places_items_in_order() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = items(&mut taffy, &[50.0, 10.0, 10.0]);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    masonry_auto_flow: MasonryAutoFlow::Next,
+                    gap: Size { width: zero(), height: length(5.0) },
+                    ..masonry_style(2)
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The third item wraps around to the first track rather than being placed into the shortest track
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: 0.0, y: 55.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 65.0);
+    }
+
+    #[test]
+    fn spanning_items_are_placed_after_all_spanned_tracks() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = items(&mut taffy, &[20.0, 40.0]);
+        let wide_item =
+            taffy.new_leaf(Style { grid_column: Line { start: auto(), end: span(2) }, ..item(10.0) }).unwrap();
+        let root = taffy.new_with_children(masonry_style(2), &[children[0], children[1], wide_item]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(wide_item).unwrap().location, Point { x: 0.0, y: 40.0 });
+        assert_eq!(taffy.layout(wide_item).unwrap().size, Size { width: 200.0, height: 10.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+    }
+
+    #[test]
+    fn align_tracks_aligns_items_within_each_track() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = items(&mut taffy, &[60.0, 20.0]);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    align_tracks: Some(AlignContent::End),
+                    size: Size { width: auto(), height: length(100.0) },
+                    ..masonry_style(2)
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(children[0]).unwrap().location, Point { x: 0.0, y: 40.0 });
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 100.0, y: 80.0 });
+    }
+
+    #[test]
+    fn auto_placed_items_contribute_to_every_track() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let narrow = taffy
+            .new_leaf(Style { size: Size { width: length(30.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let wide = taffy
+            .new_leaf(Style { size: Size { width: length(50.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { grid_template_columns: vec![auto(), auto()], ..masonry_style(0) },
+                &[narrow, wide],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 10.0 });
+        assert_eq!(taffy.layout(wide).unwrap().location, Point { x: 50.0, y: 0.0 });
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod multicol {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    fn block(height: f32) -> Style {
+        Style { size: Size { width: auto(), height: length(height) }, ..Default::default() }
+    }
+
+    fn multicol_style(width: f32) -> Style {
+        Style { display: Display::Block, size: Size { width: length(width), height: auto() }, ..Default::default() }
+    }
+
+    fn blocks(taffy: &mut TaffyTree, heights: &[f32]) -> Vec<NodeId> {
+        heights.iter().map(|&height| taffy.new_leaf(block(height)).unwrap()).collect()
+    }
+
+    #[test]
+    fn column_count_balances_children_between_columns() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = blocks(&mut taffy, &[10.0; 6]);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    column_count: Some(3),
+                    gap: Size { width: length(10.0), height: zero() },
+                    ..multicol_style(320.0)
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+        assert_eq!(taffy.layout(children[0]).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: 110.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[5]).unwrap().location, Point { x: 220.0, y: 10.0 });
+        assert_eq!(taffy.layout(children[5]).unwrap().size, Size { width: 100.0, height: 10.0 });
+    }
+
+    #[test]
+    fn column_width_determines_column_count() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = blocks(&mut taffy, &[10.0; 3]);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    column_width: length(100.0),
+                    gap: Size { width: length(20.0), height: zero() },
+                    ..multicol_style(370.0)
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Three columns of at least 100px fit, and they are stretched to fill the container
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 130.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: 260.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[2]).unwrap().size.width, 110.0);
+    }
+
+    #[test]
+    fn balancing_accounts_for_unequal_heights() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = blocks(&mut taffy, &[30.0, 10.0, 10.0, 10.0]);
+        let root =
+            taffy.new_with_children(Style { column_count: Some(2), ..multicol_style(200.0) }, &children).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(children[3]).unwrap().location, Point { x: 100.0, y: 20.0 });
+    }
+
+    #[test]
+    fn column_fill_auto_fills_columns_sequentially() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = blocks(&mut taffy, &[10.0; 4]);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    column_count: Some(2),
+                    column_fill: ColumnFill::Auto,
+                    size: Size { width: length(200.0), height: length(30.0) },
+                    ..multicol_style(200.0)
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(children[2]).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(children[3]).unwrap().location, Point { x: 100.0, y: 0.0 });
+    }
+
+    #[test]
+    fn column_spanner_splits_columns() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let before = blocks(&mut taffy, &[10.0; 4]);
+        let spanner = taffy.new_leaf(Style { column_span: ColumnSpan::All, ..block(5.0) }).unwrap();
+        let after = blocks(&mut taffy, &[10.0; 2]);
+        let children: Vec<NodeId> = before.iter().chain(Some(&spanner)).chain(after.iter()).copied().collect();
+        let root =
+            taffy.new_with_children(Style { column_count: Some(2), ..multicol_style(200.0) }, &children).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(before[2]).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(spanner).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(spanner).unwrap().size.width, 200.0);
+        assert_eq!(taffy.layout(after[0]).unwrap().location, Point { x: 0.0, y: 25.0 });
+        assert_eq!(taffy.layout(after[1]).unwrap().location, Point { x: 100.0, y: 25.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 35.0);
+    }
+
+    #[test]
+    fn intrinsic_width_of_multicol_container() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let children = blocks(&mut taffy, &[10.0; 2]);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    column_count: Some(2),
+                    column_width: length(50.0),
+                    gap: Size { width: length(10.0), height: zero() },
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 110.0, height: 10.0 });
+        assert_eq!(taffy.layout(children[1]).unwrap().location, Point { x: 60.0, y: 0.0 });
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod order {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    fn item(taffy: &mut TaffyTree, order: i32, width: f32) -> NodeId {
+        taffy
+            .new_leaf(Style { order, size: Size { width: length(width), height: length(10.0) }, ..Default::default() })
+            .unwrap()
+    }
+
+    #[test]
+    fn flex_items_are_laid_out_in_order() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 1, 10.0);
+        let second = item(&mut taffy, -1, 20.0);
+        let third = item(&mut taffy, 0, 30.0);
+        let fourth = item(&mut taffy, 1, 40.0);
+        let root = taffy.new_with_children(Style::default(), &[first, second, third, fourth]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(third).unwrap().location.x, 20.0);
+        assert_eq!(taffy.layout(first).unwrap().location.x, 50.0);
+        assert_eq!(taffy.layout(fourth).unwrap().location.x, 60.0);
+
+        // The paint order is not affected
+        assert_eq!(taffy.layout(first).unwrap().order, 0);
+        assert_eq!(taffy.layout(second).unwrap().order, 1);
+    }
+
+    #[test]
+    fn flex_order_with_row_reverse() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 1, 10.0);
+        let second = item(&mut taffy, 0, 20.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::RowReverse,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location.x, 80.0);
+        assert_eq!(taffy.layout(first).unwrap().location.x, 70.0);
+    }
+
+    #[test]
+    fn grid_items_are_auto_placed_in_order() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 2, 10.0);
+        let second = item(&mut taffy, 0, 10.0);
+        let third = item(&mut taffy, 1, 10.0);
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Grid, grid_template_columns: vec![length(10.0); 3], ..Default::default() },
+                &[first, second, third],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(third).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 20.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().order, 0);
+    }
+
+    #[test]
+    fn grid_dense_packing_respects_order() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let late = item(&mut taffy, 1, 10.0);
+        let wide =
+            taffy.new_leaf(Style { grid_column: Line { start: auto(), end: span(2) }, ..Default::default() }).unwrap();
+        let early = item(&mut taffy, -1, 10.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_auto_flow: GridAutoFlow::RowDense,
+                    grid_template_columns: vec![length(10.0); 2],
+                    grid_auto_rows: vec![length(10.0)],
+                    ..Default::default()
+                },
+                &[late, wide, early],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The wide item doesn't fit beside the early item, and dense packing then fills the gap with the late item
+        assert_eq!(taffy.layout(early).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(wide).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(late).unwrap().location, Point { x: 10.0, y: 0.0 });
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod overflow_auto {
+    use taffy::prelude::*;
+    use taffy::{Overflow, Point};
+
+    /// A 100x100 container with vertical `Overflow::Auto` scrolling, containing a child of the given height
+    fn scroll_container(taffy: &mut TaffyTree, display: Display, child_height: f32) -> (NodeId, NodeId) {
+        let child = taffy
+            .new_leaf(Style {
+                flex_shrink: 0.0,
+                size: Size { width: auto(), height: length(child_height) },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    display,
+                    flex_direction: FlexDirection::Column,
+                    overflow: Point { x: Overflow::Hidden, y: Overflow::Auto },
+                    scrollbar_width: 10.0,
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        (container, child)
+    }
+
+    #[test]
+    fn scrollbar_reserved_when_content_overflows() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let (container, child) = scroll_container(&mut taffy, display, 200.0);
+            taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(container).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 });
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: 90.0, height: 200.0 }, "{display:?}");
+        }
+    }
+
+    #[test]
+    fn no_scrollbar_when_content_fits() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let (container, child) = scroll_container(&mut taffy, display, 50.0);
+            taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(container).unwrap().scrollbar_size, Size::ZERO);
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 50.0 }, "{display:?}");
+        }
+    }
+
+    #[test]
+    fn scrollbar_of_nested_scroll_container() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let (scroll_container, _) = scroll_container(&mut taffy, Display::Flex, 200.0);
+        let root = taffy.new_with_children(Style::default(), &[scroll_container]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(scroll_container).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 });
+    }
+}
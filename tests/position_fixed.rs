@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod position_fixed {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    /// A 200x200 root containing a 50x50 relatively positioned wrapper with 5px of padding at (30, 40), containing
+    /// `child`
+    fn wrapped(taffy: &mut TaffyTree, child: NodeId) -> (NodeId, NodeId) {
+        let wrapper = taffy
+            .new_with_children(
+                Style {
+                    margin: Rect { left: length(30.0), right: zero(), top: length(40.0), bottom: zero() },
+                    padding: length(5.0),
+                    size: Size { width: length(50.0), height: length(50.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: length(200.0), height: length(200.0) }, ..Default::default() },
+                &[wrapper],
+            )
+            .unwrap();
+        (root, wrapper)
+    }
+
+    fn fixed(taffy: &mut TaffyTree, inset: Rect<LengthPercentageAuto>, size: Size<Dimension>) -> NodeId {
+        taffy.new_leaf(Style { position: Position::Fixed, inset, size, ..Default::default() }).unwrap()
+    }
+
+    const VIEWPORT: Size<AvailableSpace> =
+        Size { width: AvailableSpace::Definite(400.0), height: AvailableSpace::Definite(300.0) };
+
+    #[test]
+    fn fixed_node_positioned_against_viewport() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: length(0.0), top: auto(), bottom: length(0.0) };
+        let child = fixed(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+        let (root, _) = wrapped(&mut taffy, child);
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+
+        // The bottom-right corner of the viewport is at (370, 260) relative to the wrapper
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 350.0, y: 240.0 });
+    }
+
+    #[test]
+    fn fixed_node_keeps_static_position() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = fixed(&mut taffy, Rect::auto(), Size { width: percent(0.5), height: percent(0.5) });
+        let (root, _) = wrapped(&mut taffy, child);
+
+        for _ in 0..2 {
+            taffy.compute_layout(root, VIEWPORT).unwrap();
+            assert_eq!(taffy.layout(child).unwrap().location, Point { x: 5.0, y: 5.0 });
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: 200.0, height: 150.0 });
+        }
+    }
+
+    #[test]
+    fn viewport_defaults_to_root_size() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: length(0.0), top: auto(), bottom: length(0.0) };
+        let child = fixed(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+        let (root, _) = wrapped(&mut taffy, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 150.0, y: 140.0 });
+    }
+
+    #[test]
+    fn fixed_node_follows_viewport_when_layout_is_cached() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: length(0.0), top: auto(), bottom: length(0.0) };
+        let child = fixed(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+        let (root, _) = wrapped(&mut taffy, child);
+        taffy.compute_layout(root, VIEWPORT).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 150.0, y: 140.0 });
+    }
+
+    #[test]
+    fn fixed_node_is_out_of_flow() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let fixed = fixed(&mut taffy, Rect::auto(), Size { width: length(20.0), height: length(20.0) });
+            let sibling = taffy
+                .new_leaf(Style { size: Size { width: length(30.0), height: length(30.0) }, ..Default::default() })
+                .unwrap();
+            let root = taffy.new_with_children(Style { display, ..Default::default() }, &[fixed, sibling]).unwrap();
+            taffy.compute_layout(root, VIEWPORT).unwrap();
+
+            assert_eq!(taffy.layout(sibling).unwrap().location, Point { x: 0.0, y: 0.0 }, "{display:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "content_size")]
+    fn fixed_node_does_not_contribute_to_parent_content_size() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let inset = Rect { left: length(300.0), right: auto(), top: length(250.0), bottom: auto() };
+            let fixed = fixed(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+            let sibling = taffy
+                .new_leaf(Style { size: Size { width: length(30.0), height: length(30.0) }, ..Default::default() })
+                .unwrap();
+            let root = taffy
+                .new_with_children(
+                    Style { display, size: Size { width: length(100.0), height: length(100.0) }, ..Default::default() },
+                    &[fixed, sibling],
+                )
+                .unwrap();
+            taffy.compute_layout(root, VIEWPORT).unwrap();
+
+            assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 300.0, y: 250.0 }, "{display:?}");
+            assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 30.0, height: 30.0 }, "{display:?}");
+        }
+    }
+}
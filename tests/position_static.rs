@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod position_static {
+    use taffy::prelude::*;
+    use taffy::Point;
+
+    /// A 200x200 root with 10px of padding, containing a 50x50 wrapper with the given position, containing `child`
+    fn wrapped(taffy: &mut TaffyTree, wrapper_position: Position, child: NodeId) -> (NodeId, NodeId) {
+        let wrapper = taffy
+            .new_with_children(
+                Style {
+                    position: wrapper_position,
+                    margin: Rect { left: length(30.0), right: zero(), top: length(40.0), bottom: zero() },
+                    padding: length(5.0),
+                    size: Size { width: length(50.0), height: length(50.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    padding: length(10.0),
+                    size: Size { width: length(200.0), height: length(200.0) },
+                    ..Default::default()
+                },
+                &[wrapper],
+            )
+            .unwrap();
+        (root, wrapper)
+    }
+
+    fn absolute(taffy: &mut TaffyTree, inset: Rect<LengthPercentageAuto>, size: Size<Dimension>) -> NodeId {
+        taffy.new_leaf(Style { position: Position::Absolute, inset, size, ..Default::default() }).unwrap()
+    }
+
+    #[test]
+    fn absolute_child_of_static_node_uses_positioned_ancestor() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: length(0.0), top: auto(), bottom: length(0.0) };
+        let child = absolute(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+        let (root, wrapper) = wrapped(&mut taffy, Position::Static, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(wrapper).unwrap().location, Point { x: 40.0, y: 50.0 });
+        // Placed in the bottom-right corner of the root's padding box
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 140.0, y: 130.0 });
+    }
+
+    #[test]
+    fn absolute_child_of_relative_node_uses_parent() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: length(0.0), top: auto(), bottom: length(0.0) };
+        let child = absolute(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+        let (root, _) = wrapped(&mut taffy, Position::Relative, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 30.0, y: 30.0 });
+    }
+
+    #[test]
+    fn static_position_is_kept_and_percentages_resolve_against_containing_block() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = absolute(&mut taffy, Rect::auto(), Size { width: percent(0.5), height: length(20.0) });
+        let (root, _) = wrapped(&mut taffy, Position::Static, child);
+
+        // Laying out a second time must not move the node again
+        for _ in 0..2 {
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+            assert_eq!(taffy.layout(child).unwrap().location, Point { x: 5.0, y: 5.0 });
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 20.0 });
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "content_size")]
+    fn absolute_child_of_static_node_contributes_to_containing_block_content_size() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: length(150.0), right: auto(), top: length(180.0), bottom: auto() };
+        let child = absolute(&mut taffy, inset, Size { width: length(100.0), height: length(100.0) });
+        let (root, wrapper) = wrapped(&mut taffy, Position::Static, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 110.0, y: 130.0 });
+        // The child overflows the root rather than the wrapper (whose content size is its end padding)
+        assert_eq!(taffy.layout(wrapper).unwrap().content_size, Size { width: 5.0, height: 5.0 });
+        assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 250.0, height: 280.0 });
+    }
+
+    #[test]
+    fn nested_static_nodes() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: length(0.0), right: auto(), top: length(0.0), bottom: auto() };
+        let child = absolute(&mut taffy, inset, Size { width: length(20.0), height: length(20.0) });
+        let inner = taffy
+            .new_with_children(
+                Style { position: Position::Static, padding: length(5.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        let (root, _) = wrapped(&mut taffy, Position::Static, inner);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The wrapper is at (40, 50) and the inner node at (5, 5) within it
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: -45.0, y: -55.0 });
+    }
+
+    #[test]
+    fn static_node_ignores_inset() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let child = taffy
+                .new_leaf(Style {
+                    position: Position::Static,
+                    inset: Rect { left: length(10.0), right: auto(), top: length(10.0), bottom: auto() },
+                    size: Size { width: length(20.0), height: length(20.0) },
+                    ..Default::default()
+                })
+                .unwrap();
+            let root = taffy.new_with_children(Style { display, ..Default::default() }, &[child]).unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 }, "{display:?}");
+        }
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod position_sticky {
+    use taffy::prelude::*;
+    use taffy::{Overflow, Point};
+
+    /// A 100x100 vertical scroll container containing a 250px tall section, containing a 50px spacer followed by a
+    /// 20px tall sticky header with the given inset
+    fn scroll_container(taffy: &mut TaffyTree, inset: Rect<LengthPercentageAuto>) -> (NodeId, NodeId) {
+        let spacer =
+            taffy.new_leaf(Style { size: Size { width: auto(), height: length(50.0) }, ..Default::default() }).unwrap();
+        let header = taffy
+            .new_leaf(Style {
+                position: Position::Sticky,
+                inset,
+                flex_shrink: 0.0,
+                size: Size { width: auto(), height: length(20.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let section = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    flex_shrink: 0.0,
+                    size: Size { width: auto(), height: length(250.0) },
+                    ..Default::default()
+                },
+                &[spacer, header],
+            )
+            .unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    overflow: Point { x: Overflow::Visible, y: Overflow::Hidden },
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[section],
+            )
+            .unwrap();
+        (container, header)
+    }
+
+    #[test]
+    fn sticky_offset_is_zero_without_scrolling() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: auto(), top: length(0.0), bottom: auto() };
+        let (container, header) = scroll_container(&mut taffy, inset);
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 50.0 });
+        assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point::ZERO);
+    }
+
+    #[test]
+    fn sticks_to_top_of_scrollport_within_containing_block() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: auto(), top: length(10.0), bottom: auto() };
+        let (container, header) = scroll_container(&mut taffy, inset);
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        taffy.set_scroll_offset(container, Point { x: 0.0, y: 80.0 }).unwrap();
+        assert!(!taffy.dirty(container).unwrap());
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 50.0 });
+        assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point { x: 0.0, y: 40.0 });
+
+        // The header can not leave the section
+        taffy.set_scroll_offset(container, Point { x: 0.0, y: 300.0 }).unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point { x: 0.0, y: 180.0 });
+    }
+
+    #[test]
+    fn sticks_to_bottom_of_scrollport() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: auto(), right: auto(), top: auto(), bottom: length(0.0) };
+        let (container, header) = scroll_container(&mut taffy, inset);
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point::ZERO);
+
+        // Scrolling upwards moves the header to the bottom edge of the scrollport
+        taffy.set_scroll_offset(container, Point { x: 0.0, y: -40.0 }).unwrap();
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point { x: 0.0, y: -10.0 });
+    }
+
+    #[test]
+    fn sticky_node_ignores_inset_in_flow() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let inset = Rect { left: length(5.0), right: auto(), top: length(100.0), bottom: auto() };
+        let (container, header) = scroll_container(&mut taffy, inset);
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 50.0 });
+    }
+}
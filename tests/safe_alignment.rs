@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod safe_alignment {
+    use taffy::prelude::*;
+    use taffy::{Direction, Point};
+
+    fn item(taffy: &mut TaffyTree, width: f32, height: f32) -> NodeId {
+        taffy
+            .new_leaf(Style {
+                flex_shrink: 0.0,
+                size: Size { width: length(width), height: length(height) },
+                ..Default::default()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn flex_justify_content() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let safe = item(&mut taffy, 100.0, 150.0);
+        let not_safe = item(&mut taffy, 100.0, 150.0);
+        let container = |justify_content| Style {
+            flex_direction: FlexDirection::Column,
+            justify_content: Some(justify_content),
+            size: Size { width: length(100.0), height: length(100.0) },
+            ..Default::default()
+        };
+        let safe_container = taffy.new_with_children(container(JustifyContent::SafeCenter), &[safe]).unwrap();
+        let container = taffy.new_with_children(container(JustifyContent::Center), &[not_safe]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[safe_container, container]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(safe).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(not_safe).unwrap().location.y, -25.0);
+    }
+
+    #[test]
+    fn flex_safe_alignment_without_overflow() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = item(&mut taffy, 20.0, 20.0);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    justify_content: Some(JustifyContent::SafeEnd),
+                    align_items: Some(AlignItems::SafeCenter),
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 80.0, y: 40.0 });
+    }
+
+    #[test]
+    fn flex_align_content_and_align_self() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 60.0, 40.0);
+        let second = item(&mut taffy, 60.0, 40.0);
+        let lines = taffy
+            .new_with_children(
+                Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    align_content: Some(AlignContent::SafeFlexEnd),
+                    size: Size { width: length(100.0), height: length(50.0) },
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        let tall = taffy
+            .new_leaf(Style {
+                align_self: Some(AlignSelf::SafeCenter),
+                size: Size { width: length(20.0), height: length(80.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let single_line = taffy.new_with_children(
+            Style { size: Size { width: length(100.0), height: length(50.0) }, ..Default::default() },
+            &[tall],
+        );
+        let root = taffy.new_with_children(Style::default(), &[lines, single_line.unwrap()]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(second).unwrap().location.y, 40.0);
+        assert_eq!(taffy.layout(tall).unwrap().location.y, 0.0);
+    }
+
+    #[test]
+    fn grid_content_and_self_alignment() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let wide = taffy
+            .new_leaf(Style {
+                justify_self: Some(JustifySelf::SafeCenter),
+                size: Size { width: length(80.0), height: auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    direction: Some(Direction::Rtl),
+                    grid_template_columns: vec![length(50.0)],
+                    grid_template_rows: vec![length(60.0), length(60.0)],
+                    align_content: Some(AlignContent::SafeCenter),
+                    size: Size { width: length(50.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[first, wide],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The rows overflow the container so they are aligned to its start edge, and the overflowing item is
+        // aligned to the inline-start (right) edge of its grid area
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(wide).unwrap().location, Point { x: -30.0, y: 60.0 });
+    }
+}
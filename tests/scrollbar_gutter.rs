@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod scrollbar_gutter {
+    use taffy::prelude::*;
+    use taffy::{Overflow, Point, ScrollbarGutter};
+
+    /// A 100x100 container with the given overflow and `scrollbar_gutter`, containing a 50px tall child
+    fn scroll_container(
+        taffy: &mut TaffyTree,
+        display: Display,
+        overflow: Overflow,
+        scrollbar_gutter: ScrollbarGutter,
+    ) -> (NodeId, NodeId) {
+        let child =
+            taffy.new_leaf(Style { size: Size { width: auto(), height: length(50.0) }, ..Default::default() }).unwrap();
+        let container = taffy
+            .new_with_children(
+                Style {
+                    display,
+                    flex_direction: FlexDirection::Column,
+                    overflow: Point { x: Overflow::Hidden, y: overflow },
+                    scrollbar_width: 10.0,
+                    scrollbar_gutter,
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        (container, child)
+    }
+
+    #[test]
+    fn stable_gutter_reserved_without_overflow() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            for overflow in [Overflow::Hidden, Overflow::Auto] {
+                let mut taffy: TaffyTree = TaffyTree::new();
+                let (container, child) = scroll_container(&mut taffy, display, overflow, ScrollbarGutter::Stable);
+                taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+                assert_eq!(taffy.layout(container).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 });
+                assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 }, "{display:?}");
+                assert_eq!(taffy.layout(child).unwrap().size, Size { width: 90.0, height: 50.0 }, "{display:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn stable_gutter_not_reserved_for_visible_overflow() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let (container, child) =
+            scroll_container(&mut taffy, Display::Block, Overflow::Visible, ScrollbarGutter::Stable);
+        taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(container).unwrap().scrollbar_size, Size::ZERO);
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 50.0 });
+    }
+
+    #[test]
+    fn stable_both_edges_gutter() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let (container, child) =
+                scroll_container(&mut taffy, display, Overflow::Hidden, ScrollbarGutter::StableBothEdges);
+            taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(container).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 });
+            assert_eq!(taffy.layout(child).unwrap().location, Point { x: 10.0, y: 0.0 }, "{display:?}");
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: 80.0, height: 50.0 }, "{display:?}");
+        }
+    }
+
+    #[test]
+    fn overlay_scrollbars_reserve_no_space() {
+        for display in [Display::Flex, Display::Block, Display::Grid] {
+            let mut taffy: TaffyTree = TaffyTree::new();
+            let (container, child) = scroll_container(&mut taffy, display, Overflow::Scroll, ScrollbarGutter::Overlay);
+            taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+            assert_eq!(taffy.layout(container).unwrap().scrollbar_size, Size::ZERO);
+            assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 50.0 }, "{display:?}");
+        }
+    }
+
+    #[test]
+    fn stable_gutter_of_leaf() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let leaf = taffy
+            .new_leaf(Style {
+                overflow: Point { x: Overflow::Visible, y: Overflow::Hidden },
+                scrollbar_width: 10.0,
+                scrollbar_gutter: ScrollbarGutter::Stable,
+                padding: Rect { left: length(5.0), right: length(5.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[leaf]).unwrap();
+        taffy.compute_layout(root, Size { width: length(100.0), height: length(100.0) }).unwrap();
+
+        assert_eq!(taffy.layout(leaf).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 });
+    }
+}
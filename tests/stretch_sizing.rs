@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod stretch_sizing {
+    use taffy::prelude::*;
+    use taffy::Dimension;
+
+    fn container(taffy: &mut TaffyTree, display: Display, child: NodeId) -> NodeId {
+        taffy
+            .new_with_children(
+                Style { display, size: Size { width: length(200.0), height: length(100.0) }, ..Default::default() },
+                &[child],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn block_item_height() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::stretch(), height: Dimension::stretch() },
+                margin: Rect { left: length(5.0), right: length(5.0), top: length(10.0), bottom: length(10.0) },
+                padding: Rect::length(4.0),
+                ..Default::default()
+            })
+            .unwrap();
+        let root = container(&mut taffy, Display::Block, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 190.0, height: 80.0 });
+    }
+
+    #[test]
+    fn flex_item_main_size() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::stretch(), height: length(10.0) },
+                margin: Rect { left: length(5.0), right: length(15.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = container(&mut taffy, Display::Flex, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 180.0, height: 10.0 });
+    }
+
+    #[test]
+    fn grid_item_ignores_self_alignment() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style {
+                align_self: Some(AlignSelf::Start),
+                size: Size { width: length(10.0), height: Dimension::stretch() },
+                margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: length(5.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = container(&mut taffy, Display::Grid, child);
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 90.0 });
+    }
+
+    #[test]
+    fn absolutely_positioned_item() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let style = Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(10.0), right: auto(), top: length(20.0), bottom: length(30.0) },
+            size: Size { width: Dimension::stretch(), height: Dimension::stretch() },
+            margin: Rect::length(5.0),
+            ..Default::default()
+        };
+        let flex_child = taffy.new_leaf(style.clone()).unwrap();
+        let block_child = taffy.new_leaf(style.clone()).unwrap();
+        let grid_child = taffy.new_leaf(style).unwrap();
+        let flex = container(&mut taffy, Display::Flex, flex_child);
+        let block = container(&mut taffy, Display::Block, block_child);
+        let grid = container(&mut taffy, Display::Grid, grid_child);
+        let root = taffy.new_with_children(Style::default(), &[flex, block, grid]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The containing block is reduced by the item's insets and margins
+        let expected = Size { width: 180.0, height: 40.0 };
+        assert_eq!(taffy.layout(flex_child).unwrap().size, expected);
+        assert_eq!(taffy.layout(block_child).unwrap().size, expected);
+        assert_eq!(taffy.layout(grid_child).unwrap().size, expected);
+    }
+}
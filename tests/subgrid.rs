@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod subgrid {
+    use taffy::prelude::*;
+    use taffy::Point;
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext};
+
+    fn column_subgrid(row: i16) -> Style {
+        Style {
+            display: Display::Grid,
+            subgrid_columns: true,
+            grid_row: Line { start: line(row), end: span(1) },
+            grid_column: Line { start: line(1), end: span(2) },
+            ..Default::default()
+        }
+    }
+
+    fn form_grid() -> Style {
+        Style {
+            display: Display::Grid,
+            size: Size { width: length(200.0), height: auto() },
+            grid_template_columns: vec![auto(), fr(1.0)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn subgrid_children_size_parent_columns() {
+        let mut taffy = new_test_tree();
+        let short_label = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(30.0, 10.0)).unwrap();
+        let field_0 = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let long_label = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(50.0, 10.0)).unwrap();
+        let field_1 = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let card_0 = taffy.new_with_children(column_subgrid(1), &[short_label, field_0]).unwrap();
+        let card_1 = taffy.new_with_children(column_subgrid(2), &[long_label, field_1]).unwrap();
+        let root = taffy.new_with_children(form_grid(), &[card_0, card_1]).unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        // The label column is sized by the longest label in either card, and both cards' fields line up with it
+        assert_eq!(taffy.layout(card_0).unwrap().size, Size { width: 200.0, height: 10.0 });
+        assert_eq!(taffy.layout(card_1).unwrap().location, Point { x: 0.0, y: 10.0 });
+        assert_eq!(taffy.layout(short_label).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(field_0).unwrap().location, Point { x: 50.0, y: 0.0 });
+        assert_eq!(taffy.layout(field_0).unwrap().size.width, 150.0);
+        assert_eq!(taffy.layout(field_1).unwrap().location, Point { x: 50.0, y: 0.0 });
+    }
+
+    #[test]
+    fn subgrid_inherits_parent_gap() {
+        let mut taffy = new_test_tree();
+        let label = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(40.0, 10.0)).unwrap();
+        let field = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let card = taffy
+            .new_with_children(
+                Style { gap: Size { width: length(50.0), height: zero() }, ..column_subgrid(1) },
+                &[label, field],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { gap: Size { width: length(10.0), height: zero() }, ..form_grid() }, &[card])
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        // The subgrid's own gap is ignored in favour of the parent's gap
+        assert_eq!(taffy.layout(field).unwrap().location, Point { x: 50.0, y: 0.0 });
+        assert_eq!(taffy.layout(field).unwrap().size.width, 150.0);
+    }
+
+    #[test]
+    fn subgrid_padding_contributes_to_edge_tracks() {
+        let mut taffy = new_test_tree();
+        let label = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(40.0, 10.0)).unwrap();
+        let field = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let card = taffy
+            .new_with_children(
+                Style { padding: Rect { left: length(10.0), ..Rect::zero() }, ..column_subgrid(1) },
+                &[label, field],
+            )
+            .unwrap();
+        let other_label = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let other_field = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let other_card = taffy.new_with_children(column_subgrid(2), &[other_label, other_field]).unwrap();
+        let root = taffy.new_with_children(form_grid(), &[card, other_card]).unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        // The first column includes the padding of the subgrid, and the subgrid's lines coincide with the parent's lines
+        assert_eq!(taffy.layout(label).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(label).unwrap().size.width, 40.0);
+        assert_eq!(taffy.layout(field).unwrap().location, Point { x: 50.0, y: 0.0 });
+        assert_eq!(taffy.layout(other_label).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(other_field).unwrap().location, Point { x: 50.0, y: 0.0 });
+    }
+
+    #[test]
+    fn subgrid_rows_align_with_siblings() {
+        let mut taffy = new_test_tree();
+        let tall = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 30.0)).unwrap();
+        let short = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let column = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    subgrid_rows: true,
+                    grid_row: Line { start: line(1), end: span(2) },
+                    ..Default::default()
+                },
+                &[tall, short],
+            )
+            .unwrap();
+        let sibling_0 = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let sibling_1 = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(20.0, 10.0)).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto()],
+                    grid_template_rows: vec![auto(), auto()],
+                    grid_auto_flow: GridAutoFlow::Column,
+                    ..Default::default()
+                },
+                &[column, sibling_0, sibling_1],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(column).unwrap().size.height, 40.0);
+        assert_eq!(taffy.layout(short).unwrap().location, Point { x: 0.0, y: 30.0 });
+        assert_eq!(taffy.layout(sibling_1).unwrap().location, Point { x: 20.0, y: 30.0 });
+    }
+
+    #[test]
+    fn subgrid_without_parent_grid_uses_own_tracks() {
+        let mut taffy = new_test_tree();
+        let child = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(10.0, 10.0)).unwrap();
+        let grid = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    subgrid_columns: true,
+                    grid_template_columns: vec![length(40.0), length(40.0)],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        let root = taffy.new_with_children(Style { display: Display::Flex, ..Default::default() }, &[grid]).unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(grid).unwrap().size.width, 80.0);
+        assert_eq!(taffy.layout(child).unwrap().size.width, 40.0);
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod table {
+    use taffy::prelude::*;
+    use taffy::Point;
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+    fn table_style() -> Style {
+        Style { display: Display::Table, ..Default::default() }
+    }
+
+    fn role(table_role: TableRole) -> Style {
+        Style { table_role, ..Default::default() }
+    }
+
+    #[test]
+    fn table_rows_and_cells_with_border_spacing() {
+        let mut taffy = new_test_tree();
+        let cells: Vec<NodeId> = (0..4)
+            .map(|_| taffy.new_leaf_with_context(role(TableRole::Cell), TestNodeContext::fixed(50.0, 20.0)).unwrap())
+            .collect();
+        let row_0 = taffy.new_with_children(role(TableRole::Row), &cells[0..2]).unwrap();
+        let row_1 = taffy.new_with_children(role(TableRole::Row), &cells[2..4]).unwrap();
+        let table =
+            taffy.new_with_children(Style { border_spacing: length(10.0), ..table_style() }, &[row_0, row_1]).unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        let layout = taffy.layout(table).unwrap();
+        assert_eq!(layout.size, Size { width: 130.0, height: 70.0 });
+
+        let layout = taffy.layout(row_0).unwrap();
+        assert_eq!(layout.location, Point { x: 10.0, y: 10.0 });
+        assert_eq!(layout.size, Size { width: 110.0, height: 20.0 });
+        assert_eq!(taffy.layout(row_1).unwrap().location, Point { x: 10.0, y: 40.0 });
+
+        // Cells are positioned relative to their row
+        assert_eq!(taffy.layout(cells[0]).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(cells[1]).unwrap().location, Point { x: 60.0, y: 0.0 });
+        assert_eq!(taffy.layout(cells[3]).unwrap().location, Point { x: 60.0, y: 0.0 });
+        assert_eq!(taffy.layout(cells[3]).unwrap().size, Size { width: 50.0, height: 20.0 });
+    }
+
+    #[test]
+    fn table_colspan_and_rowspan() {
+        let mut taffy = new_test_tree();
+        let a = taffy
+            .new_leaf_with_context(Style { colspan: 2, ..Default::default() }, TestNodeContext::fixed(100.0, 10.0))
+            .unwrap();
+        let b = taffy
+            .new_leaf_with_context(Style { rowspan: 2, ..Default::default() }, TestNodeContext::fixed(30.0, 40.0))
+            .unwrap();
+        let c = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(30.0, 10.0)).unwrap();
+        let d = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(30.0, 10.0)).unwrap();
+        let row_a = taffy.new_with_children(role(TableRole::Row), &[a]).unwrap();
+        let row_b = taffy.new_with_children(role(TableRole::Row), &[b, c]).unwrap();
+        let row_c = taffy.new_with_children(role(TableRole::Row), &[d]).unwrap();
+        let table = taffy.new_with_children(table_style(), &[row_a, row_b, row_c]).unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 100.0, height: 50.0 });
+        assert_eq!(taffy.layout(a).unwrap().size, Size { width: 100.0, height: 10.0 });
+        assert_eq!(taffy.layout(b).unwrap().size, Size { width: 50.0, height: 40.0 });
+        assert_eq!(taffy.layout(row_b).unwrap().size.height, 20.0);
+        assert_eq!(taffy.layout(row_c).unwrap().size.height, 20.0);
+
+        // `d` is placed in the second column as the first column is occupied by `b`
+        assert_eq!(taffy.layout(d).unwrap().location, Point { x: 50.0, y: 0.0 });
+    }
+
+    #[test]
+    fn table_auto_layout_distributes_width_between_min_and_max_content() {
+        let mut taffy = new_test_tree();
+        let cell_0 = taffy
+            .new_leaf_with_context(
+                Style::default(),
+                TestNodeContext::ahem_text("HH\u{200b}HH", WritingMode::Horizontal),
+            )
+            .unwrap();
+        let cell_1 = taffy
+            .new_leaf_with_context(Style::default(), TestNodeContext::ahem_text("HHHHHH", WritingMode::Horizontal))
+            .unwrap();
+        let table = taffy
+            .new_with_children(
+                Style { size: Size { width: length(90.0), height: auto() }, ..table_style() },
+                &[cell_0, cell_1],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(cell_0).unwrap().size.width, 30.0);
+        assert_eq!(taffy.layout(cell_1).unwrap().size.width, 60.0);
+        assert_eq!(taffy.layout(cell_1).unwrap().location.x, 30.0);
+    }
+
+    #[test]
+    fn table_grows_to_fit_min_content_width() {
+        let mut taffy = new_test_tree();
+        let cell = taffy
+            .new_leaf_with_context(Style::default(), TestNodeContext::ahem_text("HHHHHH", WritingMode::Horizontal))
+            .unwrap();
+        let table = taffy
+            .new_with_children(Style { size: Size { width: length(20.0), height: auto() }, ..table_style() }, &[cell])
+            .unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size.width, 60.0);
+    }
+
+    #[test]
+    fn table_fixed_layout_uses_first_row_only() {
+        let mut taffy = new_test_tree();
+        let cell_00 = taffy
+            .new_leaf(Style { size: Size { width: length(50.0), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let cell_01 = taffy.new_leaf(Style::default()).unwrap();
+        let cell_10 = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(500.0, 10.0)).unwrap();
+        let cell_11 = taffy.new_leaf(Style::default()).unwrap();
+        let row_0 = taffy.new_with_children(role(TableRole::Row), &[cell_00, cell_01]).unwrap();
+        let row_1 = taffy.new_with_children(role(TableRole::Row), &[cell_10, cell_11]).unwrap();
+        let table = taffy
+            .new_with_children(
+                Style {
+                    table_layout: TableLayout::Fixed,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..table_style()
+                },
+                &[row_0, row_1],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size.width, 200.0);
+        assert_eq!(taffy.layout(cell_10).unwrap().size.width, 50.0);
+        assert_eq!(taffy.layout(cell_11).unwrap().size.width, 150.0);
+        assert_eq!(taffy.layout(cell_11).unwrap().location.x, 50.0);
+    }
+
+    #[test]
+    fn table_header_group_is_placed_first() {
+        let mut taffy = new_test_tree();
+        let body_cell = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(10.0, 10.0)).unwrap();
+        let header_cell = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(10.0, 20.0)).unwrap();
+        let body_row = taffy.new_with_children(role(TableRole::Row), &[body_cell]).unwrap();
+        let header_row = taffy.new_with_children(role(TableRole::Row), &[header_cell]).unwrap();
+        let body = taffy.new_with_children(role(TableRole::RowGroup), &[body_row]).unwrap();
+        let header = taffy.new_with_children(role(TableRole::HeaderGroup), &[header_row]).unwrap();
+        let table = taffy.new_with_children(table_style(), &[body, header]).unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 10.0, height: 30.0 });
+        assert_eq!(taffy.layout(header).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(body).unwrap().location.y, 20.0);
+        assert_eq!(taffy.layout(body_row).unwrap().location.y, 0.0);
+    }
+
+    #[test]
+    fn table_is_not_stretched_by_block_parent() {
+        let mut taffy = new_test_tree();
+        let cell = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(40.0, 10.0)).unwrap();
+        let table = taffy.new_with_children(table_style(), &[cell]).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[table],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 40.0, height: 10.0 });
+    }
+
+    #[test]
+    fn display_contents_children_of_tables_and_rows_are_replaced_by_their_children() {
+        let mut taffy = new_test_tree();
+        let cells: Vec<NodeId> = (0..4)
+            .map(|_| taffy.new_leaf_with_context(role(TableRole::Cell), TestNodeContext::fixed(50.0, 20.0)).unwrap())
+            .collect();
+        let contents_cells =
+            taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &cells[1..3]).unwrap();
+        let row_0 = taffy.new_with_children(role(TableRole::Row), &[cells[0], contents_cells]).unwrap();
+        let row_1 = taffy.new_with_children(role(TableRole::Row), &cells[3..4]).unwrap();
+        let contents_rows =
+            taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[row_1]).unwrap();
+        let body = taffy.new_with_children(role(TableRole::RowGroup), &[row_0, contents_rows]).unwrap();
+        let contents_body =
+            taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[body]).unwrap();
+        let table = taffy.new_with_children(table_style(), &[contents_body]).unwrap();
+        taffy.compute_layout_with_measure(table, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(table).unwrap().size, Size { width: 150.0, height: 40.0 });
+        assert_eq!(taffy.layout(cells[1]).unwrap().location, Point { x: 50.0, y: 0.0 });
+        assert_eq!(taffy.layout(cells[2]).unwrap().location, Point { x: 100.0, y: 0.0 });
+        assert_eq!(taffy.layout(row_1).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(contents_cells).unwrap().size, Size::ZERO);
+    }
+}
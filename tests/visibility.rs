@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod visibility {
+    use taffy::prelude::*;
+    use taffy::{Point, Visibility};
+
+    fn item(taffy: &mut TaffyTree, width: f32, height: f32, visibility: Visibility) -> NodeId {
+        taffy
+            .new_leaf(Style {
+                visibility,
+                size: Size { width: length(width), height: length(height) },
+                ..Default::default()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn collapsed_flex_item_keeps_line_cross_size() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 20.0, 10.0, Visibility::Visible);
+        let collapsed = item(&mut taffy, 30.0, 40.0, Visibility::Collapse);
+        let last = item(&mut taffy, 20.0, 10.0, Visibility::Visible);
+        let stretched_style = Style { size: Size { width: length(5.0), height: auto() }, ..Default::default() };
+        let stretched = taffy.new_leaf(stretched_style).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: length(100.0), height: auto() }, ..Default::default() },
+                &[first, collapsed, last, stretched],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size.height, 40.0);
+        assert_eq!(taffy.layout(collapsed).unwrap().size, Size::zero());
+        assert_eq!(taffy.layout(last).unwrap().location, Point { x: 20.0, y: 0.0 });
+        assert_eq!(taffy.layout(stretched).unwrap().location.x, 40.0);
+        assert_eq!(taffy.layout(stretched).unwrap().size.height, 40.0);
+    }
+
+    #[test]
+    fn collapsed_flex_item_has_zero_main_size_when_wrapping() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 30.0, 10.0, Visibility::Visible);
+        let collapsed = item(&mut taffy, 30.0, 20.0, Visibility::Collapse);
+        let last = item(&mut taffy, 30.0, 10.0, Visibility::Visible);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    flex_wrap: FlexWrap::Wrap,
+                    size: Size { width: length(50.0), height: auto() },
+                    ..Default::default()
+                },
+                &[first, collapsed, last],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The collapsed item shares the first line, whose cross size is set to the collapsed item's strut size
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(last).unwrap().location, Point { x: 0.0, y: 20.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+    }
+
+    #[test]
+    fn collapsed_grid_item_is_hidden() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 20.0, 10.0, Visibility::Collapse);
+        let second = item(&mut taffy, 30.0, 10.0, Visibility::Hidden);
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto()],
+                    justify_content: Some(JustifyContent::Start),
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Collapsed grid items still contribute to the size of their tracks
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 20.0, height: 10.0 });
+        assert_eq!(taffy.layout(second).unwrap().location.x, 20.0);
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod writing_mode {
+    use taffy::prelude::*;
+    use taffy::{Point, WritingMode};
+
+    fn item(taffy: &mut TaffyTree, width: f32, height: f32) -> NodeId {
+        taffy
+            .new_leaf(Style { size: Size { width: length(width), height: length(height) }, ..Default::default() })
+            .unwrap()
+    }
+
+    fn container(writing_mode: Option<WritingMode>, style: Style) -> Style {
+        Style { writing_mode, size: Size { width: length(100.0), height: length(100.0) }, ..style }
+    }
+
+    #[test]
+    fn flex_row_runs_vertically() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let rl_first = item(&mut taffy, 20.0, 10.0);
+        let rl_second = item(&mut taffy, 30.0, 10.0);
+        let lr_first = item(&mut taffy, 20.0, 10.0);
+        let vertical_rl =
+            taffy.new_with_children(container(Some(WritingMode::VerticalRl), Style::default()), &[rl_first, rl_second]);
+        let vertical_lr =
+            taffy.new_with_children(container(Some(WritingMode::VerticalLr), Style::default()), &[lr_first]);
+        let root = taffy.new_with_children(Style::default(), &[vertical_rl.unwrap(), vertical_lr.unwrap()]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The main axis is vertical and the cross axis starts at the right edge in vertical-rl mode
+        assert_eq!(taffy.layout(rl_first).unwrap().location, Point { x: 80.0, y: 0.0 });
+        assert_eq!(taffy.layout(rl_second).unwrap().location, Point { x: 70.0, y: 10.0 });
+        assert_eq!(taffy.layout(rl_second).unwrap().size, Size { width: 30.0, height: 10.0 });
+        assert_eq!(taffy.layout(lr_first).unwrap().location, Point { x: 0.0, y: 0.0 });
+    }
+
+    #[test]
+    fn block_children_are_stacked_horizontally() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = taffy.new_leaf(Style { size: Size { width: length(20.0), height: auto() }, ..Default::default() });
+        let second = taffy.new_leaf(Style {
+            size: Size { width: length(30.0), height: auto() },
+            margin: Rect { left: zero(), right: length(5.0), top: zero(), bottom: zero() },
+            ..Default::default()
+        });
+        let (first, second) = (first.unwrap(), second.unwrap());
+        let root = taffy
+            .new_with_children(
+                container(
+                    Some(WritingMode::VerticalRl),
+                    Style {
+                        display: Display::Block,
+                        padding: Rect { left: zero(), right: length(10.0), top: length(4.0), bottom: zero() },
+                        ..Default::default()
+                    },
+                ),
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Children fill the inline size (height) of the container and are stacked from right to left
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 70.0, y: 4.0 });
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 20.0, height: 96.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 35.0, y: 4.0 });
+        assert_eq!(taffy.layout(second).unwrap().margin.right, 5.0);
+        assert_eq!(taffy.layout(root).unwrap().padding.right, 10.0);
+    }
+
+    #[test]
+    fn grid_columns_run_vertically() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = taffy.new_leaf(Style::default()).unwrap();
+        let second = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy
+            .new_with_children(
+                container(
+                    Some(WritingMode::VerticalLr),
+                    Style {
+                        display: Display::Grid,
+                        grid_template_columns: vec![length(30.0), length(40.0)],
+                        grid_template_rows: vec![length(25.0)],
+                        ..Default::default()
+                    },
+                ),
+                &[first, second],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(first).unwrap().size, Size { width: 25.0, height: 30.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 30.0 });
+        assert_eq!(taffy.layout(second).unwrap().size, Size { width: 25.0, height: 40.0 });
+    }
+
+    #[test]
+    fn writing_mode_is_inherited() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let child = item(&mut taffy, 20.0, 10.0);
+        let inner = taffy.new_with_children(container(None, Style::default()), &[child]).unwrap();
+        let root = taffy
+            .new_with_children(Style { writing_mode: Some(WritingMode::VerticalRl), ..Default::default() }, &[inner])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, 80.0);
+
+        // Changing the writing mode of an ancestor invalidates the cached layout of its descendants
+        taffy.set_style(root, Style { writing_mode: Some(WritingMode::HorizontalTb), ..Default::default() }).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+    }
+
+    #[test]
+    fn orthogonal_flow() {
+        let mut taffy: TaffyTree = TaffyTree::new();
+        let first = item(&mut taffy, 20.0, 10.0);
+        let second = item(&mut taffy, 20.0, 15.0);
+        let vertical = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    writing_mode: Some(WritingMode::VerticalRl),
+                    margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: zero() },
+                    ..Default::default()
+                },
+                &[first, second],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(100.0), height: auto() },
+                    ..Default::default()
+                },
+                &[vertical],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // The vertical child is stretched to fill the width of its horizontal parent, while its height is
+        // determined by the inline size of its own content. Its margins don't collapse with its parent's.
+        assert_eq!(taffy.layout(vertical).unwrap().location, Point { x: 0.0, y: 5.0 });
+        assert_eq!(taffy.layout(vertical).unwrap().size, Size { width: 100.0, height: 15.0 });
+        assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+        assert_eq!(taffy.layout(first).unwrap().location, Point { x: 80.0, y: 0.0 });
+        assert_eq!(taffy.layout(second).unwrap().location, Point { x: 60.0, y: 0.0 });
+    }
+}